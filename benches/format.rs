@@ -0,0 +1,43 @@
+use chinese_numerals::{ChineseNumeral, Formatter, MyriadScaleInt, Options};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+/// Numbers of various magnitudes.
+fn corpus() -> Vec<MyriadScaleInt> {
+    let mut numbers = Vec::new();
+    let mut n: i128 = 1;
+    while n < i128::MAX / 7 {
+        numbers.push(MyriadScaleInt::from(n));
+        numbers.push(MyriadScaleInt::from(-n));
+        n = n * 7 + 3;
+    }
+    numbers
+}
+
+fn bench_format(c: &mut Criterion) {
+    let numbers = corpus();
+    let options = Options::default();
+    let mut group = c.benchmark_group("format");
+    group.throughput(Throughput::Elements(numbers.len() as u64));
+    group.bench_function("to_chinese", |b| {
+        b.iter(|| {
+            for num in &numbers {
+                black_box(num.to_chinese(black_box(&options)));
+            }
+        })
+    });
+    group.bench_function("format_into", |b| {
+        let mut formatter = Formatter::new(options.clone());
+        let mut output = String::new();
+        b.iter(|| {
+            for num in &numbers {
+                output.clear();
+                formatter.format_into(&mut output, black_box(num));
+                black_box(&output);
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_format);
+criterion_main!(benches);
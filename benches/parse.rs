@@ -0,0 +1,36 @@
+use chinese_numerals::{validate, ChineseNumeral, MyriadScaleInt, Scale, Variant};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+/// Numerals of various lengths in all cases and variants.
+fn corpus() -> Vec<String> {
+    let mut numerals = Vec::new();
+    let mut n: i128 = 1;
+    while n < i128::MAX / 7 {
+        let num = MyriadScaleInt::from(n);
+        numerals.push(num.to_lowercase(Variant::Simplified));
+        numerals.push(num.to_uppercase(Variant::Simplified));
+        numerals.push(num.to_lowercase(Variant::Traditional));
+        numerals.push(num.to_uppercase(Variant::Traditional));
+        n = n * 7 + 3;
+    }
+    numerals
+}
+
+fn bench_validate(c: &mut Criterion) {
+    let numerals = corpus();
+    let mut group = c.benchmark_group("validate");
+    group.throughput(Throughput::Bytes(
+        numerals.iter().map(|numeral| numeral.len() as u64).sum(),
+    ));
+    group.bench_function("myriad", |b| {
+        b.iter(|| {
+            for numeral in &numerals {
+                black_box(validate(black_box(numeral), Scale::Myriad)).unwrap();
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_validate);
+criterion_main!(benches);
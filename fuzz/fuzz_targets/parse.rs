@@ -0,0 +1,18 @@
+#![no_main]
+
+use chinese_numerals::{ChineseNumeral, LongScaleBigInt, MyriadScaleInt, ShortScaleInt};
+use libfuzzer_sys::fuzz_target;
+
+// Parsing any string must not panic, and a parsed number must format without panicking.
+fuzz_target!(|s: &str| {
+    if let Ok(num) = ShortScaleInt::try_from(s) {
+        num.to_lowercase_simp();
+    }
+    if let Ok(num) = MyriadScaleInt::try_from(s) {
+        num.to_uppercase_trad();
+    }
+    if let Ok(num) = LongScaleBigInt::try_from(s) {
+        num.to_lowercase_trad();
+    }
+    let _ = s.parse::<MyriadScaleInt>();
+});
@@ -0,0 +1,37 @@
+#![no_main]
+
+use chinese_numerals::{
+    ChineseNumeral, LongScaleBigInt, LongScaleInt, MidScaleBigInt, MidScaleInt, MyriadScaleBigInt,
+    MyriadScaleInt, ShortScaleInt,
+};
+use libfuzzer_sys::fuzz_target;
+
+/// Formats a number in both cases and parses it back.
+fn round_trip<T>(num: T)
+where
+    T: ChineseNumeral + PartialEq + std::fmt::Debug + for<'a> TryFrom<&'a str>,
+    for<'a> <T as TryFrom<&'a str>>::Error: std::fmt::Debug,
+{
+    for numeral in [num.to_lowercase_simp(), num.to_uppercase_trad()] {
+        let parsed = T::try_from(numeral.as_str()).expect("Formatted numeral parses");
+        assert_eq!(num, parsed, "{numeral}");
+    }
+}
+
+fuzz_target!(|input: (
+    ShortScaleInt,
+    MyriadScaleInt,
+    MidScaleInt,
+    LongScaleInt,
+    MyriadScaleBigInt,
+    MidScaleBigInt,
+    LongScaleBigInt,
+)| {
+    round_trip(input.0);
+    round_trip(input.1);
+    round_trip(input.2);
+    round_trip(input.3);
+    round_trip(input.4);
+    round_trip(input.5);
+    round_trip(input.6);
+});
@@ -0,0 +1,109 @@
+//! Converts numbers to Chinese numerals from the command line.
+//!
+//! Numbers are read from the arguments, or from the standard input if none is given.
+
+use chinese_numerals::{
+    convert_str, format_digits, format_money, parse_cents, Case, Error, Options, Scale, Variant,
+};
+use std::io::BufRead;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: cn-num [OPTIONS] [NUMBER]...
+
+Converts numbers to Chinese numerals. Reads numbers from the standard input if none is given.
+
+Options:
+  -s, --scale <SCALE>  Scale of numerals: short, myriad, mid, or long [default: myriad]
+  -t, --traditional    Uses traditional Chinese
+  -j, --japanese       Uses Japanese kanji numerals
+  -u, --uppercase      Uses uppercase (financial) numerals
+  -c, --currency       Formats numbers as amounts of money in yuan, e.g. 123.45
+  -d, --digits         Reads numbers digit by digit
+  -h, --help           Prints help";
+
+#[derive(Clone, Copy)]
+enum Mode {
+    Scale(Scale),
+    Currency,
+    Digits,
+}
+
+struct Args {
+    mode: Mode,
+    options: Options,
+    numbers: Vec<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        mode: Mode::Scale(Scale::Myriad),
+        options: Options::default(),
+        numbers: Vec::new(),
+    };
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-s" | "--scale" => {
+                let scale = iter.next().ok_or("Missing value for --scale")?;
+                args.mode = Mode::Scale(scale.parse().map_err(|err| format!("{err}"))?);
+            }
+            "-t" | "--traditional" => args.options.variant = Variant::Traditional,
+            "-j" | "--japanese" => args.options.variant = Variant::Japanese,
+            "-u" | "--uppercase" => args.options.case = Case::Upper,
+            "-c" | "--currency" => args.mode = Mode::Currency,
+            "-d" | "--digits" => args.mode = Mode::Digits,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                std::process::exit(0);
+            }
+            _ if arg.starts_with('-') && !arg[1..].starts_with(|ch: char| ch.is_ascii_digit()) => {
+                return Err(format!("Unknown option {arg:?}"));
+            }
+            _ => args.numbers.push(arg),
+        }
+    }
+    Ok(args)
+}
+
+fn convert(input: &str, mode: Mode, options: &Options) -> Result<String, Error> {
+    Ok(match mode {
+        Mode::Scale(scale) => convert_str(input, scale, options)?,
+        Mode::Currency => format_money(parse_cents(input)?, options.variant),
+        Mode::Digits => format_digits(input, options.case, options.variant)?,
+    })
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let mut run = |input: &str| match convert(input, args.mode, &args.options) {
+        Ok(output) => println!("{output}"),
+        Err(err) => {
+            eprintln!("{input}: {err}");
+            status = ExitCode::FAILURE;
+        }
+    };
+
+    if args.numbers.is_empty() {
+        for line in std::io::stdin().lock().lines() {
+            match line {
+                Ok(line) => line.split_whitespace().for_each(&mut run),
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    } else {
+        args.numbers.iter().for_each(|input| run(input));
+    }
+    status
+}
@@ -1,109 +1,493 @@
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+/// Characters of Chinese numerals, rendered to different cases and variants.
+///
+/// It is the character-level API for crates building on the tables, e.g. input methods and validators. The characters and their order are stable: digits from [`Zero`](NumChar::Zero) to [`Nine`](NumChar::Nine) come first so that `NumChar::Nine as usize` is 9, followed by the units in ascending order and the signs, and new characters are only appended.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum NumChar {
+    /// 零.
     Zero,
+    /// 一.
     One,
+    /// 二.
     Two,
+    /// 三.
     Three,
+    /// 四.
     Four,
+    /// 五.
     Five,
+    /// 六.
     Six,
+    /// 七.
     Seven,
+    /// 八.
     Eight,
+    /// 九.
     Nine,
+    /// 十, 10.
     Shi,
+    /// 百, 100.
     Bai,
+    /// 千, 1000.
     Qian,
+    /// 万.
     Wan,
+    /// 亿.
     Yi,
+    /// 兆.
     Zhao,
+    /// 京.
     Jing,
+    /// 垓.
     Gai,
+    /// 秭.
     Zi,
+    /// 穰.
     Rang,
+    /// 沟.
     Gou,
+    /// 涧.
     Jian,
+    /// 正.
     Zheng,
+    /// 载.
     Zai,
+    /// 负, the negative sign.
     Neg,
+    /// 正, the positive sign.
+    Pos,
+    /// 点, the decimal point.
+    Point,
 }
 
+use crate::{Case, Variant};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use NumChar::*;
 
-pub(crate) const NUM_CHARS: [NumChar; 25] = [
+/// 幺, read for 1 in phone numbers and codes.
+pub(crate) const YAO: &str = "幺";
+
+pub(crate) const NUM_CHARS: [NumChar; 27] = [
     Zero, One, Two, Three, Four, Five, Six, Seven, Eight, Nine, Shi, Bai, Qian, Wan, Yi, Zhao,
-    Jing, Gai, Zi, Rang, Gou, Jian, Zheng, Zai, Neg,
+    Jing, Gai, Zi, Rang, Gou, Jian, Zheng, Zai, Neg, Pos, Point,
+];
+
+/// Lowercase characters in simplified Chinese, indexed by [`NumChar`].
+const LOWERCASE_SIMP: [&str; 27] = [
+    "零", "一", "二", "三", "四", "五", "六", "七", "八", "九", "十", "百", "千", "万", "亿", "兆",
+    "京", "垓", "秭", "穰", "沟", "涧", "正", "载", "负", "正", "点",
+];
+
+/// Uppercase characters in simplified Chinese, indexed by [`NumChar`].
+const UPPERCASE_SIMP: [&str; 27] = [
+    "零", "壹", "贰", "叁", "肆", "伍", "陆", "柒", "捌", "玖", "拾", "佰", "仟", "万", "亿", "兆",
+    "京", "垓", "秭", "穰", "沟", "涧", "正", "载", "负", "正", "点",
+];
+
+/// Lowercase characters in traditional Chinese, indexed by [`NumChar`].
+const LOWERCASE_TRAD: [&str; 27] = [
+    "零", "一", "二", "三", "四", "五", "六", "七", "八", "九", "十", "百", "千", "萬", "億", "兆",
+    "京", "垓", "秭", "穰", "溝", "澗", "正", "載", "負", "正", "點",
+];
+
+/// Uppercase characters in traditional Chinese, indexed by [`NumChar`].
+const UPPERCASE_TRAD: [&str; 27] = [
+    "零", "壹", "貳", "叄", "肆", "伍", "陸", "柒", "捌", "玖", "拾", "佰", "仟", "萬", "億", "兆",
+    "京", "垓", "秭", "穰", "溝", "澗", "正", "載", "負", "正", "點",
+];
+
+/// Lowercase characters in Japanese, indexed by [`NumChar`].
+const LOWERCASE_JA: [&str; 27] = [
+    "〇", "一", "二", "三", "四", "五", "六", "七", "八", "九", "十", "百", "千", "万", "億", "兆",
+    "京", "垓", "𥝱", "穣", "溝", "澗", "正", "載", "負", "正", "・",
 ];
 
+/// Lowercase characters in Korean hanja, indexed by [`NumChar`].
+const LOWERCASE_KO: [&str; 27] = [
+    "零", "一", "二", "三", "四", "五", "六", "七", "八", "九", "十", "百", "千", "萬", "億", "兆",
+    "京", "垓", "秭", "穰", "溝", "澗", "正", "載", "負", "正", "點",
+];
+
+/// Uppercase characters in Korean hanja, indexed by [`NumChar`].
+const UPPERCASE_KO: [&str; 27] = [
+    "零", "壹", "貳", "參", "四", "五", "六", "七", "八", "九", "拾", "百", "千", "萬", "億", "兆",
+    "京", "垓", "秭", "穰", "溝", "澗", "正", "載", "負", "正", "點",
+];
+
+/// Lowercase characters in Vietnamese chữ Nôm, indexed by [`NumChar`], where units of 万 and above are Hán characters.
+const LOWERCASE_VI: [&str; 27] = [
+    "零", "𠬠", "𠄩", "𠀧", "𦊚", "𠄼", "𦒹", "𦉱", "𠔭", "𠃩", "𨑮", "𤾓", "𠦳", "萬", "億", "兆",
+    "京", "垓", "秭", "穰", "溝", "澗", "正", "載", "負", "正", "點",
+];
+
+/// Uppercase characters (大字) in Japanese, indexed by [`NumChar`].
+const UPPERCASE_JA: [&str; 27] = [
+    "零", "壱", "弐", "参", "四", "五", "六", "七", "八", "九", "拾", "百", "千", "万", "億", "兆",
+    "京", "垓", "𥝱", "穣", "溝", "澗", "正", "載", "負", "正", "・",
+];
+
+/// Mandarin readings in pinyin with tone numbers, indexed by [`NumChar`].
+const PINYIN: [&str; 27] = [
+    "ling2", "yi1", "er4", "san1", "si4", "wu3", "liu4", "qi1", "ba1", "jiu3", "shi2", "bai3",
+    "qian1", "wan4", "yi4", "zhao4", "jing1", "gai1", "zi3", "rang2", "gou1", "jian4", "zheng4",
+    "zai4", "fu4", "zheng4", "dian3",
+];
+
+/// Characters of all built-in tables but Japanese, Korean, and Vietnamese, sorted by code point for classification.
+const CLASSIFICATION: [(char, NumChar); 4 * NUM_CHARS.len()] = build_classification([
+    LOWERCASE_SIMP,
+    UPPERCASE_SIMP,
+    LOWERCASE_TRAD,
+    UPPERCASE_TRAD,
+]);
+
+/// Decodes a string of a single character at compile time.
+const fn single_char(s: &str) -> char {
+    let b = s.as_bytes();
+    let code = match b.len() {
+        1 => b[0] as u32,
+        2 => (b[0] as u32 & 0x1f) << 6 | (b[1] as u32 & 0x3f),
+        3 => (b[0] as u32 & 0x0f) << 12 | (b[1] as u32 & 0x3f) << 6 | (b[2] as u32 & 0x3f),
+        4 => {
+            (b[0] as u32 & 0x07) << 18
+                | (b[1] as u32 & 0x3f) << 12
+                | (b[2] as u32 & 0x3f) << 6
+                | (b[3] as u32 & 0x3f)
+        }
+        _ => panic!("Not a single character"),
+    };
+    match char::from_u32(code) {
+        Some(ch) => ch,
+        None => unreachable!(),
+    }
+}
+
+/// Sorts the characters of tables by code point at compile time.
+///
+/// A character appearing more than once is classified as the first [`NumChar`] it stands for, so that 正 is [`Zheng`] rather than [`Pos`].
+const fn build_classification(
+    tables: [[&str; NUM_CHARS.len()]; 4],
+) -> [(char, NumChar); 4 * NUM_CHARS.len()] {
+    let mut entries = [(' ', Zero); 4 * NUM_CHARS.len()];
+    let mut i = 0;
+    while i < entries.len() {
+        entries[i] = (
+            single_char(tables[i / NUM_CHARS.len()][i % NUM_CHARS.len()]),
+            NUM_CHARS[i % NUM_CHARS.len()],
+        );
+        i += 1;
+    }
+
+    // insertion sort by code point, then by NumChar
+    let mut i = 1;
+    while i < entries.len() {
+        let mut j = i;
+        while j > 0 && {
+            let (a, b) = (entries[j - 1], entries[j]);
+            (a.0 as u32) > (b.0 as u32)
+                || ((a.0 as u32) == (b.0 as u32) && (a.1 as usize) > (b.1 as usize))
+        } {
+            let tmp = entries[j - 1];
+            entries[j - 1] = entries[j];
+            entries[j] = tmp;
+            j -= 1;
+        }
+        i += 1;
+    }
+
+    let mut i = 1;
+    while i < entries.len() {
+        if entries[i].0 as u32 == entries[i - 1].0 as u32 {
+            entries[i].1 = entries[i - 1].1;
+        }
+        i += 1;
+    }
+    entries
+}
+
 impl NumChar {
-    pub fn to_lowercase_simp(self) -> char {
-        match self {
-            Zero => '零',
-            One => '一',
-            Two => '二',
-            Three => '三',
-            Four => '四',
-            Five => '五',
-            Six => '六',
-            Seven => '七',
-            Eight => '八',
-            Nine => '九',
-            Shi => '十',
-            Bai => '百',
-            Qian => '千',
-            Wan => '万',
-            Yi => '亿',
-            Zhao => '兆',
-            Jing => '京',
-            Gai => '垓',
-            Zi => '秭',
-            Rang => '穰',
-            Gou => '沟',
-            Jian => '涧',
-            Zheng => '正',
-            Zai => '载',
-            Neg => '负',
+    /// All characters in order, from 零 to 点.
+    pub const ALL: [NumChar; 27] = NUM_CHARS;
+
+    /// Whether the character is a unit of 万 or above.
+    pub const fn is_myriad_unit(self) -> bool {
+        matches!(
+            self,
+            Wan | Yi | Zhao | Jing | Gai | Zi | Rang | Gou | Jian | Zheng | Zai
+        )
+    }
+
+    /// Converts the character to lowercase.
+    pub const fn to_lowercase(self, variant: Variant) -> &'static str {
+        match variant {
+            Variant::Simplified => self.to_lowercase_simp(),
+            Variant::Traditional => self.to_lowercase_trad(),
+            Variant::Japanese => LOWERCASE_JA[self as usize],
+            Variant::Korean => LOWERCASE_KO[self as usize],
+            Variant::Vietnamese => LOWERCASE_VI[self as usize],
         }
     }
 
-    pub fn to_uppercase_simp(self) -> char {
-        match self {
-            One => '壹',
-            Two => '贰',
-            Three => '叁',
-            Four => '肆',
-            Five => '伍',
-            Six => '陆',
-            Seven => '柒',
-            Eight => '捌',
-            Nine => '玖',
-            Shi => '拾',
-            Bai => '佰',
-            Qian => '仟',
-            Wan => '万',
-            _ => self.to_lowercase_simp(),
+    /// Converts the character to uppercase.
+    pub const fn to_uppercase(self, variant: Variant) -> &'static str {
+        match variant {
+            Variant::Simplified => self.to_uppercase_simp(),
+            // Vietnamese has no uppercase forms of its own, and writes those of traditional Chinese
+            Variant::Traditional | Variant::Vietnamese => self.to_uppercase_trad(),
+            Variant::Japanese => UPPERCASE_JA[self as usize],
+            Variant::Korean => UPPERCASE_KO[self as usize],
         }
     }
 
-    pub fn to_lowercase_trad(self) -> char {
-        match self {
-            Wan => '萬',
-            Yi => '億',
-            Zhao => '兆',
-            Gou => '溝',
-            Jian => '澗',
-            Zai => '載',
-            Neg => '負',
-            _ => self.to_lowercase_simp(),
+    /// Recognizes a character in lowercase or uppercase, simplified or traditional Chinese, or returns `None` for other characters.
+    ///
+    /// As 正 is both a unit and the positive sign, it is recognized as [`Zheng`]. Variant forms outside the tables, e.g. 〇 and 两, are not recognized; see [`is_digit_char`] for them.
+    ///
+    /// ```
+    /// use chinese_numerals::NumChar;
+    ///
+    /// assert_eq!(Some(NumChar::Two), NumChar::from_char('贰'));
+    /// assert_eq!(Some(NumChar::Wan), NumChar::from_char('萬'));
+    /// assert_eq!(Some(NumChar::Point), NumChar::from_char('點'));
+    /// assert_eq!(Some(NumChar::Zheng), NumChar::from_char('正'));
+    /// assert_eq!(None, NumChar::from_char('两'));
+    /// assert_eq!(None, NumChar::from_char('2'));
+    /// ```
+    pub fn from_char(ch: char) -> Option<Self> {
+        if ch.is_ascii() {
+            return None;
+        }
+        CLASSIFICATION
+            .binary_search_by_key(&ch, |&(c, _)| c)
+            .ok()
+            .map(|pos| CLASSIFICATION[pos].1)
+    }
+
+    /// Returns the Mandarin reading in pinyin with a tone number, e.g. `zhao4` for 兆.
+    pub const fn pinyin(self) -> &'static str {
+        PINYIN[self as usize]
+    }
+
+    /// Converts the character to lowercase in simplified Chinese.
+    pub const fn to_lowercase_simp(self) -> &'static str {
+        LOWERCASE_SIMP[self as usize]
+    }
+
+    /// Converts the character to uppercase in simplified Chinese.
+    pub const fn to_uppercase_simp(self) -> &'static str {
+        UPPERCASE_SIMP[self as usize]
+    }
+
+    /// Converts the character to lowercase in traditional Chinese.
+    pub const fn to_lowercase_trad(self) -> &'static str {
+        LOWERCASE_TRAD[self as usize]
+    }
+
+    /// Converts the character to uppercase in traditional Chinese.
+    pub const fn to_uppercase_trad(self) -> &'static str {
+        UPPERCASE_TRAD[self as usize]
+    }
+}
+
+/// Recognizes a digit or unit character for the classification helpers, also accepting 〇 for zero and 两 or 兩 for two.
+pub(crate) fn classify(ch: char) -> Option<NumChar> {
+    match ch {
+        '〇' => Some(Zero),
+        '两' | '兩' => Some(Two),
+        _ => NumChar::from_char(ch),
+    }
+}
+
+/// Whether the character is a digit from 零 to 九, in lowercase or uppercase, simplified or traditional Chinese, including 〇 and 两.
+///
+/// ```
+/// use chinese_numerals::is_digit_char;
+///
+/// assert!(is_digit_char('七'));
+/// assert!(is_digit_char('貳'));
+/// assert!(is_digit_char('〇'));
+/// assert!(!is_digit_char('万'));
+/// assert!(!is_digit_char('7'));
+/// ```
+pub fn is_digit_char(ch: char) -> bool {
+    classify(ch).is_some_and(|num| (num as usize) <= Nine as usize)
+}
+
+/// Whether the character is a unit from 十 to 载, in lowercase or uppercase, simplified or traditional Chinese.
+///
+/// ```
+/// use chinese_numerals::is_unit_char;
+///
+/// assert!(is_unit_char('拾'));
+/// assert!(is_unit_char('萬'));
+/// assert!(is_unit_char('正'));
+/// assert!(!is_unit_char('九'));
+/// ```
+pub fn is_unit_char(ch: char) -> bool {
+    classify(ch).is_some_and(|num| (Shi as usize..=Zai as usize).contains(&(num as usize)))
+}
+
+/// Returns the value of a digit or unit character, with units of 万 and above valued in myriad scale, or `None` for other characters and units above `u128::MAX`, i.e. 正 and 载.
+///
+/// ```
+/// use chinese_numerals::char_value;
+///
+/// assert_eq!(Some(3), char_value('叁'));
+/// assert_eq!(Some(100), char_value('佰'));
+/// assert_eq!(Some(1_0000_0000), char_value('億'));
+/// assert_eq!(None, char_value('载'));
+/// assert_eq!(None, char_value('负'));
+/// ```
+pub fn char_value(ch: char) -> Option<u128> {
+    let num = classify(ch)?;
+    match num as usize {
+        digit if digit <= Nine as usize => Some(digit as u128),
+        unit if unit <= Qian as usize => Some(10_u128.pow((unit - Nine as usize) as u32)),
+        unit if unit <= Zai as usize => {
+            10_u128.checked_pow(crate::units(crate::Scale::Myriad)[unit - Wan as usize].exponent)
         }
+        _ => None,
     }
+}
+
+/// Compatibility characters that look the same as numeral characters, i.e. Kangxi radicals and CJK compatibility ideographs, with the characters they are normalized to.
+const COMPATIBILITY: [(char, char); 9] = [
+    ('\u{2F00}', '一'),
+    ('\u{2F06}', '二'),
+    ('\u{2F0B}', '八'),
+    ('\u{2F17}', '十'),
+    ('\u{F973}', '拾'),
+    ('\u{F978}', '兩'),
+    ('\u{F9B2}', '零'),
+    ('\u{F9D1}', '六'),
+    ('\u{F9D3}', '陸'),
+];
+
+/// Folds the numeral characters of a string to lowercase simplified Chinese, for comparing and deduplicating numerals written in different cases and variants.
+///
+/// Uppercase and traditional characters are mapped back through the character tables, 〇 is folded to 零, 两 and 兩 to 二, full-width digits to ASCII digits, and compatibility characters that look the same as numeral characters, such as the Kangxi radical ⼗, to the characters they are normalized to. Other characters are kept.
+///
+/// ```
+/// use chinese_numerals::fold_numeral;
+///
+/// assert_eq!("一万零二百", fold_numeral("壹萬零貳佰"));
+/// assert_eq!(fold_numeral("二〇二四年"), fold_numeral("二零二四年"));
+/// assert_eq!("二万3千元", fold_numeral("兩萬３千元"));
+/// ```
+pub fn fold_numeral(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+    for ch in s.chars() {
+        let ch = COMPATIBILITY
+            .iter()
+            .find(|&&(compat, _)| compat == ch)
+            .map_or(ch, |&(_, normal)| normal);
+        match ch {
+            '０'..='９' => folded.push(char::from(b'0' + (ch as u32 - '０' as u32) as u8)),
+            _ => match classify(ch) {
+                Some(num) => folded.push_str(num.to_lowercase_simp()),
+                None => folded.push(ch),
+            },
+        }
+    }
+    folded
+}
+
+/// Character sets mapping [`NumChar`]s to strings, used to render regional or archaic forms, see [`Options::charset`](crate::Options::charset).
+///
+/// ```
+/// use chinese_numerals::{Case, CharacterSet, ChineseNumeral, MyriadScaleInt, NumChar, Options, Variant};
+///
+/// let charset = CharacterSet::new(Case::Upper, Variant::Simplified)
+///     .with(NumChar::One, "弌")
+///     .with(NumChar::Two, "弍")
+///     .with(NumChar::Three, "弎");
+/// let mut options = Options::default();
+/// options.case = Case::Upper;
+/// options.charset = Some(charset);
+/// assert_eq!("弌万弍仟弎佰", MyriadScaleInt::from(1_2300).to_chinese(&options));
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct CharacterSet {
+    chars: [Cow<'static, str>; NUM_CHARS.len()],
+}
+
+impl CharacterSet {
+    /// Creates a character set with the built-in characters of given case and variant.
+    pub fn new(case: Case, variant: Variant) -> Self {
+        let method = match case {
+            Case::Lower => NumChar::to_lowercase,
+            Case::Upper => NumChar::to_uppercase,
+        };
+        Self {
+            chars: NUM_CHARS.map(|ch| Cow::Borrowed(method(ch, variant))),
+        }
+    }
+
+    /// Returns the string of a character.
+    pub fn get(&self, ch: NumChar) -> &str {
+        &self.chars[ch as usize]
+    }
+
+    /// Sets the string of a character.
+    pub fn set(&mut self, ch: NumChar, s: impl Into<Cow<'static, str>>) {
+        self.chars[ch as usize] = s.into();
+    }
+
+    /// Returns the character set with the string of a character replaced.
+    pub fn with(mut self, ch: NumChar, s: impl Into<Cow<'static, str>>) -> Self {
+        self.set(ch, s);
+        self
+    }
+
+    /// Returns the character set with the characters in an override map replaced, e.g. bank-specific glyphs.
+    ///
+    /// ```
+    /// use chinese_numerals::{Case, CharacterSet, ChineseNumeral, MyriadScaleInt, NumChar, Options, Variant};
+    /// use std::collections::HashMap;
+    ///
+    /// let overrides = HashMap::from([(NumChar::Three, '參'), (NumChar::Six, '陸')]);
+    /// let mut options = Options::default();
+    /// options.case = Case::Upper;
+    /// options.charset = Some(CharacterSet::new(Case::Upper, Variant::Traditional).with_overrides(&overrides));
+    /// assert_eq!("參拾陸", MyriadScaleInt::from(36).to_chinese(&options));
+    /// ```
+    pub fn with_overrides(mut self, overrides: &HashMap<NumChar, char>) -> Self {
+        for (&ch, &glyph) in overrides {
+            self.set(ch, glyph.to_string());
+        }
+        self
+    }
+}
+
+/// Profiles of uppercase glyphs, including historical forms for museums and historical documents.
+///
+/// ```
+/// use chinese_numerals::{Case, ChineseNumeral, GlyphProfile, MyriadScaleInt, Options, Variant};
+///
+/// let mut options = Options::default();
+/// options.case = Case::Upper;
+/// options.charset = Some(GlyphProfile::Ancient.charset(Variant::Simplified));
+/// assert_eq!("弌万弍仟弎佰", MyriadScaleInt::from(1_2300).to_chinese(&options));
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum GlyphProfile {
+    /// Standard uppercase characters, e.g. 壹贰叁, 佰, and 仟.
+    #[default]
+    Standard,
+    /// Ancient uppercase characters 弌, 弍, and 弎 for one, two, and three.
+    Ancient,
+    /// Alternative unit forms 陌 and 阡 for hundred and thousand.
+    Alternative,
+}
 
-    pub fn to_uppercase_trad(self) -> char {
+impl GlyphProfile {
+    /// Returns the uppercase character set of the profile in given variant.
+    pub fn charset(self, variant: Variant) -> CharacterSet {
+        let charset = CharacterSet::new(Case::Upper, variant);
         match self {
-            Two => '貳',
-            Three => '叄',
-            Six => '陸',
-            Wan | Yi | Zhao | Gou | Jian | Zai | Neg => self.to_lowercase_trad(),
-            _ => self.to_uppercase_simp(),
+            GlyphProfile::Standard => charset,
+            GlyphProfile::Ancient => charset.with(One, "弌").with(Two, "弍").with(Three, "弎"),
+            GlyphProfile::Alternative => charset.with(Bai, "陌").with(Qian, "阡"),
         }
     }
 }
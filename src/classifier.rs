@@ -0,0 +1,51 @@
+use crate::{characters::NumChar, Case, ChineseNumeral, Options, Sign, Variant};
+
+/// Converts a number followed by a classifier (量词), e.g. 三十五个, choosing 两 over 二 as spoken before classifiers.
+///
+/// In lowercase Chinese, the number 2 and a leading 二 before 百, 千, and units of 万 and above are read as 两 (兩 in traditional Chinese), e.g. 两个 and 两千只, while other 二 are kept, e.g. 十二张. With [`Options::colloquial`], 两个 and 三个 are contracted to 俩 (倆) and 仨. Uppercase numerals are kept as they are.
+///
+/// ```
+/// use chinese_numerals::{with_classifier, MyriadScaleInt, Options};
+///
+/// let options = Options::default();
+/// assert_eq!("三十五个", with_classifier(&MyriadScaleInt::from(35), "个", &options));
+/// assert_eq!("两只", with_classifier(&MyriadScaleInt::from(2), "只", &options));
+/// assert_eq!("两千二百张", with_classifier(&MyriadScaleInt::from(2200), "张", &options));
+/// assert_eq!("二十二个", with_classifier(&MyriadScaleInt::from(22), "个", &options));
+///
+/// let mut options = Options::default();
+/// options.colloquial = true;
+/// assert_eq!("俩", with_classifier(&MyriadScaleInt::from(2), "个", &options));
+/// assert_eq!("两千二张", with_classifier(&MyriadScaleInt::from(2200), "张", &options));
+/// ```
+pub fn with_classifier<T: ChineseNumeral>(num: &T, classifier: &str, options: &Options) -> String {
+    let liang = match (options.case, options.variant) {
+        (Case::Lower, Variant::Simplified) => "两",
+        (Case::Lower, Variant::Traditional) => "兩",
+        _ => return num.to_chinese(options) + classifier,
+    };
+    let chars = num.to_chars();
+    if options.colloquial && matches!(classifier, "个" | "個") && num.sign() == Sign::Pos {
+        match (chars.as_slice(), options.variant) {
+            ([NumChar::Two], Variant::Simplified) => return "俩".to_owned(),
+            ([NumChar::Two], _) => return "倆".to_owned(),
+            ([NumChar::Three], _) => return "仨".to_owned(),
+            _ => {}
+        }
+    }
+
+    let mut output = num.to_chinese(options);
+    // the leading digit is the last one, as the characters are in reversed order
+    let leading_two = match chars.as_slice() {
+        [NumChar::Two] => true,
+        [.., unit, NumChar::Two] => *unit != NumChar::Shi,
+        _ => false,
+    };
+    if leading_two {
+        let two = options.glyph(NumChar::Two);
+        if let Some(pos) = output.find(two) {
+            output.replace_range(pos..pos + two.len(), liang);
+        }
+    }
+    output + classifier
+}
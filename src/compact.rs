@@ -0,0 +1,74 @@
+use crate::{
+    characters::NumChar, ChineseInt, Error, Long, LongScaleInt, Mid, MidScaleInt, Myriad,
+    MyriadScaleInt, Scale, ScaleBase, ScaleKind, Sign,
+};
+
+macro_rules! impl_compact {
+    ($($marker:ident, $num:ident, $abs:ty, $wide:ident, $wide_num:ident, $name:literal),+ $(,)?) => {
+        $(#[doc = concat!("Marker type of ", $name, " with a `", stringify!($abs), "` absolute value for [`ChineseInt`].")]
+        #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+        pub struct $marker;
+
+        impl ScaleKind for $marker {}
+
+        #[doc = concat!("Integers in ", $name, " with a `", stringify!($abs), "` absolute value, smaller to copy than [`", stringify!($wide_num), "`] for callers formatting small values only.")]
+        pub type $num = ChineseInt<$marker>;
+
+        impl $num {
+            #[doc = concat!("The maximum integer can be expressed in ", $name, " with a `", stringify!($abs), "` absolute value.")]
+            pub const MAX: Self = Self {
+                sign: Sign::Pos,
+                data: <$abs>::MAX,
+            };
+
+            #[doc = concat!("The minimum integer can be expressed in ", $name, " with a `", stringify!($abs), "` absolute value.")]
+            pub const MIN: Self = Self {
+                sign: Sign::Neg,
+                data: <$abs>::MAX,
+            };
+        }
+
+        impl ScaleBase for $marker {
+            type Abs = $abs;
+
+            const SCALE: Scale = <$wide as ScaleBase>::SCALE;
+            const MAX_ABS: $abs = <$abs>::MAX;
+
+            fn push_abs(num: $abs, chars: &mut Vec<NumChar>) {
+                $wide::push_abs(num.into(), chars);
+            }
+        }
+
+        impl From<$num> for $wide_num {
+            fn from(num: $num) -> Self {
+                Self {
+                    sign: num.sign,
+                    data: num.data.into(),
+                }
+            }
+        }
+
+        impl TryFrom<$wide_num> for $num {
+            type Error = Error;
+
+            /// Narrows the absolute value, or returns [`Error`] if it does not fit.
+            fn try_from(num: $wide_num) -> Result<Self, Self::Error> {
+                let data = <$abs>::try_from(num.data)
+                    .map_err(|_| Error::out_of_range(<$wide as ScaleBase>::SCALE, num.data))?;
+                Ok(Self {
+                    sign: num.sign,
+                    data,
+                })
+            }
+        })+
+    };
+}
+
+impl_compact! {
+    Myriad32, MyriadScaleInt32, u32, Myriad, MyriadScaleInt, "myriad scale",
+    Myriad64, MyriadScaleInt64, u64, Myriad, MyriadScaleInt, "myriad scale",
+    Mid32, MidScaleInt32, u32, Mid, MidScaleInt, "mid-scale",
+    Mid64, MidScaleInt64, u64, Mid, MidScaleInt, "mid-scale",
+    Long32, LongScaleInt32, u32, Long, LongScaleInt, "long scale",
+    Long64, LongScaleInt64, u64, Long, LongScaleInt, "long scale",
+}
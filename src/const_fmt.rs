@@ -0,0 +1,272 @@
+use crate::{characters::*, Case, Scale, ShortScaleInt, Variant};
+use NumChar::*;
+
+/// Maximum number of characters of a numeral formatted at compile time.
+const MAX_CHARS: usize = 128;
+
+/// Characters of a number in reversed order, collected at compile time.
+struct ConstChars {
+    chars: [NumChar; MAX_CHARS],
+    len: usize,
+}
+
+impl ConstChars {
+    const fn push(&mut self, ch: NumChar) {
+        self.chars[self.len] = ch;
+        self.len += 1;
+    }
+
+    /// Collects the characters of an absolute value, the same as `to_chars` of the scale structs.
+    const fn push_abs(&mut self, mut num: u128, scale: Scale) {
+        let (base, first_exp, zero_below) = match scale {
+            Scale::Short => (10, 9, 1),
+            Scale::Myriad => (1_0000, 12, 1000),
+            Scale::Mid => (1_0000_0000, 13, 1000_0000),
+            Scale::Long => (1_0000_0000_0000_0000, 14, 1000_0000_0000_0000),
+        };
+        let start = self.len;
+        let mut prev_rem = zero_below;
+        let mut exp = first_exp;
+        while num > 0 {
+            let rem = num % base;
+            num /= base;
+
+            if rem > 0 {
+                if self.len > start && prev_rem < zero_below {
+                    self.push(Zero);
+                }
+                if exp > first_exp {
+                    self.push(NUM_CHARS[exp]);
+                }
+                match scale {
+                    Scale::Short => self.push(NUM_CHARS[rem as usize]),
+                    Scale::Myriad => self.push_abs(rem, Scale::Short),
+                    Scale::Mid => self.push_abs(rem, Scale::Myriad),
+                    Scale::Long => self.push_abs(rem, Scale::Mid),
+                }
+            }
+            prev_rem = rem;
+            exp += 1;
+        }
+    }
+}
+
+/// Characters of a group below 10000 in reversed order, e.g. 九千九百九十九.
+#[derive(Clone, Copy)]
+pub(crate) struct Group {
+    chars: [NumChar; 7],
+    len: u8,
+}
+
+impl Group {
+    /// Returns the characters in reversed order.
+    pub(crate) fn chars(&self) -> &[NumChar] {
+        &self.chars[..self.len as usize]
+    }
+}
+
+/// Characters of all groups from 0 to 9999, the same as `to_chars` of short scale numbers, computed at compile time.
+pub(crate) static GROUPS: [Group; 1_0000] = {
+    let mut groups = [Group {
+        chars: [Zero; 7],
+        len: 0,
+    }; 1_0000];
+    let mut num = 0;
+    while num < 1_0000 {
+        let mut chars = ConstChars {
+            chars: [Zero; MAX_CHARS],
+            len: 0,
+        };
+        chars.push_abs(num as u128, Scale::Short);
+        let group = &mut groups[num];
+        let mut i = 0;
+        while i < chars.len {
+            group.chars[i] = chars.chars[i];
+            i += 1;
+        }
+        group.len = chars.len as u8;
+        num += 1;
+    }
+    groups
+};
+
+/// A Chinese numeral stored inline without heap allocation, formatted by [`format_const`], [`chinese!`](crate::chinese), or `to_array_string` of the structs backed by primitive integers.
+#[derive(Clone, Copy, Debug)]
+pub struct ConstStr {
+    bytes: [u8; 4 * MAX_CHARS],
+    len: usize,
+}
+
+impl ConstStr {
+    /// Returns the numeral as a string slice.
+    pub const fn as_str(&self) -> &str {
+        let (bytes, _) = self.bytes.split_at(self.len);
+        match std::str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl std::ops::Deref for ConstStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ConstStr {
+    /// Writes the numeral, padded to the width on the left by default, the same as the integer structs.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::macros::write_padded(self.as_str(), f)
+    }
+}
+
+/// Formats an integer to Chinese numerals in a `const` context, so that fixed labels cost nothing at runtime.
+///
+/// The output is the same as [`to_lowercase`](crate::ChineseNumeral::to_lowercase) or [`to_uppercase`](crate::ChineseNumeral::to_uppercase) of [`ShortScaleInt`], [`MyriadScaleInt`](crate::MyriadScaleInt), [`MidScaleInt`](crate::MidScaleInt), or [`LongScaleInt`](crate::LongScaleInt). Panics, or fails to compile in a `const` context, if the value is out of range of short scale.
+///
+/// ```
+/// use chinese_numerals::{format_const, Case, ConstStr, Scale, Variant};
+///
+/// const TWELVE: ConstStr = format_const(12, Scale::Myriad, Case::Lower, Variant::Simplified);
+/// assert_eq!("十二", TWELVE.as_str());
+/// ```
+pub const fn format_const(value: i128, scale: Scale, case: Case, variant: Variant) -> ConstStr {
+    let abs = value.unsigned_abs();
+    if let Scale::Short = scale {
+        assert!(
+            abs <= ShortScaleInt::MAX_ABS as u128,
+            "Absolute value out of range for a short scale number"
+        );
+    }
+    format_signed(value < 0, abs, scale, case, variant)
+}
+
+/// Formats a sign and an absolute value in range of the scale to an inline string.
+pub(crate) const fn format_signed(
+    neg: bool,
+    abs: u128,
+    scale: Scale,
+    case: Case,
+    variant: Variant,
+) -> ConstStr {
+    let mut chars = ConstChars {
+        chars: [Zero; MAX_CHARS],
+        len: 0,
+    };
+    chars.push_abs(abs, scale);
+    if let Case::Lower = case {
+        // trims the leading 一 of 十 in the highest group
+        let mut head = abs;
+        while !matches!(scale, Scale::Short) && head >= 1_0000 {
+            head /= 1_0000;
+        }
+        if head >= 10 && head <= 19 {
+            chars.len -= 1;
+        }
+    }
+    if neg {
+        chars.push(Neg);
+    } else if abs == 0 {
+        chars.push(Zero);
+    }
+
+    let omit_zeros_and_ones = matches!(variant, Variant::Japanese | Variant::Korean);
+    let mut numeral = ConstStr {
+        bytes: [0; 4 * MAX_CHARS],
+        len: 0,
+    };
+    let mut i = chars.len;
+    while i > 0 {
+        i -= 1;
+        let ch = chars.chars[i];
+        if omit_zeros_and_ones {
+            let omitted = match ch {
+                Zero => chars.len > 1,
+                One => {
+                    matches!(case, Case::Lower)
+                        && i > 0
+                        && matches!(chars.chars[i - 1], Shi | Bai | Qian)
+                }
+                _ => false,
+            };
+            if omitted {
+                continue;
+            }
+        }
+        let glyph = match case {
+            Case::Lower => ch.to_lowercase(variant),
+            Case::Upper => ch.to_uppercase(variant),
+        };
+        let mut j = 0;
+        while j < glyph.len() {
+            numeral.bytes[numeral.len] = glyph.as_bytes()[j];
+            numeral.len += 1;
+            j += 1;
+        }
+    }
+    numeral
+}
+
+/// Formats an integer literal or constant to Chinese numerals at compile time, returning a `&'static str`.
+///
+/// The scale, case, and variant are given as `short`, `myriad`, `mid`, or `long`; `lower` or `upper`; and `simp`, `trad`, `ja`, `ko`, or `vi`. They default to myriad scale, lowercase, and simplified Chinese.
+///
+/// ```
+/// use chinese_numerals::chinese;
+///
+/// const CHAPTER: &str = chinese!(12);
+/// assert_eq!("十二", CHAPTER);
+/// assert_eq!("壹萬零貳佰", chinese!(10200, mid, upper, trad));
+/// ```
+#[macro_export]
+macro_rules! chinese {
+    (@scale short) => {
+        $crate::Scale::Short
+    };
+    (@scale myriad) => {
+        $crate::Scale::Myriad
+    };
+    (@scale mid) => {
+        $crate::Scale::Mid
+    };
+    (@scale long) => {
+        $crate::Scale::Long
+    };
+    (@case lower) => {
+        $crate::Case::Lower
+    };
+    (@case upper) => {
+        $crate::Case::Upper
+    };
+    (@variant simp) => {
+        $crate::Variant::Simplified
+    };
+    (@variant trad) => {
+        $crate::Variant::Traditional
+    };
+    (@variant ja) => {
+        $crate::Variant::Japanese
+    };
+    (@variant ko) => {
+        $crate::Variant::Korean
+    };
+    (@variant vi) => {
+        $crate::Variant::Vietnamese
+    };
+    ($value:expr) => {
+        $crate::chinese!($value, myriad, lower, simp)
+    };
+    ($value:expr, $scale:ident, $case:ident, $variant:ident) => {{
+        const NUMERAL: $crate::ConstStr = $crate::format_const(
+            $value,
+        $crate::chinese!(@scale $scale),
+        $crate::chinese!(@case $case),
+        $crate::chinese!(@variant $variant),
+        );
+        const STR: &str = NUMERAL.as_str();
+        STR
+    }};
+}
@@ -0,0 +1,63 @@
+use crate::{
+    ChineseNumeral, DecimalStr, Error, Formatter, Long, Mid, Myriad, MyriadScaleInt, Options,
+    Scale, ShortScaleInt,
+};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Converts a decimal string to Chinese numerals in the given scale, without choosing the struct to hold the number.
+///
+/// The string is parsed by [`FromStr`](std::str::FromStr) of [`ShortScaleInt`] for short scale, and [`DecimalStr`] for other scales, so that all integers in range can be converted even without feature "bigint".
+///
+/// ```
+/// use chinese_numerals::{convert_str, Options, Scale};
+///
+/// assert_eq!(
+///     "一亿零二百零三万零四百零五",
+///     convert_str("1_0203_0405", Scale::Mid, &Options::default()).unwrap()
+/// );
+/// ```
+pub fn convert_str(value: &str, scale: Scale, options: &Options) -> Result<String, Error> {
+    Ok(match scale {
+        Scale::Short => value.parse::<ShortScaleInt>()?.to_chinese(options),
+        Scale::Myriad => value.parse::<DecimalStr<Myriad>>()?.to_chinese(options),
+        Scale::Mid => value.parse::<DecimalStr<Mid>>()?.to_chinese(options),
+        Scale::Long => value.parse::<DecimalStr<Long>>()?.to_chinese(options),
+    })
+}
+
+/// Converts a batch of integers to Chinese numerals in myriad scale with the same options, the same as [`to_chinese`](ChineseNumeral::to_chinese) of [`MyriadScaleInt`] for each.
+///
+/// A [`Formatter`] is reused for the numbers, so that the character buffer is shared. With feature "rayon", the numbers are formatted in parallel, with a formatter for each worker.
+///
+/// ```
+/// use chinese_numerals::{convert_batch, Options};
+///
+/// assert_eq!(
+///     vec!["十二", "负三百四十五", "零"],
+///     convert_batch(&[12, -345, 0], &Options::default())
+/// );
+/// ```
+pub fn convert_batch(values: &[i128], options: &Options) -> Vec<String> {
+    let format = |formatter: &mut Formatter, &value: &i128| {
+        let mut output = String::new();
+        formatter.format_into(&mut output, &MyriadScaleInt::from(value));
+        output
+    };
+    #[cfg(feature = "rayon")]
+    {
+        values
+            .par_iter()
+            .map_init(|| Formatter::new(options.clone()), format)
+            .collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        let mut formatter = Formatter::new(options.clone());
+        values
+            .iter()
+            .map(|value| format(&mut formatter, value))
+            .collect()
+    }
+}
@@ -0,0 +1,307 @@
+use crate::digits::{char_to_digit, digit_to_str};
+use crate::{ChineseNumeral, MyriadScaleInt, Variant};
+
+/// Formats a year digit by digit, e.g. 二〇〇五年.
+///
+/// ```
+/// use chinese_numerals::format_year;
+///
+/// assert_eq!("二〇〇五年", format_year(2005));
+/// ```
+pub fn format_year(year: u32) -> String {
+    let mut chars = year_digits(year);
+    chars.push('年');
+    chars
+}
+
+/// Formats the digits of a year one by one, without 年.
+fn year_digits(year: u32) -> String {
+    year.to_string()
+        .chars()
+        .filter_map(|ch| ch.to_digit(10))
+        .map(digit_to_str)
+        .collect()
+}
+
+/// Splits an astronomical year into whether it is before the common era and the digits of the year in its era.
+fn era_digits(year: i32) -> (bool, String) {
+    if year > 0 {
+        (false, year_digits(year.unsigned_abs()))
+    } else {
+        // 0 is 1 BCE, -1 is 2 BCE, and so on
+        (true, year_digits(year.unsigned_abs() + 1))
+    }
+}
+
+/// Formats an astronomical year with its era, where years up to 0 are years before the common era, e.g. 公元前二〇二年 for -201.
+///
+/// The years are numbered as in `chrono` and `time`, where 0 is 1 BCE. Years of the common era are formatted as [`format_year`] does.
+///
+/// ```
+/// use chinese_numerals::format_era_year;
+///
+/// assert_eq!("公元前二〇二年", format_era_year(-201));
+/// assert_eq!("公元前一年", format_era_year(0));
+/// assert_eq!("一九四九年", format_era_year(1949));
+/// ```
+pub fn format_era_year(year: i32) -> String {
+    match era_digits(year) {
+        (true, digits) => format!("公元前{digits}年"),
+        (false, digits) => format!("{digits}年"),
+    }
+}
+
+/// Formats a range of astronomical years joined by 至, where years up to 0 are years before the common era as in [`format_era_year`], e.g. 一九四九至一九七八年.
+///
+/// 年 is written once for years of the same era, with 前 repeated for years before the common era. A range across eras writes both eras in full.
+///
+/// ```
+/// use chinese_numerals::format_year_range;
+///
+/// assert_eq!("一九四九至一九七八年", format_year_range(1949, 1978));
+/// assert_eq!("公元前二〇二至前一九五年", format_year_range(-201, -194));
+/// assert_eq!("公元前二〇二年至公元八年", format_year_range(-201, 8));
+/// ```
+pub fn format_year_range(start: i32, end: i32) -> String {
+    match (era_digits(start), era_digits(end)) {
+        ((true, start), (true, end)) => format!("公元前{start}至前{end}年"),
+        ((false, start), (false, end)) => format!("{start}至{end}年"),
+        ((true, start), (false, end)) => format!("公元前{start}年至公元{end}年"),
+        ((false, start), (true, end)) => format!("公元{start}年至公元前{end}年"),
+    }
+}
+
+/// Formats the contraction of a year with its last two digits, e.g. 〇五年.
+///
+/// ```
+/// use chinese_numerals::format_two_digit_year;
+///
+/// assert_eq!("〇五年", format_two_digit_year(2005));
+/// assert_eq!("九七年", format_two_digit_year(1997));
+/// ```
+pub fn format_two_digit_year(year: u32) -> String {
+    let year = year % 100;
+    [digit_to_str(year / 10), digit_to_str(year % 10), "年"].concat()
+}
+
+/// Expands a two-digit year to the year in the 100-year window starting from `pivot`.
+///
+/// Returns [`None`] if the two-digit year is larger than 99.
+///
+/// ```
+/// use chinese_numerals::expand_two_digit_year;
+///
+/// assert_eq!(Some(2005), expand_two_digit_year(5, 1950));
+/// assert_eq!(Some(1997), expand_two_digit_year(97, 1950));
+/// ```
+pub fn expand_two_digit_year(two_digit: u32, pivot: i32) -> Option<i32> {
+    if two_digit > 99 {
+        return None;
+    }
+    let year = pivot - pivot.rem_euclid(100) + two_digit as i32;
+    if year < pivot {
+        Some(year + 100)
+    } else {
+        Some(year)
+    }
+}
+
+/// Parses the contraction of a year like 〇五年 or 零五, and expands it to the year in the 100-year window starting from `pivot`.
+///
+/// The trailing 年 is optional. Returns [`None`] if the string is not a two-digit year.
+///
+/// ```
+/// use chinese_numerals::parse_two_digit_year;
+///
+/// assert_eq!(Some(2005), parse_two_digit_year("〇五年", 1950));
+/// assert_eq!(Some(2005), parse_two_digit_year("零五", 1950));
+/// assert_eq!(Some(1905), parse_two_digit_year("〇五年", 1900));
+/// ```
+pub fn parse_two_digit_year(s: &str, pivot: i32) -> Option<i32> {
+    let s = s.strip_suffix('年').unwrap_or(s);
+    let mut chars = s.chars();
+    let tens = char_to_digit(chars.next()?)?;
+    let ones = char_to_digit(chars.next()?)?;
+    if chars.next().is_some() {
+        return None;
+    }
+    expand_two_digit_year(tens * 10 + ones, pivot)
+}
+
+/// Formats a date with the year digit by digit, e.g. 二〇二四年三月十五日.
+///
+/// The year is astronomical, and written with its era as [`format_era_year`] does, e.g. 公元前四四年 for -43.
+///
+/// ```
+/// use chinese_numerals::format_date;
+///
+/// assert_eq!("二〇二四年三月十五日", format_date(2024, 3, 15));
+/// assert_eq!("一九九七年十二月二十一日", format_date(1997, 12, 21));
+/// assert_eq!("公元前四四年三月十五日", format_date(-43, 3, 15));
+/// ```
+pub fn format_date(year: i32, month: u32, day: u32) -> String {
+    let mut date = format_era_year(year);
+    date.push_str(&MyriadScaleInt::from(month).to_lowercase_simp());
+    date.push('月');
+    date.push_str(&MyriadScaleInt::from(day).to_lowercase_simp());
+    date.push('日');
+    date
+}
+
+/// Formats a weekday counted from Monday as 0 to Sunday as 6, e.g. 星期三, or returns `None` for other numbers. See [`weekday_name`] for other styles.
+///
+/// ```
+/// use chinese_numerals::format_weekday;
+///
+/// assert_eq!(Some("星期三".to_owned()), format_weekday(2));
+/// assert_eq!(Some("星期日".to_owned()), format_weekday(6));
+/// assert_eq!(None, format_weekday(7));
+/// ```
+pub fn format_weekday(days_from_monday: u32) -> Option<String> {
+    weekday_name(
+        days_from_monday.checked_add(1)?,
+        WeekdayStyle::Xingqi,
+        Variant::Simplified,
+    )
+}
+
+/// Prefixes of weekday names, see [`weekday_name`].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub enum WeekdayStyle {
+    /// 星期, e.g. 星期一. Default.
+    #[default]
+    Xingqi,
+    /// 周 (週 in traditional Chinese), e.g. 周一.
+    Zhou,
+}
+
+/// Returns the name of a weekday numbered from Monday as 1 to Sunday as 7, e.g. 星期一 or 周日, or `None` for other numbers.
+///
+/// ```
+/// use chinese_numerals::{weekday_name, Variant, WeekdayStyle};
+///
+/// assert_eq!(Some("星期一".to_owned()), weekday_name(1, WeekdayStyle::Xingqi, Variant::Simplified));
+/// assert_eq!(Some("周日".to_owned()), weekday_name(7, WeekdayStyle::Zhou, Variant::Simplified));
+/// assert_eq!(Some("週六".to_owned()), weekday_name(6, WeekdayStyle::Zhou, Variant::Traditional));
+/// assert_eq!(None, weekday_name(8, WeekdayStyle::Xingqi, Variant::Simplified));
+/// ```
+pub fn weekday_name(weekday: u32, style: WeekdayStyle, variant: Variant) -> Option<String> {
+    let prefix = match (style, variant) {
+        (WeekdayStyle::Xingqi, _) => "星期",
+        (WeekdayStyle::Zhou, Variant::Simplified) => "周",
+        (WeekdayStyle::Zhou, _) => "週",
+    };
+    match weekday {
+        1..=6 => Some(format!("{prefix}{}", digit_to_str(weekday))),
+        7 => Some(format!("{prefix}日")),
+        _ => None,
+    }
+}
+
+/// Returns the name of a month from 1 to 12, e.g. 一月 or 十二月, or `None` for other numbers.
+///
+/// ```
+/// use chinese_numerals::{month_name, Variant};
+///
+/// assert_eq!(Some("三月".to_owned()), month_name(3, Variant::Simplified));
+/// assert_eq!(Some("十二月".to_owned()), month_name(12, Variant::Traditional));
+/// assert_eq!(None, month_name(13, Variant::Simplified));
+/// ```
+pub fn month_name(month: u32, variant: Variant) -> Option<String> {
+    match month {
+        1..=12 => Some(MyriadScaleInt::from(month).to_lowercase(variant) + "月"),
+        _ => None,
+    }
+}
+
+/// Dates of date and time crates, formatted to Chinese dates, with features `chrono` and `time`.
+///
+/// ```
+/// # #[cfg(feature = "chrono")]
+/// # {
+/// use chinese_numerals::ToChineseDate;
+/// use chrono::NaiveDate;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+/// assert_eq!("二〇二四年三月十五日", date.to_chinese_date());
+/// assert_eq!("星期五", date.to_chinese_weekday());
+/// # }
+/// ```
+pub trait ToChineseDate {
+    /// Formats the date with the year digit by digit, see [`format_date`].
+    fn to_chinese_date(&self) -> String;
+
+    /// Formats the weekday of the date, see [`format_weekday`].
+    fn to_chinese_weekday(&self) -> String;
+}
+
+#[cfg(feature = "chrono")]
+impl ToChineseDate for chrono::NaiveDate {
+    fn to_chinese_date(&self) -> String {
+        use chrono::Datelike;
+        format_date(self.year(), self.month(), self.day())
+    }
+
+    fn to_chinese_weekday(&self) -> String {
+        use chrono::Datelike;
+        format_weekday(self.weekday().num_days_from_monday()).expect("Weekday is at most 6")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToChineseDate for chrono::NaiveDateTime {
+    fn to_chinese_date(&self) -> String {
+        self.date().to_chinese_date()
+    }
+
+    fn to_chinese_weekday(&self) -> String {
+        self.date().to_chinese_weekday()
+    }
+}
+
+/// Formats the local date of the time zone.
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> ToChineseDate for chrono::DateTime<Tz> {
+    fn to_chinese_date(&self) -> String {
+        self.date_naive().to_chinese_date()
+    }
+
+    fn to_chinese_weekday(&self) -> String {
+        self.date_naive().to_chinese_weekday()
+    }
+}
+
+#[cfg(feature = "time")]
+impl ToChineseDate for time::Date {
+    fn to_chinese_date(&self) -> String {
+        format_date(self.year(), self.month() as u32, self.day() as u32)
+    }
+
+    fn to_chinese_weekday(&self) -> String {
+        format_weekday(self.weekday().number_days_from_monday() as u32)
+            .expect("Weekday is at most 6")
+    }
+}
+
+#[cfg(feature = "time")]
+impl ToChineseDate for time::PrimitiveDateTime {
+    fn to_chinese_date(&self) -> String {
+        self.date().to_chinese_date()
+    }
+
+    fn to_chinese_weekday(&self) -> String {
+        self.date().to_chinese_weekday()
+    }
+}
+
+/// Formats the date at the offset.
+#[cfg(feature = "time")]
+impl ToChineseDate for time::OffsetDateTime {
+    fn to_chinese_date(&self) -> String {
+        self.date().to_chinese_date()
+    }
+
+    fn to_chinese_weekday(&self) -> String {
+        self.date().to_chinese_weekday()
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{characters::*, default_options, parse::split_decimal, Case, ChineseNumeral, Error};
+use crate::{macros::write_padded, MyriadScaleInt, ParseError, Variant};
+use std::str::FromStr;
+
+/// A decimal number, whose integer part is read by the rules of a scale struct, and whose digits after the decimal point are read one by one after 点.
+///
+/// It is parsed from a decimal string, or converted from `rust_decimal::Decimal` with feature `rust_decimal` and `bigdecimal::BigDecimal` with feature `bigdecimal`. Trailing zeros after the decimal point are kept, since they are significant in financial records. The digits after the decimal point are limited by [`ChineseDecimal::round`] with a [`Rounding`] mode.
+///
+/// ```
+/// use chinese_numerals::{ChineseDecimal, MidScaleInt, Variant};
+///
+/// let num: ChineseDecimal = "-12.50".parse().unwrap();
+/// assert_eq!("负十二点五零", num.to_string());
+/// assert_eq!("负壹拾贰点伍零", format!("{:#}", num));
+///
+/// let num: ChineseDecimal<MidScaleInt> = "100000000.25".parse().unwrap();
+/// assert_eq!("一億點二五", num.to_lowercase(Variant::Traditional));
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct ChineseDecimal<T = MyriadScaleInt> {
+    neg: bool,
+    int: T,
+    int_digits: String,
+    frac: String,
+}
+
+/// Rounding modes for the digits after the decimal point of [`ChineseDecimal`].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub enum Rounding {
+    /// Rounds half away from zero, e.g. 2.5 to 3 and -2.5 to -3, as in financial records. Default.
+    #[default]
+    HalfUp,
+    /// Rounds half to even, e.g. 2.5 to 2 and 3.5 to 4, as in scientific computing.
+    HalfEven,
+    /// Drops the extra digits, e.g. 2.59 to 2.5.
+    Truncate,
+}
+
+impl<T: ChineseNumeral + FromStr<Err = Error>> ChineseDecimal<T> {
+    /// Creates a decimal from a sign, the digits of the integer part, and the digits after the decimal point.
+    fn new(neg: bool, int: &str, frac: &str) -> Result<Self, Error> {
+        if let Some(ch) = frac.chars().find(|ch| !ch.is_ascii_digit()) {
+            return Err(ParseError::InvalidChar(ch).into());
+        }
+        let nonzero = |digits: &str| digits.bytes().any(|digit| digit != b'0');
+        let neg = neg && (nonzero(int) || nonzero(frac));
+        let int_digits = match int.trim_start_matches('0') {
+            "" => "0",
+            digits => digits,
+        };
+        Ok(Self {
+            neg,
+            int: T::from_str(int_digits)?,
+            int_digits: int_digits.to_owned(),
+            frac: frac.to_owned(),
+        })
+    }
+
+    /// Rounds the decimal to at most `digits` digits after the decimal point with the rounding mode. Decimals with fewer digits are kept as they are.
+    ///
+    /// Returns [`Error`] if the integer part is out of range after carrying.
+    ///
+    /// ```
+    /// use chinese_numerals::{ChineseDecimal, Rounding};
+    ///
+    /// let num: ChineseDecimal = "2.345".parse().unwrap();
+    /// assert_eq!("二点三五", num.round(2, Rounding::HalfUp).unwrap().to_string());
+    /// assert_eq!("二点三四", num.round(2, Rounding::HalfEven).unwrap().to_string());
+    /// assert_eq!("二点三", num.round(1, Rounding::Truncate).unwrap().to_string());
+    /// ```
+    pub fn round(&self, digits: usize, rounding: Rounding) -> Result<Self, Error> {
+        if self.frac.len() <= digits {
+            return Self::new(self.neg, &self.int_digits, &self.frac);
+        }
+        let (kept, dropped) = self.frac.split_at(digits);
+        let mut all = format!("{}{kept}", self.int_digits).into_bytes();
+        let (first, rest) = (dropped.as_bytes()[0], &dropped[1..]);
+        let round_up = match rounding {
+            Rounding::HalfUp => first >= b'5',
+            Rounding::HalfEven if first == b'5' && rest.bytes().all(|digit| digit == b'0') => {
+                all.last().is_some_and(|digit| digit % 2 == 1)
+            }
+            Rounding::HalfEven => first >= b'5',
+            Rounding::Truncate => false,
+        };
+        if round_up {
+            match all.iter().rposition(|&digit| digit != b'9') {
+                Some(pos) => {
+                    all[pos] += 1;
+                    all[pos + 1..].fill(b'0');
+                }
+                None => {
+                    all.fill(b'0');
+                    all.insert(0, b'1');
+                }
+            }
+        }
+        let all = String::from_utf8(all).expect("decimal digits are ASCII");
+        let (int, frac) = all.split_at(all.len() - kept.len());
+        Self::new(self.neg, int, frac)
+    }
+
+    /// Creates a decimal from a sign and the digits of an unscaled value divided by 10 to the power of `scale`.
+    #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+    fn from_scaled(neg: bool, digits: &str, scale: i64) -> Result<Self, Error> {
+        match usize::try_from(scale) {
+            Ok(scale) if digits.len() > scale => {
+                let (int, frac) = digits.split_at(digits.len() - scale);
+                Self::new(neg, int, frac)
+            }
+            Ok(scale) => {
+                let frac = format!("{digits:0>scale$}");
+                Self::new(neg, "0", &frac)
+            }
+            Err(_) => {
+                let zeros =
+                    usize::try_from(scale.unsigned_abs()).map_err(|_| ParseError::Overflow)?;
+                Self::new(neg, &format!("{digits}{}", "0".repeat(zeros)), "")
+            }
+        }
+    }
+}
+
+impl<T: ChineseNumeral> ChineseDecimal<T> {
+    /// Whether the number is negative.
+    pub fn is_negative(&self) -> bool {
+        self.neg
+    }
+
+    /// Returns the integer part without the sign.
+    pub fn int(&self) -> &T {
+        &self.int
+    }
+
+    /// Returns the digits after the decimal point.
+    pub fn frac_digits(&self) -> &str {
+        &self.frac
+    }
+
+    /// Converts the decimal to lowercase.
+    pub fn to_lowercase(&self, variant: Variant) -> String {
+        self.render(Case::Lower, variant)
+    }
+
+    /// Converts the decimal to uppercase.
+    pub fn to_uppercase(&self, variant: Variant) -> String {
+        self.render(Case::Upper, variant)
+    }
+
+    fn render(&self, case: Case, variant: Variant) -> String {
+        let method = match case {
+            Case::Lower => NumChar::to_lowercase,
+            Case::Upper => NumChar::to_uppercase,
+        };
+        let mut output = String::new();
+        if self.neg {
+            output.push_str(method(NumChar::Neg, variant));
+        }
+        output.push_str(&match case {
+            Case::Lower => self.int.to_lowercase(variant),
+            Case::Upper => self.int.to_uppercase(variant),
+        });
+        if !self.frac.is_empty() {
+            output.push_str(method(NumChar::Point, variant));
+            for digit in self.frac.bytes() {
+                output.push_str(method(NUM_CHARS[(digit - b'0') as usize], variant));
+            }
+        }
+        output
+    }
+}
+
+impl<T: ChineseNumeral> std::fmt::Display for ChineseDecimal<T> {
+    /// Formats the decimal with the crate-wide default case and variant, where `{:#}` selects uppercase and `{:-}` selects traditional Chinese, and the width pads the numeral on the left by default, e.g. `{:<8}` for aligning columns.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let options = default_options();
+        let case = if f.alternate() {
+            Case::Upper
+        } else {
+            options.case
+        };
+        let variant = if f.sign_minus() {
+            Variant::Traditional
+        } else {
+            options.variant
+        };
+        write_padded(&self.render(case, variant), f)
+    }
+}
+
+impl<T: ChineseNumeral + FromStr<Err = Error>> FromStr for ChineseDecimal<T> {
+    type Err = Error;
+
+    /// Parses a decimal string, e.g. `-12.50`, with `_` or `,` as separators in the integer part.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+        let (neg, int) = split_decimal(int)?;
+        Self::new(neg, &int, frac)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl<T: ChineseNumeral + FromStr<Err = Error>> TryFrom<rust_decimal::Decimal>
+    for ChineseDecimal<T>
+{
+    type Error = Error;
+
+    /// Performs the conversion. Returns [`Error`] if the integer part is out of range.
+    fn try_from(value: rust_decimal::Decimal) -> Result<Self, Self::Error> {
+        let digits = value.mantissa().unsigned_abs().to_string();
+        Self::from_scaled(value.is_sign_negative(), &digits, value.scale() as i64)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl<T: ChineseNumeral + FromStr<Err = Error>> TryFrom<&bigdecimal::BigDecimal>
+    for ChineseDecimal<T>
+{
+    type Error = Error;
+
+    /// Performs the conversion. Returns [`Error`] if the integer part is out of range.
+    fn try_from(value: &bigdecimal::BigDecimal) -> Result<Self, Self::Error> {
+        let (unscaled, scale) = value.as_bigint_and_exponent();
+        let digits = unscaled.to_string();
+        match digits.strip_prefix('-') {
+            Some(digits) => Self::from_scaled(true, digits, scale),
+            None => Self::from_scaled(false, &digits, scale),
+        }
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl<T: ChineseNumeral + FromStr<Err = Error>> TryFrom<bigdecimal::BigDecimal>
+    for ChineseDecimal<T>
+{
+    type Error = Error;
+
+    /// Performs the conversion. Returns [`Error`] if the integer part is out of range.
+    fn try_from(value: bigdecimal::BigDecimal) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
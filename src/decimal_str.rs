@@ -0,0 +1,168 @@
+use crate::{
+    characters::NumChar, macros::write_numeral, parse::split_decimal, units, ChineseNumeralBase,
+    Error, Long, Mid, Myriad, Scale, ScaleBase, ScaleKind, Short, Sign, Signed,
+};
+use std::marker::PhantomData;
+
+/// Numbers of any size in the scale of the marker type `S`, held as a string of decimal digits, so that huge numbers can be converted without feature "bigint".
+///
+/// The digits are split into groups and units directly, the same as the big integer structs.
+///
+/// ```
+/// use chinese_numerals::{ChineseNumeral, DecimalStr, Myriad};
+///
+/// let num = DecimalStr::<Myriad>::from_decimal_str("-1_0000_0000_0000_0000_0000_0000_0000_0000_0000_0012").unwrap();
+/// assert_eq!("负一正零一十二", num.to_lowercase_simp());
+/// assert!(DecimalStr::<Myriad>::from_decimal_str(&"9".repeat(49)).is_err());
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Debug, Default)]
+pub struct DecimalStr<S: ScaleKind = Myriad> {
+    sign: Sign,
+    digits: String,
+    scale: PhantomData<S>,
+}
+
+impl<S: ScaleKind> DecimalStr<S> {
+    /// Parses a decimal string, with an optional sign and `_` or `,` as separators, or returns [`Error`] if the number is out of range of the scale.
+    pub fn from_decimal_str(s: &str) -> Result<Self, Error> {
+        let (neg, digits) = split_decimal(s)?;
+        let digits = match digits.trim_start_matches('0') {
+            "" => "0",
+            digits => digits,
+        };
+        if digits.len() > S::SCALE.max_magnitude() + 1 {
+            return Err(Error::out_of_range(S::SCALE, digits));
+        }
+        let sign = match (neg, digits == "0") {
+            (_, true) => Sign::Nil,
+            (true, false) => Sign::Neg,
+            (false, false) => Sign::Pos,
+        };
+        Ok(Self {
+            sign,
+            digits: digits.to_owned(),
+            scale: PhantomData,
+        })
+    }
+
+    /// Returns the digits of the absolute value without leading zeros, e.g. `0` for zero.
+    pub fn digits(&self) -> &str {
+        &self.digits
+    }
+}
+
+impl<S: ScaleKind> std::str::FromStr for DecimalStr<S> {
+    type Err = Error;
+
+    /// Parses a decimal string, the same as [`from_decimal_str`](DecimalStr::from_decimal_str).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_decimal_str(s)
+    }
+}
+
+impl<S: ScaleKind> std::fmt::Display for DecimalStr<S> {
+    /// Formats the number the same as the scale structs, see [`MyriadScaleInt`](crate::MyriadScaleInt).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_numeral(self, f)
+    }
+}
+
+/// Converts a 256-bit unsigned integer without feature "bigint", e.g. a balance on a blockchain.
+///
+/// All values fit in mid-scale and long scale, while those out of range of short scale or myriad scale return [`Error`].
+#[cfg(feature = "ethnum")]
+impl<S: ScaleKind> TryFrom<ethnum::U256> for DecimalStr<S> {
+    type Error = Error;
+
+    fn try_from(value: ethnum::U256) -> Result<Self, Self::Error> {
+        Self::from_decimal_str(&value.to_string())
+    }
+}
+
+/// Converts a 256-bit signed integer without feature "bigint", the same as from [`U256`](ethnum::U256).
+#[cfg(feature = "ethnum")]
+impl<S: ScaleKind> TryFrom<ethnum::I256> for DecimalStr<S> {
+    type Error = Error;
+
+    fn try_from(value: ethnum::I256) -> Result<Self, Self::Error> {
+        Self::from_decimal_str(&value.to_string())
+    }
+}
+
+/// Generates numbers with random signs and random digits in range of the scale.
+#[cfg(feature = "arbitrary")]
+impl<'a, S: ScaleKind> arbitrary::Arbitrary<'a> for DecimalStr<S> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let neg: bool = u.arbitrary()?;
+        let len = u.int_in_range(1..=S::SCALE.max_magnitude() + 1)?;
+        let mut digits = String::with_capacity(len + 1);
+        if neg {
+            digits.push('-');
+        }
+        for _ in 0..len {
+            digits.push(char::from(b'0' + u.int_in_range(0..=9)?));
+        }
+        Ok(Self::from_decimal_str(&digits).expect("Digits are in range"))
+    }
+}
+
+/// Serializes the number as a struct of its value and numeral in human-readable formats, or as the decimal string in compact formats, the same as the integer structs.
+#[cfg(feature = "serde")]
+impl<S: ScaleKind> serde::Serialize for DecimalStr<S> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        crate::serialize_numeral(self, serializer)
+    }
+}
+
+impl<S: ScaleKind> Signed for DecimalStr<S> {
+    type Data = String;
+
+    fn sign(&self) -> Sign {
+        self.sign
+    }
+
+    fn data(&self) -> &String {
+        &self.digits
+    }
+}
+
+impl<S: ScaleKind> ChineseNumeralBase for DecimalStr<S> {
+    const SCALE: Scale = S::SCALE;
+
+    fn push_chars(&self, chars: &mut Vec<NumChar>) {
+        push_digits(self.digits.as_bytes(), S::SCALE, chars);
+    }
+}
+
+/// Collects the characters of the absolute value of digits without leading zeros in reversed order.
+///
+/// Numbers fitting in `u128` are left to the scale structs, while larger ones are split at the largest unit below them.
+fn push_digits(digits: &[u8], scale: Scale, chars: &mut Vec<NumChar>) {
+    if digits.len() <= 38 {
+        let abs = digits
+            .iter()
+            .fold(0, |abs, &digit| abs * 10 + u128::from(digit - b'0'));
+        match scale {
+            Scale::Short => Short::push_abs(abs as u64, chars),
+            Scale::Myriad => Myriad::push_abs(abs, chars),
+            Scale::Mid => Mid::push_abs(abs, chars),
+            Scale::Long => Long::push_abs(abs, chars),
+        }
+        return;
+    }
+    let unit = units(scale)
+        .iter()
+        .rev()
+        .find(|unit| (unit.exponent as usize) < digits.len())
+        .expect("Digits are longer than 万");
+    let (high, low) = digits.split_at(digits.len() - unit.exponent as usize);
+    let low_start = low.iter().position(|&digit| digit != b'0');
+    if let Some(start) = low_start {
+        push_digits(&low[start..], scale, chars);
+        if start > 0 {
+            chars.push(NumChar::Zero);
+        }
+    }
+    chars.push(unit.char);
+    push_digits(high, scale, chars);
+}
@@ -0,0 +1,172 @@
+use crate::{characters::*, Case, ChineseNumeral, MyriadScaleInt, ParseError, Sign, Variant};
+
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
+
+/// Reads a digit in dates, where zero is written as 〇.
+pub(crate) fn digit_to_str(digit: u32) -> &'static str {
+    if digit == 0 {
+        "〇"
+    } else {
+        NUM_CHARS[digit as usize].to_lowercase_simp()
+    }
+}
+
+/// Recognizes a digit in any case and variant, including 〇, 幺, and Arabic digits.
+pub(crate) fn char_to_digit(ch: char) -> Option<u32> {
+    match ch {
+        '〇' | '○' => Some(0),
+        '幺' => Some(1),
+        '0'..='9' => ch.to_digit(10),
+        _ => NumChar::from_char(ch)
+            .map(|num| num as u32)
+            .filter(|&digit| digit <= 9),
+    }
+}
+
+/// Styles of reading digits one by one, see [`format_digits_with`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum DigitStyle {
+    /// Reads 1 as 一, e.g. 一一零.
+    #[default]
+    Standard,
+    /// Reads 1 as 幺 in lowercase, as in phone numbers and codes read aloud, e.g. 幺幺零.
+    Yao,
+}
+
+/// Reads a string of Arabic digits one by one, e.g. 一二三 for `"123"`.
+///
+/// Leading zeros are kept, and a decimal point is read as 点. Returns [`ParseError`] if the string is empty or contains other characters.
+///
+/// ```
+/// use chinese_numerals::{format_digits, Case, Variant};
+///
+/// assert_eq!("一一零", format_digits("110", Case::Lower, Variant::Simplified).unwrap());
+/// assert_eq!("三點一四", format_digits("3.14", Case::Lower, Variant::Traditional).unwrap());
+/// assert_eq!("零贰肆", format_digits("024", Case::Upper, Variant::Simplified).unwrap());
+/// ```
+pub fn format_digits(digits: &str, case: Case, variant: Variant) -> Result<String, ParseError> {
+    format_digits_with(digits, case, variant, DigitStyle::Standard)
+}
+
+/// Reads a string of Arabic digits one by one in given style, the same as [`format_digits`] otherwise.
+///
+/// ```
+/// use chinese_numerals::{format_digits_with, Case, DigitStyle, Variant};
+///
+/// assert_eq!(
+///     "幺幺零",
+///     format_digits_with("110", Case::Lower, Variant::Simplified, DigitStyle::Yao).unwrap()
+/// );
+/// ```
+pub fn format_digits_with(
+    digits: &str,
+    case: Case,
+    variant: Variant,
+    style: DigitStyle,
+) -> Result<String, ParseError> {
+    if digits.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let method = match case {
+        Case::Lower => NumChar::to_lowercase,
+        Case::Upper => NumChar::to_uppercase,
+    };
+    let mut output = String::with_capacity(digits.len() * 3);
+    for ch in digits.chars() {
+        match ch {
+            '1' if style == DigitStyle::Yao && case == Case::Lower => output.push_str(YAO),
+            '0'..='9' => output.push_str(method(NUM_CHARS[ch as usize - '0' as usize], variant)),
+            '.' => output.push_str(method(NumChar::Point, variant)),
+            _ => return Err(ParseError::InvalidChar(ch)),
+        }
+    }
+    Ok(output)
+}
+
+/// Reads a string of decimal digits in scientific notation, e.g. 一点二三乘十的四次方 for `"12300"`.
+pub(crate) fn format_scientific(digits: &str, case: Case, variant: Variant) -> String {
+    let (first, rest) = digits.split_at(1);
+    let rest = rest.trim_end_matches('0');
+    let mantissa = if rest.is_empty() {
+        first.to_owned()
+    } else {
+        format!("{first}.{rest}")
+    };
+    let exp = MyriadScaleInt::from(digits.len() - 1);
+    let (ten, exp) = match case {
+        Case::Lower => (
+            NumChar::Shi.to_lowercase(variant),
+            exp.to_lowercase(variant),
+        ),
+        Case::Upper => (
+            NumChar::Shi.to_uppercase(variant),
+            exp.to_uppercase(variant),
+        ),
+    };
+    let mantissa = format_digits(&mantissa, case, variant).expect("Digits are valid");
+    format!("{mantissa}乘{ten}的{exp}次方")
+}
+
+/// Absolute values that can be written in digits of a radix.
+pub(crate) trait RadixDigits {
+    /// Returns the digits in the radix, the most significant first.
+    fn radix_digits(&self, radix: u32) -> Vec<u32>;
+}
+
+macro_rules! impl_radix_digits {
+    ($($uint:ty),+ $(,)?) => {
+        $(impl RadixDigits for $uint {
+            fn radix_digits(&self, radix: u32) -> Vec<u32> {
+                let mut num = *self;
+                let mut digits = vec![(num % radix as $uint) as u32];
+                while num >= radix as $uint {
+                    num /= radix as $uint;
+                    digits.push((num % radix as $uint) as u32);
+                }
+                digits.reverse();
+                digits
+            }
+        })+
+    };
+}
+
+impl_radix_digits! {u32, u64, u128}
+
+#[cfg(feature = "bigint")]
+impl RadixDigits for BigUint {
+    fn radix_digits(&self, radix: u32) -> Vec<u32> {
+        self.to_radix_be(radix).into_iter().map(u32::from).collect()
+    }
+}
+
+/// Reads the digits of a number in a radix one by one, see `to_digits_radix` of the structs.
+///
+/// Digits above nine are read as numbers, so digits are separated by 、 for radices above ten.
+pub(crate) fn format_radix(
+    sign: Sign,
+    abs: &impl RadixDigits,
+    radix: u32,
+    case: Case,
+    variant: Variant,
+) -> String {
+    assert!(
+        (2..=36).contains(&radix),
+        "Radix {radix} is not in the range from 2 to 36"
+    );
+    let mut numeral = String::new();
+    if sign == Sign::Neg {
+        numeral.push_str(NumChar::Neg.to_lowercase(variant));
+    }
+    for (i, digit) in abs.radix_digits(radix).into_iter().enumerate() {
+        if i > 0 && radix > 10 {
+            numeral.push('、');
+        }
+        let digit = MyriadScaleInt::from(digit);
+        numeral.push_str(&match case {
+            Case::Lower => digit.to_lowercase(variant),
+            Case::Upper => digit.to_uppercase(variant),
+        });
+    }
+    numeral
+}
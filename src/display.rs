@@ -0,0 +1,63 @@
+use crate::{macros::write_padded, signed_chars, Case, ChineseNumeral, Variant};
+
+/// Lowercase view of a number, returned by [`display_lower`](ChineseNumeral::display_lower).
+///
+/// It writes the numeral into the formatter directly, without building an intermediate `String`.
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayLower<'a, T: ?Sized> {
+    num: &'a T,
+    variant: Variant,
+}
+
+/// Uppercase view of a number, returned by [`display_upper`](ChineseNumeral::display_upper).
+///
+/// It writes the numeral into the formatter directly, without building an intermediate `String`.
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayUpper<'a, T: ?Sized> {
+    num: &'a T,
+    variant: Variant,
+}
+
+impl<'a, T: ?Sized> DisplayLower<'a, T> {
+    pub(crate) fn new(num: &'a T, variant: Variant) -> Self {
+        Self { num, variant }
+    }
+}
+
+impl<'a, T: ?Sized> DisplayUpper<'a, T> {
+    pub(crate) fn new(num: &'a T, variant: Variant) -> Self {
+        Self { num, variant }
+    }
+}
+
+/// Writes the numeral of a number in the case, padded on the left by default only if a width is given.
+fn write_case<T: ChineseNumeral + ?Sized>(
+    num: &T,
+    case: Case,
+    variant: Variant,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    let mut glyphs = signed_chars(num, case, variant)
+        .into_iter()
+        .rev()
+        .map(|ch| match case {
+            Case::Lower => ch.to_lowercase(variant),
+            Case::Upper => ch.to_uppercase(variant),
+        });
+    if f.width().is_some() {
+        return write_padded(&glyphs.collect::<String>(), f);
+    }
+    glyphs.try_for_each(|glyph| f.write_str(glyph))
+}
+
+impl<T: ChineseNumeral + ?Sized> std::fmt::Display for DisplayLower<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_case(self.num, Case::Lower, self.variant, f)
+    }
+}
+
+impl<T: ChineseNumeral + ?Sized> std::fmt::Display for DisplayUpper<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_case(self.num, Case::Upper, self.variant, f)
+    }
+}
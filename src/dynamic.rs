@@ -0,0 +1,64 @@
+use crate::{private::Signed, ChineseNumeral, Error, Options, Scale, Sign, Variant};
+
+/// Object-safe counterpart of [`ChineseNumeral`], implemented for all numbers, so that numbers of different scales and types can be stored together as `Box<dyn DynChineseNumeral>`.
+///
+/// Its methods are named differently from those of [`ChineseNumeral`], so that both traits can be in scope at once. Trait objects are displayed as the numbers are, in lowercase simplified Chinese, or in uppercase with `{:#}`.
+///
+/// ```
+/// use chinese_numerals::{DynChineseNumeral, MidScaleInt, MyriadScaleInt, Scale, ShortScaleInt, Variant};
+///
+/// let nums: Vec<Box<dyn DynChineseNumeral>> = vec![
+///     Box::new(ShortScaleInt::from(10_0000)),
+///     Box::new(MyriadScaleInt::from(-12)),
+///     Box::new(MidScaleInt::from(305)),
+/// ];
+/// assert_eq!(Scale::Short, nums[0].scale());
+/// assert_eq!("一亿", nums[0].lowercase(Variant::Simplified));
+/// assert_eq!("負壹拾貳", nums[1].uppercase(Variant::Traditional));
+/// assert_eq!("三百零五", nums[2].to_string());
+/// ```
+pub trait DynChineseNumeral: std::fmt::Display + std::fmt::Debug {
+    /// Returns the scale of the number.
+    fn scale(&self) -> Scale;
+
+    /// Returns the sign of the number.
+    fn sign(&self) -> Sign;
+
+    /// Converts the number to lowercase, as [`ChineseNumeral::to_lowercase`].
+    fn lowercase(&self, variant: Variant) -> String;
+
+    /// Converts the number to uppercase, as [`ChineseNumeral::to_uppercase`].
+    fn uppercase(&self, variant: Variant) -> String;
+
+    /// Converts the number with given options, as [`ChineseNumeral::to_chinese`].
+    fn render(&self, options: &Options) -> String;
+
+    /// Converts the number with given options, or returns [`Error`] if the output is too long, as [`ChineseNumeral::try_to_chinese`].
+    fn try_render(&self, options: &Options) -> Result<String, Error>;
+}
+
+impl<T: ChineseNumeral + std::fmt::Display + std::fmt::Debug> DynChineseNumeral for T {
+    fn scale(&self) -> Scale {
+        T::SCALE
+    }
+
+    fn sign(&self) -> Sign {
+        Signed::sign(self)
+    }
+
+    fn lowercase(&self, variant: Variant) -> String {
+        self.to_lowercase(variant)
+    }
+
+    fn uppercase(&self, variant: Variant) -> String {
+        self.to_uppercase(variant)
+    }
+
+    fn render(&self, options: &Options) -> String {
+        self.to_chinese(options)
+    }
+
+    fn try_render(&self, options: &Options) -> Result<String, Error> {
+        self.try_to_chinese(options)
+    }
+}
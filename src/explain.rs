@@ -0,0 +1,135 @@
+//! Step-by-step explanations of conversions, see [`ChineseNumeral::explain`](crate::ChineseNumeral::explain).
+
+use crate::{
+    characters::NumChar, const_fmt::GROUPS, segment, ChineseNumeralBase, Scale, Segment, Sign,
+};
+use std::fmt;
+
+/// A group of a numeral with its units, and how it is read.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Step {
+    /// The group and its units.
+    pub segment: Segment,
+    /// Exponent of 10 of the units, e.g. 4 for 万.
+    pub exp: u32,
+    /// The group as read in lowercase simplified Chinese, including 零 before it.
+    pub text: String,
+    /// Why 零s are read or characters omitted in the group.
+    pub notes: Vec<String>,
+}
+
+/// A step-by-step explanation of a conversion, formatted as text by [`Display`](fmt::Display).
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Explanation {
+    /// The scale of the number.
+    pub scale: Scale,
+    /// The sign of the number.
+    pub sign: Sign,
+    /// The absolute value in Arabic digits.
+    pub value: String,
+    /// The groups in reading order.
+    pub steps: Vec<Step>,
+    /// The numeral in lowercase simplified Chinese.
+    pub numeral: String,
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let minus = if self.sign == Sign::Neg { "-" } else { "" };
+        writeln!(
+            f,
+            "{minus}{} in {}: {}",
+            self.value,
+            self.scale.name_en(),
+            self.numeral
+        )?;
+        if self.sign == Sign::Neg {
+            writeln!(f, "  负 marks a negative number")?;
+        }
+        for step in &self.steps {
+            match step.exp {
+                0 => writeln!(f, "  {} → {}", step.segment.value, step.text)?,
+                exp => writeln!(f, "  {} × 10^{exp} → {}", step.segment.value, step.text)?,
+            }
+            for note in &step.notes {
+                writeln!(f, "    {note}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Describes a run of zero digits from `high` down to `low` stood for by a 零.
+fn zero_note(high: u32, low: u32) -> String {
+    if high == low {
+        format!("零 stands for the zero digit at 10^{high}")
+    } else {
+        format!("零 stands for the zero digits from 10^{high} to 10^{low}")
+    }
+}
+
+/// Explains the conversion of a number.
+pub(crate) fn explain<T: ChineseNumeralBase>(num: &T) -> Explanation {
+    let scale = T::SCALE;
+    let mut steps = Vec::new();
+    let mut prev_exp = None;
+    let segments = segment::segments(num.to_chars());
+    let exps = segment::exps(&segments, scale);
+    for (segment, exp) in segments.into_iter().zip(exps) {
+        let top = exp + segment.value.ilog10();
+        let mut notes = Vec::new();
+        let mut text = String::new();
+        if segment.zero {
+            text.push_str(NumChar::Zero.to_lowercase_simp());
+            if let Some(prev_exp) = prev_exp {
+                notes.push(zero_note(prev_exp - 1, top + 1));
+            }
+        }
+
+        let mut chars = GROUPS[segment.value as usize].chars().to_vec();
+        if steps.is_empty() && (10..20).contains(&segment.value) {
+            chars.pop();
+            notes.push("the 一 of the leading 十 is omitted".to_owned());
+        }
+        text.extend(chars.iter().rev().map(|ch| ch.to_lowercase_simp()));
+        text.extend(segment.units.iter().map(|ch| ch.to_lowercase_simp()));
+
+        // zeros between nonzero digits inside the group
+        let mut zeros = None;
+        for digit_exp in (0..segment.value.ilog10()).rev() {
+            let digit = segment.value / 10_u16.pow(digit_exp) % 10;
+            match (digit, zeros) {
+                (0, None) => zeros = Some(digit_exp),
+                (0, Some(_)) => {}
+                (_, Some(high)) => {
+                    notes.push(zero_note(exp + high, exp + digit_exp + 1));
+                    zeros = None;
+                }
+                (_, None) => {}
+            }
+        }
+
+        prev_exp = Some(exp);
+        steps.push(Step {
+            segment,
+            exp,
+            text,
+            notes,
+        });
+    }
+
+    let mut numeral = String::new();
+    match num.sign() {
+        Sign::Neg => numeral.push_str(NumChar::Neg.to_lowercase_simp()),
+        Sign::Nil => numeral.push_str(NumChar::Zero.to_lowercase_simp()),
+        Sign::Pos => {}
+    }
+    numeral.extend(steps.iter().map(|step| step.text.as_str()));
+    Explanation {
+        scale,
+        sign: num.sign(),
+        value: num.data().to_string(),
+        steps,
+        numeral,
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{render_into, ChineseNumeral, NumChar, Options};
+
+/// A reusable formatter, amortizing allocations across numbers formatted with the same options.
+///
+/// Unlike [`to_chinese`](ChineseNumeral::to_chinese), which allocates a new string for each number, [`format_into`](Formatter::format_into) appends to a given string, and reuses its internal buffer.
+///
+/// ```
+/// use chinese_numerals::{Formatter, MyriadScaleInt, Options};
+///
+/// let mut formatter = Formatter::new(Options::default());
+/// let mut output = String::new();
+/// for n in [12, 345] {
+///     formatter.format_into(&mut output, &MyriadScaleInt::from(n));
+///     output.push('\n');
+/// }
+/// assert_eq!("十二\n三百四十五\n", output);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Formatter {
+    options: Options,
+    chars: Vec<NumChar>,
+}
+
+impl Formatter {
+    /// Creates a formatter with given options.
+    pub fn new(options: Options) -> Self {
+        Self {
+            options,
+            chars: Vec::new(),
+        }
+    }
+
+    /// Returns the options of the formatter.
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    /// Appends the number to the output, the same as [`to_chinese`](ChineseNumeral::to_chinese) with the options of the formatter.
+    pub fn format_into<T: ChineseNumeral>(&mut self, output: &mut String, num: &T) {
+        let start = output.len();
+        render_into(num, &self.options, &mut self.chars, output);
+        if let Some(max) = self.options.max_output_chars {
+            if let Some((pos, _)) = output[start..].char_indices().nth(max) {
+                output.truncate(start + pos);
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{characters::*, ChineseNumeral, MyriadScaleInt, Variant};
+
+/// Values that can be expressed as percentages, see [`to_percentage`].
+pub trait Ratio {
+    /// Returns the ratio as a float, where `1.0` means 100%.
+    fn ratio(&self) -> f64;
+}
+
+impl Ratio for f64 {
+    fn ratio(&self) -> f64 {
+        *self
+    }
+}
+
+impl Ratio for f32 {
+    fn ratio(&self) -> f64 {
+        *self as f64
+    }
+}
+
+/// The ratio of a numerator to a denominator.
+impl Ratio for (i64, i64) {
+    fn ratio(&self) -> f64 {
+        self.0 as f64 / self.1 as f64
+    }
+}
+
+/// Styles of percentages.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum PercentStyle {
+    /// Per hundred, e.g. 百分之三十五.
+    Percent,
+    /// Per ten (成), e.g. 三成五. Rounded to the nearest percent, and falls back to [`PercentStyle::Percent`] if less than 10%.
+    Cheng,
+}
+
+/// Converts a non-negative decimal string to lowercase, reading the digits after the decimal point one by one.
+pub(crate) fn decimal_to_lowercase(decimal: &str, variant: Variant) -> Option<String> {
+    let (int, frac) = decimal.split_once('.').unwrap_or((decimal, ""));
+    let int: u128 = int.parse().ok()?;
+    let mut chars = MyriadScaleInt::from(int).to_lowercase(variant);
+    if !frac.is_empty() {
+        chars.push_str(NumChar::Point.to_lowercase(variant));
+        for digit in frac.chars() {
+            let digit = digit.to_digit(10)?;
+            chars.push_str(NUM_CHARS[digit as usize].to_lowercase(variant));
+        }
+    }
+    Some(chars)
+}
+
+/// Converts a ratio to a percentage in lowercase, e.g. `0.35` to 百分之三十五.
+///
+/// Up to 6 digits after the decimal point of the percentage are kept. Returns [`None`] if the ratio is not finite or too large.
+///
+/// ```
+/// use chinese_numerals::{to_percentage, PercentStyle, Variant};
+///
+/// assert_eq!(
+///     Some("百分之三十五".to_owned()),
+///     to_percentage(0.35, PercentStyle::Percent, Variant::Simplified)
+/// );
+/// assert_eq!(
+///     Some("百分之十二点五".to_owned()),
+///     to_percentage((1, 8), PercentStyle::Percent, Variant::Simplified)
+/// );
+/// assert_eq!(
+///     Some("三成五".to_owned()),
+///     to_percentage(0.35, PercentStyle::Cheng, Variant::Simplified)
+/// );
+/// ```
+pub fn to_percentage<R: Ratio>(ratio: R, style: PercentStyle, variant: Variant) -> Option<String> {
+    let percent = ratio.ratio() * 100.0;
+    if !percent.is_finite() {
+        return None;
+    }
+    let neg = percent < 0.0;
+    let percent = percent.abs();
+    let sign = NumChar::Neg.to_lowercase(variant);
+
+    let rounded = percent.round();
+    if style == PercentStyle::Cheng && rounded >= 10.0 {
+        if rounded >= u128::MAX as f64 {
+            return None;
+        }
+        let rounded = rounded as u128;
+        let mut cheng = if neg { sign.to_owned() } else { String::new() };
+        cheng.push_str(&MyriadScaleInt::from(rounded / 10).to_lowercase(variant));
+        cheng.push('成');
+        if !rounded.is_multiple_of(10) {
+            cheng.push_str(NUM_CHARS[(rounded % 10) as usize].to_lowercase(variant));
+        }
+        return Some(cheng);
+    }
+
+    let percent = format!("{:.6}", percent);
+    let percent = percent.trim_end_matches('0').trim_end_matches('.');
+    // a negative number rounded to zero is written without the sign
+    let sign = if neg && percent != "0" { sign } else { "" };
+    Some(sign.to_owned() + "百分之" + &decimal_to_lowercase(percent, variant)?)
+}
+
+/// Converts a fraction to lowercase, e.g. `1` over `3` to 三分之一.
+///
+/// Returns [`None`] if the denominator is zero.
+///
+/// ```
+/// use chinese_numerals::{format_fraction, Variant};
+///
+/// assert_eq!(Some("三分之一".to_owned()), format_fraction(1, 3, Variant::Simplified));
+/// assert_eq!(Some("负十分之七".to_owned()), format_fraction(-7, 10, Variant::Simplified));
+/// ```
+pub fn format_fraction(numerator: i128, denominator: u128, variant: Variant) -> Option<String> {
+    if denominator == 0 {
+        return None;
+    }
+    let mut fraction = String::new();
+    if numerator < 0 {
+        fraction.push_str(NumChar::Neg.to_lowercase(variant));
+    }
+    fraction += &MyriadScaleInt::from(denominator).to_lowercase(variant);
+    fraction += "分之";
+    fraction += &MyriadScaleInt::from(numerator.unsigned_abs()).to_lowercase(variant);
+    Some(fraction)
+}
@@ -0,0 +1,42 @@
+//! Rendering with full-width Arabic digits, see [`Style::FullWidth`](crate::Style::FullWidth) and [`Style::FullWidthWithUnits`](crate::Style::FullWidthWithUnits).
+
+use crate::{segment, ChineseNumeralBase, Options, Sign, Style, Token, ZeroStyle};
+
+/// Converts ASCII digits to full-width digits, e.g. `１２３`.
+fn full_width(digits: &str) -> String {
+    digits
+        .chars()
+        .map(|ch| match ch {
+            '0'..='9' => char::from_u32(ch as u32 - '0' as u32 + '０' as u32).unwrap(),
+            _ => ch,
+        })
+        .collect()
+}
+
+/// Collects the tokens of a number in reading order, with digits in full width.
+///
+/// With [`Style::FullWidthWithUnits`], each group below 10000 is written in digits followed by its units, e.g. ３億５０００万.
+pub(crate) fn tokens<T: ChineseNumeralBase>(num: &T, options: &Options) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    match num.sign() {
+        Sign::Neg => tokens.push(Token::Text("－".to_owned())),
+        Sign::Pos if options.positive_sign => tokens.push(Token::Text("＋".to_owned())),
+        Sign::Nil => {
+            if options.zero_style == ZeroStyle::Ling {
+                tokens.push(Token::Text("０".to_owned()));
+            }
+            return tokens;
+        }
+        Sign::Pos => {}
+    }
+
+    if options.style == Style::FullWidthWithUnits {
+        for segment in segment::segments(num.to_chars()) {
+            tokens.push(Token::Text(full_width(&segment.value.to_string())));
+            tokens.extend(segment.units.into_iter().map(Token::Char));
+        }
+    } else {
+        tokens.push(Token::Text(full_width(&num.data().to_string())));
+    }
+    tokens
+}
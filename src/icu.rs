@@ -0,0 +1,90 @@
+//! Rendering compatible with ICU's spellout rules, see [`Style::IcuCompatible`](crate::Style::IcuCompatible).
+
+use crate::{characters::*, Case, ChineseNumeralBase, Options, Sign, Token, Variant, ZeroStyle};
+
+/// Numbers of absolute values below are spelled out, while others are written in Arabic digits.
+const LIMIT: u128 = 100_0000_0000_0000_0000;
+
+/// Units with the thresholds below which a remainder after them is prefixed with 零, taken from ICU's data.
+const UNITS: [(u128, NumChar, u128); 7] = [
+    (1_0000_0000_0000_0000, NumChar::Jing, 1_0000_0000_0000),
+    (1_0000_0000_0000, NumChar::Zhao, 1000_0000),
+    (1_0000_0000, NumChar::Yi, 1_0000),
+    (1_0000, NumChar::Wan, 1000),
+    (1000, NumChar::Qian, 100),
+    (100, NumChar::Bai, 10),
+    (10, NumChar::Shi, 1),
+];
+
+/// Returns the character of ICU's rules.
+pub(crate) fn glyph(ch: NumChar, case: Case, variant: Variant) -> &'static str {
+    match (ch, case, variant) {
+        (NumChar::Zero, Case::Lower, Variant::Simplified) => "〇",
+        (NumChar::Three, Case::Upper, Variant::Traditional) => "參",
+        (_, Case::Lower, _) => ch.to_lowercase(variant),
+        (_, Case::Upper, _) => ch.to_uppercase(variant),
+    }
+}
+
+/// Collects the characters of an absolute value in reading order.
+///
+/// A remainder after a unit is read as a continuation, keeping the 一 of 十, while multipliers of units are read on their own.
+fn push_chars(num: u128, continuation: bool, chars: &mut Vec<NumChar>) {
+    let Some(&(unit, unit_char, threshold)) = UNITS.iter().find(|(unit, _, _)| num >= *unit) else {
+        chars.push(NUM_CHARS[num as usize]);
+        return;
+    };
+    let (quot, rem) = (num / unit, num % unit);
+    if unit_char != NumChar::Shi || quot != 1 || continuation {
+        push_chars(quot, false, chars);
+    }
+    chars.push(unit_char);
+    if rem > 0 {
+        if rem < threshold {
+            chars.push(NumChar::Zero);
+        }
+        push_chars(rem, true, chars);
+    }
+}
+
+/// Groups Arabic digits by thousands, e.g. `1,000,000`.
+fn group_digits(digits: &str) -> String {
+    let mut grouped = String::with_capacity(digits.len() * 4 / 3);
+    for (i, ch) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+    grouped
+}
+
+/// Collects the tokens of a number in reading order, following ICU's rules.
+pub(crate) fn tokens<T: ChineseNumeralBase>(num: &T, options: &Options) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    match num.sign() {
+        Sign::Neg => tokens.push(Token::Char(NumChar::Neg)),
+        Sign::Pos if options.positive_sign => tokens.push(Token::Char(NumChar::Pos)),
+        Sign::Nil => {
+            if options.zero_style == ZeroStyle::Ling {
+                tokens.push(match options.case {
+                    Case::Lower if options.charset.is_none() => Token::Text("〇".to_owned()),
+                    _ => Token::Char(NumChar::Zero),
+                });
+            }
+            return tokens;
+        }
+        Sign::Pos => {}
+    }
+
+    let digits = num.data().to_string();
+    match digits.parse::<u128>() {
+        Ok(abs) if abs < LIMIT => {
+            let mut chars = Vec::new();
+            push_chars(abs, false, &mut chars);
+            tokens.extend(chars.into_iter().map(Token::Char));
+        }
+        _ => tokens.push(Token::Text(group_digits(&digits))),
+    }
+    tokens
+}
@@ -0,0 +1,175 @@
+use crate::{
+    characters::NumChar, private::ScaleBase, ChineseNumeralBase, Error, Scale, ScaleInt, Sign,
+    Signed,
+};
+
+/// Marker types of scales for [`ChineseInt`], i.e. [`Short`](crate::Short), [`Myriad`](crate::Myriad), [`Mid`](crate::Mid), and [`Long`](crate::Long).
+///
+/// The trait is sealed, and lets code be generic over scales.
+///
+/// ```
+/// use chinese_numerals::{ChineseInt, ChineseNumeral, Myriad, ScaleKind, Short};
+///
+/// fn label<S: ScaleKind>(num: ChineseInt<S>) -> String {
+///     format!("第{}", num.to_lowercase_simp())
+/// }
+///
+/// assert_eq!("第一亿", label(ChineseInt::<Myriad>::from(1_0000_0000)));
+/// assert_eq!("第一垓", label(ChineseInt::<Short>::from(1_0000_0000)));
+/// ```
+pub trait ScaleKind: ScaleBase + Copy + Eq + std::hash::Hash + std::fmt::Debug + Default {}
+
+/// Integers in the scale of the marker type `S`, backed by primitive integers.
+///
+/// [`ShortScaleInt`](crate::ShortScaleInt), [`MyriadScaleInt`](crate::MyriadScaleInt), [`MidScaleInt`](crate::MidScaleInt), and [`LongScaleInt`](crate::LongScaleInt) are aliases of it.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub struct ChineseInt<S: ScaleKind> {
+    pub(super) sign: Sign,
+    pub(super) data: S::Abs,
+}
+
+impl<S: ScaleKind> ChineseInt<S> {
+    /// Whether the number is zero.
+    pub fn is_zero(&self) -> bool {
+        self.sign() == Sign::Nil
+    }
+
+    /// Whether the number is strictly positive.
+    pub fn is_positive(&self) -> bool {
+        self.sign() == Sign::Pos
+    }
+
+    /// Whether the number is strictly negative.
+    pub fn is_negative(&self) -> bool {
+        self.sign() == Sign::Neg
+    }
+
+    /// Constructs the number `mantissa` × 10<sup>`exponent`</sup>, or returns [`Error`] if it is out of range.
+    ///
+    /// ```
+    /// use chinese_numerals::{ChineseNumeral, MidScaleInt, ShortScaleInt};
+    ///
+    /// assert_eq!("三千万亿", MidScaleInt::from_sci(3, 15).unwrap().to_lowercase_simp());
+    /// assert_eq!("负一京", ShortScaleInt::from_sci(-1, 7).unwrap().to_lowercase_simp());
+    /// assert!(ShortScaleInt::from_sci(1, 16).is_err());
+    /// ```
+    pub fn from_sci(mantissa: i128, exponent: u32) -> Result<Self, Error> {
+        let out_of_range = || Error::out_of_range_sci(S::SCALE, mantissa.unsigned_abs(), exponent);
+        if mantissa == 0 {
+            return Ok(Self::default());
+        }
+        let abs = 10_u128
+            .checked_pow(exponent)
+            .and_then(|pow| mantissa.unsigned_abs().checked_mul(pow))
+            .ok_or_else(out_of_range)?;
+        let abs = S::Abs::try_from(abs).map_err(|_| out_of_range())?;
+        let sign = match mantissa < 0 {
+            true => Sign::Neg,
+            false => Sign::Pos,
+        };
+        Self::try_new(sign, abs).map_err(|_| out_of_range())
+    }
+
+    /// Checks in debug builds that zero, and only zero, has no sign.
+    fn debug_check_sign(&self) {
+        debug_assert_eq!(
+            self.sign == Sign::Nil,
+            self.data == S::Abs::default(),
+            "sign {:?} is inconsistent with absolute value {}",
+            self.sign,
+            self.data,
+        );
+    }
+}
+
+impl<S: ScaleKind> Signed for ChineseInt<S> {
+    type Data = S::Abs;
+
+    fn sign(&self) -> Sign {
+        self.debug_check_sign();
+        self.sign
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.data
+    }
+}
+
+impl<S: ScaleKind> ChineseNumeralBase for ChineseInt<S> {
+    const SCALE: Scale = S::SCALE;
+
+    fn push_chars(&self, chars: &mut Vec<NumChar>) {
+        S::push_abs(self.data, chars);
+    }
+}
+
+impl<S: ScaleKind> ScaleInt for ChineseInt<S> {
+    type Abs = S::Abs;
+
+    fn try_new(sign: Sign, abs: S::Abs) -> Result<Self, Error> {
+        if abs > S::MAX_ABS {
+            return Err(Error::out_of_range(S::SCALE, abs));
+        }
+        let sign = match sign {
+            _ if abs == S::Abs::default() => Sign::Nil,
+            Sign::Nil => Sign::Pos,
+            sign => sign,
+        };
+        Ok(Self { sign, data: abs })
+    }
+
+    fn into_parts(self) -> (Sign, S::Abs) {
+        (self.sign, self.data)
+    }
+}
+
+/// Serializes the number as `{"value": "-12", "chinese": "负十二"}` in human-readable formats, e.g. JSON, or as the decimal string `"-12"` in compact formats, e.g. bincode.
+#[cfg(feature = "serde")]
+impl<S: ScaleKind> serde::Serialize for ChineseInt<S> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        crate::serialize_numeral(self, serializer)
+    }
+}
+
+/// Logs the number in lowercase simplified Chinese with `defmt`, e.g. on embedded devices.
+#[cfg(feature = "defmt")]
+impl<S: ScaleKind> defmt::Format for ChineseInt<S> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        crate::write_defmt(self, f);
+    }
+}
+
+/// Samples numbers uniformly from the range of the scale, e.g. `rng.gen::<MidScaleInt>()` for practice numbers in quizzes.
+#[cfg(feature = "rand")]
+impl<S: ScaleKind> rand::distributions::Distribution<ChineseInt<S>>
+    for rand::distributions::Standard
+where
+    S::Abs: rand::distributions::uniform::SampleUniform,
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> ChineseInt<S> {
+        loop {
+            let neg = rng.gen::<bool>();
+            let abs = rng.gen_range(S::Abs::default()..=S::MAX_ABS);
+            // rejects negative zero, so that zero is as likely as any other number
+            if !(neg && abs == S::Abs::default()) {
+                return ChineseInt::new(if neg { Sign::Neg } else { Sign::Pos }, abs);
+            }
+        }
+    }
+}
+
+/// Generates numbers with uniformly random signs and absolute values in range of the scale.
+#[cfg(feature = "arbitrary")]
+impl<'a, S: ScaleKind> arbitrary::Arbitrary<'a> for ChineseInt<S>
+where
+    S::Abs: arbitrary::unstructured::Int,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let sign = match u.arbitrary()? {
+            true => Sign::Neg,
+            false => Sign::Pos,
+        };
+        let abs = u.int_in_range(S::Abs::default()..=S::MAX_ABS)?;
+        Ok(Self::new(sign, abs))
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{default_options, ChineseNumeral, Options};
+
+/// Converts numbers with the default options set by [`set_default_options`](crate::set_default_options) and joins them with a separator.
+///
+/// ```
+/// use chinese_numerals::{join_chinese, MyriadScaleInt};
+///
+/// let nums = [3, 15, -200].map(MyriadScaleInt::from);
+/// assert_eq!("三，十五，负二百", join_chinese(nums, "，"));
+/// assert_eq!("", join_chinese(Vec::<MyriadScaleInt>::new(), "，"));
+/// ```
+pub fn join_chinese(values: impl IntoIterator<Item = impl ChineseNumeral>, sep: &str) -> String {
+    join_with(values, sep, &default_options())
+}
+
+/// Converts numbers with given options and joins them with 、 (顿号), as lists are written in Chinese.
+///
+/// ```
+/// use chinese_numerals::{format_list, MyriadScaleInt, Options};
+///
+/// let nums = (1..=3).map(MyriadScaleInt::from);
+/// assert_eq!("一、二、三", format_list(nums, &Options::default()));
+/// ```
+pub fn format_list(
+    values: impl IntoIterator<Item = impl ChineseNumeral>,
+    options: &Options,
+) -> String {
+    join_with(values, "、", options)
+}
+
+/// Converts numbers with given options and joins them with a separator.
+fn join_with(
+    values: impl IntoIterator<Item = impl ChineseNumeral>,
+    sep: &str,
+    options: &Options,
+) -> String {
+    let mut output = String::new();
+    for (i, value) in values.into_iter().enumerate() {
+        if i > 0 {
+            output.push_str(sep);
+        }
+        output.push_str(&value.to_chinese(options));
+    }
+    output
+}
@@ -9,10 +9,16 @@
 //! Add to `Cargo.toml`:
 //! ```toml
 //! [dependencies]
-//! chinese-numerals = "0.2"
+//! chinese-numerals = "0.3"
 //! ```
 //!
-//! All structs have implemented [`Display`](std::fmt::Display) trait's normal (with `"{}"`) and alternative (with `"{:#}"`) formats, converting to lowercase and uppercase Chinese numbers. Besides, [`ChineseNumeral`] trait provides following functions:
+//! The default features depend on the standard library only, with primitive integers converted without `num-traits`. Dependencies are pulled only by optional features, e.g. `num-bigint`, `num-integer`, and `num-traits` by `bigint`. For supply-chain-sensitive environments, the `minimal` feature is guaranteed to stay free of dependencies, even if the default features change:
+//! ```toml
+//! [dependencies]
+//! chinese-numerals = { version = "0.3", default-features = false, features = ["minimal"] }
+//! ```
+//!
+//! All structs have implemented [`Display`](std::fmt::Display) trait's normal (with `"{}"`) and alternative (with `"{:#}"`) formats, converting to lowercase and uppercase Chinese numbers with the options set by [`set_default_options`], with `"{:-}"` for traditional Chinese, precision for approximating with 约, and width, fill, and alignment for padding. [`Binary`](std::fmt::Binary) (`"{:b}"`), [`Octal`](std::fmt::Octal) (`"{:o}"`), and [`LowerExp`](std::fmt::LowerExp) (`"{:e}"`) read the binary or octal digits one by one, or the number in scientific notation, e.g. 一零一一 and 一点一乘十的一次方 for 11, and `to_digits_radix` reads digits in any radix up to 36, e.g. 一、十五 for 0x1F. Besides, [`ChineseNumeral`] trait provides following functions:
 //!
 //! - [`to_lowercase`](crate::ChineseNumeral::to_lowercase)
 //! - [`to_lowercase_simp`](crate::ChineseNumeral::to_lowercase_simp)
@@ -20,15 +26,49 @@
 //! - [`to_uppercase`](crate::ChineseNumeral::to_uppercase)
 //! - [`to_uppercase_simp`](crate::ChineseNumeral::to_uppercase_simp)
 //! - [`to_uppercase_trad`](crate::ChineseNumeral::to_uppercase_trad)
+//! - [`to_chinese`](crate::ChineseNumeral::to_chinese), with [`Options`] to customize the output
+//! - [`with_classifier`], converting the number followed by a classifier, e.g. 两个 rather than 二个
+//! - [`join_chinese`] and [`format_list`], converting numbers and joining them with a separator or 、, e.g. 一、二、三
+//! - [`display_lower`](crate::ChineseNumeral::display_lower) and [`display_upper`](crate::ChineseNumeral::display_upper), writing the number into a formatter without an intermediate `String`
+//! - [`segments`](crate::ChineseNumeral::segments), splitting the number into groups and units for custom layouts
+//! - [`to_grouped_strings`](crate::ChineseNumeral::to_grouped_strings), converting the number to a string per group for aligning columns
+//! - [`explain`](crate::ChineseNumeral::explain), showing the working of a conversion step by step, for teaching
+//! - [`highest_unit`](crate::ChineseNumeral::highest_unit) and [`magnitude`](crate::ChineseNumeral::magnitude), finding the highest unit read and its power of ten
+//!
+//! [`ChineseNumeral`] cannot be made into a trait object, so [`DynChineseNumeral`] provides the main conversions for storing numbers of different scales together, e.g. as `Box<dyn DynChineseNumeral>`.
+//!
+//! [`is_digit_char`], [`is_unit_char`], and [`char_value`] classify the characters of numerals, for building parsers, highlighters, and validators.
+//!
+//! [`fold_numeral`] folds numerals in all cases and variants to lowercase simplified Chinese, e.g. 壹萬 to 一万, for comparison and deduplication.
+//!
+//! [`units`] lists the power of ten each of 万, 亿, 兆, 京, 垓, 秭, 穰, 沟, 涧, 正, and 载 represents in a scale.
+//!
+//! [`SHORT_SCALE_MAX_EXP`], [`MYRIAD_SCALE_MAX_EXP`], [`MID_SCALE_MAX_EXP`], and [`LONG_SCALE_MAX_EXP`] give the powers of ten absolute values in each scale are below, and [`fits_in_scale`] checks whether an integer is in range of a scale before converting it.
 //!
 //! ## Premitive Integers
 //!
 //! For each scale, a struct has been implemented to perform the convertion.
 //!
-//! [`ShortScaleInt`] has implemented [`From`] trait for `i8`, `u8`, `i16`, `u16`, `i32`, and `u32`, and [`TryFrom`] trait for `i64`, `u64`, `i128`, `u128`, `isize`, and `usize`.
+//! They are aliases of [`ChineseInt`] over the marker types [`Short`], [`Myriad`], [`Mid`], and [`Long`], so code can be generic over scales with [`ScaleKind`].
+//!
+//! [`MyriadScaleInt32`], [`MidScaleInt32`], and [`LongScaleInt32`] keep a `u32` absolute value instead of `u128`, and the `64` structs a `u64` one, to reduce the copy size for callers formatting small values only. They convert to and from the structs with `u128` values with [`From`] and [`TryFrom`].
+//!
+//! [`ShortScaleInt`] has implemented [`From`] trait for `i8`, `u8`, `i16`, `u16`, `i32`, and `u32`, and [`TryFrom`] trait for `i64`, `u64`, `i128`, `u128`, `isize`, and `usize`. With [`TryToChinese`] trait, the latter convert to short scale numerals directly, e.g. `12345_u64.try_to_short_scale()`.
 //!
 //! [`MyriadScaleInt`], [`MidScaleInt`], and [`LongScaleInt`] have implemented `From` trait for all premitive integers.
 //!
+//! All structs have `saturating_from`, clamping values out of range to `MAX` or `MIN` instead of failing, and [`ShortScaleInt`] also has `wrapping_from_i128` and `wrapping_from_u128`.
+//!
+//! All structs, including big integers, can also be constructed from a [`Sign`] and an absolute value with [`ScaleInt`] trait.
+//!
+//! All structs backed by primitive integers have `MAX` and `MIN` constants, and big integers have `max_value` and `min_value` functions. All structs but the compact `32` and `64` ones support arithmetic operators, which panic if the result is out of range. With feature `num-traits`, which `bigint` enables, all structs implement `Bounded` of `num_traits`, and all but the compact ones also `Zero`, `One`, `Num`, `Signed`, `FromPrimitive`, and `ToPrimitive`. With feature `arbitrary`, all structs implement `Arbitrary` of `arbitrary` for fuzzing, and the `fuzz` directory has `cargo fuzz` targets of parsing and formatting round trips. With feature `defmt`, all structs implement `Format` of `defmt`, logging numerals in lowercase simplified Chinese on embedded devices. With feature `rand`, the `Standard` distribution of `rand` samples all structs uniformly in range of their scales, e.g. `rng.gen::<MidScaleInt>()` for practice numbers in quizzes. With feature `serde`, all structs and [`DecimalStr`] implement `Serialize` of `serde`, as a struct of the value and the numeral, e.g. `{"value":"-12","chinese":"负十二"}`, in human-readable formats like JSON, and as the decimal string only in compact formats like bincode.
+//!
+//! All structs parse Chinese numerals with `TryFrom<&str>`, where Arabic digits may be mixed in, e.g. `MyriadScaleInt::try_from("3万5千")`.
+//!
+//! For numerals of unknown scale, [`parse_auto`] returns the values in all scales consistent with the units written, e.g. 一万亿 in mid-scale and long scale, and [`parse_preferring`] reads a numeral in a preferred scale, reporting other values of ambiguous numerals, e.g. 一兆 as 10<sup>6</sup>, 10<sup>12</sup>, or 10<sup>16</sup>.
+//!
+//! [`check_numeral`] locates parse errors with [`SpannedParseError`], giving the byte span, the offending character, and what is expected, e.g. for editors to underline invalid numerals.
+//!
 //! ### Examples
 //! ```
 //! use chinese_numerals::{ChineseNumeral, ShortScaleInt, MidScaleInt};
@@ -46,7 +86,11 @@
 //!
 //! For scales except short scale, a struct has been implemented to perform the convertion from [`BigInt`](num_bigint::BigInt) and [`BigUint`](num_bigint::BigUint).
 //!
-//! [`MyriadScaleBigInt`], [`MidScaleBigInt`], and [`LongScaleBigInt`] have implemented `TryFrom` trait for both `BigInt` and `BigUint`.
+//! [`MyriadScaleBigInt`], [`MidScaleBigInt`], and [`LongScaleBigInt`] have implemented `TryFrom` trait for both `BigInt` and `BigUint`, and `From` trait for all premitive integers.
+//!
+//! Without the dependency, [`DecimalStr`] holds a number of any size in range as a string of decimal digits, parsed by `from_decimal_str`. With feature `ethnum`, it also converts from `U256` and `I256` of `ethnum` with [`TryFrom`], e.g. for 256-bit balances.
+//!
+//! For numbers too large to hold in memory, [`DigitStream`] is fed with decimal digits most significant first, e.g. from a file, and writes the characters of each group as soon as it completes.
 //!
 //! ### Dependencies
 //!
@@ -54,7 +98,7 @@
 //! ```toml
 //! [dependencies]
 //! num-bigint = "0.4"
-//! chinese-numerals = { version = "0.2", features = ["bigint"] }
+//! chinese-numerals = { version = "0.3", features = ["bigint"] }
 //! ```
 //!
 //! ### Examples
@@ -72,22 +116,99 @@
 //!     num.to_lowercase_simp()
 //! );
 //! ```
+//!
+//! ## Decimals
+//!
+//! [`ChineseDecimal`] reads the integer part of a decimal by the rules of a scale struct, and the digits after the decimal point one by one after 点. [`ChineseDecimal::round`] limits the digits after the decimal point with a [`Rounding`] mode: half up for financial records, half even for scientific computing, or truncation. With features `rust_decimal` and `bigdecimal`, it implements `TryFrom` for `rust_decimal::Decimal` and `bigdecimal::BigDecimal` respectively.
 
 mod characters;
+mod classifier;
+mod compact;
+mod const_fmt;
+mod convert;
+mod date;
+mod decimal;
+mod decimal_str;
+mod digits;
+mod display;
+mod dynamic;
+mod explain;
+mod formatter;
+mod fraction;
+mod fullwidth;
+mod icu;
+mod int;
+mod join;
 mod longscale;
 mod macros;
+mod middleware;
 mod midscale;
+mod mixed;
+mod money;
 mod myriadscale;
+mod ops;
+mod options;
+mod parse;
+#[cfg(feature = "reference")]
+pub mod reference;
+mod segment;
 mod shortscale;
+mod ssml;
+mod stream;
+mod suggest;
+mod text;
+mod validate;
+mod vertical;
+#[cfg(feature = "wasm")]
+mod wasm;
 
-use characters::NumChar;
-pub use longscale::LongScaleInt;
-pub use midscale::MidScaleInt;
-pub use myriadscale::MyriadScaleInt;
-pub use shortscale::ShortScaleInt;
-
-#[cfg(feature = "bigint")]
-use num_bigint::BigUint;
+pub use characters::{
+    char_value, fold_numeral, is_digit_char, is_unit_char, CharacterSet, GlyphProfile, NumChar,
+};
+pub use classifier::with_classifier;
+pub use compact::{
+    Long32, Long64, LongScaleInt32, LongScaleInt64, Mid32, Mid64, MidScaleInt32, MidScaleInt64,
+    Myriad32, Myriad64, MyriadScaleInt32, MyriadScaleInt64,
+};
+pub use const_fmt::{format_const, ConstStr};
+pub use convert::{convert_batch, convert_str};
+pub use date::{
+    expand_two_digit_year, format_date, format_era_year, format_two_digit_year, format_weekday,
+    format_year, format_year_range, month_name, parse_two_digit_year, weekday_name, ToChineseDate,
+    WeekdayStyle,
+};
+pub use decimal::{ChineseDecimal, Rounding};
+pub use decimal_str::DecimalStr;
+pub use digits::{format_digits, format_digits_with, DigitStyle};
+pub use display::{DisplayLower, DisplayUpper};
+pub use dynamic::DynChineseNumeral;
+pub use explain::{Explanation, Step};
+pub use formatter::Formatter;
+pub use fraction::{format_fraction, to_percentage, PercentStyle, Ratio};
+pub use int::{ChineseInt, ScaleKind};
+pub use join::{format_list, join_chinese};
+pub use longscale::{Long, LongScaleInt};
+pub use middleware::{Middleware, Token};
+pub use midscale::{Mid, MidScaleInt};
+pub use mixed::{format_mixed, Bracket, MixedStyle};
+pub use money::{format_money, format_money_with, parse_cents, FinancialStyle};
+pub use myriadscale::{Myriad, MyriadScaleInt};
+pub use ops::ScaleRange;
+pub use options::{
+    default_options, set_default_options, LeadingOneStyle, NegativeStyle, Options, Strictness,
+    Style, ZeroPolicy, ZeroStyle,
+};
+pub use parse::{
+    check_numeral, parse_auto, parse_preferring, ParseError, ScaleReading, SpannedParseError,
+};
+pub use segment::Segment;
+pub use shortscale::{Short, ShortScaleInt, TryToChinese};
+pub use ssml::{speech_tokens, to_ssml, to_ssml_sub, SpeechToken};
+pub use stream::DigitStream;
+pub use suggest::{suggest, Candidate, CandidateStyle, SuggestOptions};
+pub use text::{convert_numbers_in_text, convert_numerals_in_text};
+pub use validate::{validate, ValidationError};
+pub use vertical::{to_vertical, vertical_form, VerticalLayout};
 
 #[cfg(feature = "bigint")]
 pub use longscale::LongScaleBigInt;
@@ -96,96 +217,556 @@ pub use midscale::MidScaleBigInt;
 #[cfg(feature = "bigint")]
 pub use myriadscale::MyriadScaleBigInt;
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
-pub(crate) enum Sign {
+mod private {
+    use crate::{NumChar, Scale, Sign};
+
+    pub trait Signed {
+        type Data: std::fmt::Display;
+
+        fn sign(&self) -> Sign;
+        fn data(&self) -> &Self::Data;
+    }
+
+    pub trait ScaleBase {
+        type Abs: Copy
+            + Ord
+            + Default
+            + TryFrom<u128>
+            + std::hash::Hash
+            + std::fmt::Debug
+            + std::fmt::Display;
+
+        const SCALE: Scale;
+        const MAX_ABS: Self::Abs;
+
+        fn push_abs(abs: Self::Abs, chars: &mut Vec<NumChar>);
+    }
+
+    pub trait ChineseNumeralBase: Signed {
+        const SCALE: Scale;
+
+        fn push_chars(&self, chars: &mut Vec<NumChar>);
+
+        fn push_chars_trimmed(&self, chars: &mut Vec<NumChar>) {
+            let start = chars.len();
+            self.push_chars(chars);
+            // trims the leading 一 of 十 in the highest group
+            if chars[start..].ends_with(&[NumChar::Shi, NumChar::One]) {
+                chars.pop();
+            }
+        }
+
+        fn to_chars(&self) -> Vec<NumChar> {
+            let mut chars = Vec::new();
+            self.push_chars(&mut chars);
+            chars
+        }
+
+        fn to_chars_trimmed(&self) -> Vec<NumChar> {
+            let mut chars = Vec::new();
+            self.push_chars_trimmed(&mut chars);
+            chars
+        }
+    }
+}
+
+use private::{ChineseNumeralBase, ScaleBase, Signed};
+
+/// Signs of numbers.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug, Default)]
+pub enum Sign {
+    /// Negative.
     Neg,
+    /// No sign, only for zero.
+    #[default]
     Nil,
+    /// Positive.
     Pos,
 }
 
-impl Default for Sign {
-    fn default() -> Self {
-        Self::Nil
+impl Sign {
+    /// Returns the character of the sign, [`NumChar::Neg`] (负) or [`NumChar::Pos`] (正), or `None` for zero.
+    pub const fn to_num_char(self) -> Option<NumChar> {
+        match self {
+            Sign::Neg => Some(NumChar::Neg),
+            Sign::Nil => None,
+            Sign::Pos => Some(NumChar::Pos),
+        }
+    }
+}
+
+impl std::fmt::Display for Sign {
+    /// Writes 负 or 正, or nothing for zero, with `-` for traditional Chinese and width for padding.
+    ///
+    /// ```
+    /// use chinese_numerals::Sign;
+    ///
+    /// assert_eq!("负", Sign::Neg.to_string());
+    /// assert_eq!("負", format!("{:-}", Sign::Neg));
+    /// assert_eq!("", Sign::Nil.to_string());
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variant = match f.sign_minus() {
+            true => Variant::Traditional,
+            false => Variant::Simplified,
+        };
+        match self.to_num_char() {
+            Some(ch) => f.pad(ch.to_lowercase(variant)),
+            None => f.pad(""),
+        }
+    }
+}
+
+impl From<std::cmp::Ordering> for Sign {
+    /// Converts the ordering of a number to zero to its sign.
+    fn from(ordering: std::cmp::Ordering) -> Self {
+        match ordering {
+            std::cmp::Ordering::Less => Sign::Neg,
+            std::cmp::Ordering::Equal => Sign::Nil,
+            std::cmp::Ordering::Greater => Sign::Pos,
+        }
     }
 }
 
-pub(crate) trait Signed {
-    type Data;
+impl From<Sign> for std::cmp::Ordering {
+    /// Converts the sign of a number to its ordering to zero.
+    fn from(sign: Sign) -> Self {
+        match sign {
+            Sign::Neg => std::cmp::Ordering::Less,
+            Sign::Nil => std::cmp::Ordering::Equal,
+            Sign::Pos => std::cmp::Ordering::Greater,
+        }
+    }
+}
 
-    fn sign(&self) -> Sign;
-    fn data(&self) -> &Self::Data;
+#[cfg(feature = "bigint")]
+impl From<num_bigint::Sign> for Sign {
+    fn from(sign: num_bigint::Sign) -> Self {
+        match sign {
+            num_bigint::Sign::Minus => Sign::Neg,
+            num_bigint::Sign::NoSign => Sign::Nil,
+            num_bigint::Sign::Plus => Sign::Pos,
+        }
+    }
 }
 
-pub(crate) trait ChineseNumeralBase: Signed {
-    fn to_chars(&self) -> Vec<crate::characters::NumChar>;
-    fn to_chars_trimmed(&self) -> Vec<crate::characters::NumChar>;
+#[cfg(feature = "bigint")]
+impl From<Sign> for num_bigint::Sign {
+    fn from(sign: Sign) -> Self {
+        match sign {
+            Sign::Neg => num_bigint::Sign::Minus,
+            Sign::Nil => num_bigint::Sign::NoSign,
+            Sign::Pos => num_bigint::Sign::Plus,
+        }
+    }
 }
 
 /// Chinese variants.
+///
+/// More variants may be added in minor versions, so matches on it outside this crate need a wildcard arm.
+#[non_exhaustive]
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum Variant {
     /// Simplified Chinese. Used in China, Singapore, and Malaysia.
     Simplified,
     /// Traditional Chinese. Used in Taiwan (Province of China), Hong Kong, and Macau.
     Traditional,
+    /// Japanese kanji numerals, which write 〇 for zero, omit 零 between digits, and omit 一 before 十, 百, and 千 in lowercase.
+    Japanese,
+    /// Sino-Korean hanja numerals, which omit 零 between digits, and omit 一 before 十, 百, and 千 in lowercase.
+    Korean,
+    /// Vietnamese numerals, which write the digits and 十, 百, and 千 in chữ Nôm in lowercase, e.g. 𠬠萬𠄩𠦳 for 12000, and follow the rules of Chinese. Uppercase is the same as traditional Chinese.
+    Vietnamese,
 }
 
-/// Out of range errors.
-#[cfg(feature = "bigint")]
-#[derive(Debug)]
-pub enum Error {
-    ShortScaleOutOfRange(u128),
-    MyriadScaleOutOfRange(BigUint),
-    MidScaleOutOfRange(BigUint),
-    LongScaleOutOfRange(BigUint),
+/// Letter cases of Chinese numerals.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Case {
+    /// Lowercase (小写数字), used for normal contexts.
+    Lower,
+    /// Uppercase (大写数字), used for financial contexts.
+    Upper,
 }
 
-#[cfg(feature = "bigint")]
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// Scales of Chinese numerals.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Scale {
+    /// Short scale (下数), in which each name represents a number 10 times the previous.
+    Short,
+    /// Myriad scale (万进), in which each name represents a number 1,0000 times the previous.
+    Myriad,
+    /// Mid-scale (中数), in which each name represents a number 1,0000,0000 times the previous.
+    Mid,
+    /// Long scale (上数), in which each name represents the square of the previous.
+    Long,
+}
+
+impl Scale {
+    /// Returns the Chinese name of the scale, e.g. 下数.
+    pub fn name_zh(self) -> &'static str {
         match self {
-            Error::ShortScaleOutOfRange(value) => write!(
-                f,
-                "Absolute value {value} out of range for a short scale number"
-            ),
-            Error::MyriadScaleOutOfRange(value) => write!(
-                f,
-                "Absolute value {value} out of range for a myriad scale number"
-            ),
-            Error::MidScaleOutOfRange(value) => write!(
-                f,
-                "Absolute value {value} out of range for a mid-scale number"
-            ),
-            Error::LongScaleOutOfRange(value) => write!(
-                f,
-                "Absolute value {value} out of range for a long scale number"
-            ),
+            Scale::Short => "下数",
+            Scale::Myriad => "万进",
+            Scale::Mid => "中数",
+            Scale::Long => "上数",
+        }
+    }
+
+    /// Returns the English name of the scale, e.g. short scale.
+    pub fn name_en(self) -> &'static str {
+        match self {
+            Scale::Short => "short scale",
+            Scale::Myriad => "myriad scale",
+            Scale::Mid => "mid-scale",
+            Scale::Long => "long scale",
+        }
+    }
+
+    /// Returns the quotation from 《五经算术》 describing the scale.
+    pub fn citation(self) -> &'static str {
+        match self {
+            Scale::Short => "下数者，十十变之。若言十万曰亿，十亿曰兆，十兆曰京也。",
+            Scale::Myriad => "以万进者，万万曰亿，万亿曰兆。",
+            Scale::Mid => "中数者，万万变之。若言万万曰亿，万万亿曰兆，万万兆曰京也。",
+            Scale::Long => "上数者，数穷则变。若言万万曰亿，亿亿曰兆、兆兆曰京也。",
+        }
+    }
+
+    /// Returns the exponent of 10 of the unit at `index`, where 万 is at index 0 and 载 at index 10.
+    pub(crate) const fn unit_exp(self, index: u32) -> u32 {
+        match self {
+            Scale::Short => 4 + index,
+            Scale::Myriad => 4 * (index + 1),
+            Scale::Mid if index == 0 => 4,
+            Scale::Mid => 8 * index,
+            Scale::Long => 4 << index,
+        }
+    }
+
+    /// Returns the exponent of 10 all absolute values in the scale are below, e.g. 48 for myriad scale, see [`MYRIAD_SCALE_MAX_EXP`].
+    pub const fn max_exp(self) -> u32 {
+        match self {
+            Scale::Short => SHORT_SCALE_MAX_EXP,
+            Scale::Myriad => MYRIAD_SCALE_MAX_EXP,
+            Scale::Mid => MID_SCALE_MAX_EXP,
+            Scale::Long => LONG_SCALE_MAX_EXP,
         }
     }
+
+    /// Returns the exponent of 10 of the leading digit of the largest number in the scale.
+    pub(crate) fn max_magnitude(self) -> usize {
+        self.max_exp() as usize - 1
+    }
+}
+
+/// Absolute values in short scale are below 10<sup>15</sup>, as 载 is 10<sup>14</sup>.
+pub const SHORT_SCALE_MAX_EXP: u32 = 15;
+/// Absolute values in myriad scale are below 10<sup>48</sup>, as 载 is 10<sup>44</sup>.
+pub const MYRIAD_SCALE_MAX_EXP: u32 = 48;
+/// Absolute values in mid-scale are below 10<sup>88</sup>, as 载 is 10<sup>80</sup>.
+pub const MID_SCALE_MAX_EXP: u32 = 88;
+/// Absolute values in long scale are below 10<sup>8192</sup>, as 载 is 10<sup>4096</sup>.
+pub const LONG_SCALE_MAX_EXP: u32 = 8192;
+
+/// Whether an integer is in range of the scale, i.e. its absolute value is below 10<sup>[`max_exp`](Scale::max_exp)</sup>, so that callers can check before converting instead of handling the [`Error`] of `TryFrom`.
+///
+/// The value may be any integer written in decimal by [`Display`](std::fmt::Display), e.g. primitive integers, big integers, or decimal strings with a sign and `_` or `,` as separators. Other values are not integers and do not fit.
+///
+/// ```
+/// use chinese_numerals::{fits_in_scale, Scale};
+///
+/// assert!(fits_in_scale(999_9999_9999_9999_i64, Scale::Short));
+/// assert!(!fits_in_scale(-1000_0000_0000_0000_i64, Scale::Short));
+/// assert!(fits_in_scale(u128::MAX, Scale::Myriad));
+/// assert!(fits_in_scale(format!("1{}", "0".repeat(87)), Scale::Mid));
+/// assert!(!fits_in_scale(format!("1{}", "0".repeat(88)), Scale::Mid));
+/// assert!(!fits_in_scale("1.5", Scale::Long));
+/// ```
+pub fn fits_in_scale(value: impl std::fmt::Display, scale: Scale) -> bool {
+    parse::split_decimal(&value.to_string())
+        .is_ok_and(|(_, digits)| digits.trim_start_matches('0').len() <= scale.max_exp() as usize)
+}
+
+/// A unit of 万 or above, with the power of ten it represents in a scale, see [`units`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct UnitInfo {
+    /// The unit character, e.g. [`NumChar::Yi`] for 亿.
+    pub char: NumChar,
+    /// The exponent of 10 the unit represents, e.g. 8 for 亿 in myriad scale.
+    pub exponent: u32,
+}
+
+/// Collects the units from 万 to 载 with their exponents in a scale at compile time.
+const fn unit_table(scale: Scale) -> [UnitInfo; 11] {
+    let mut table = [UnitInfo {
+        char: NumChar::Wan,
+        exponent: 0,
+    }; 11];
+    let mut index = 0;
+    while index < table.len() {
+        table[index] = UnitInfo {
+            char: characters::NUM_CHARS[NumChar::Wan as usize + index],
+            exponent: scale.unit_exp(index as u32),
+        };
+        index += 1;
+    }
+    table
+}
+
+static SHORT_UNITS: [UnitInfo; 11] = unit_table(Scale::Short);
+static MYRIAD_UNITS: [UnitInfo; 11] = unit_table(Scale::Myriad);
+static MID_UNITS: [UnitInfo; 11] = unit_table(Scale::Mid);
+static LONG_UNITS: [UnitInfo; 11] = unit_table(Scale::Long);
+
+/// Returns the units from 万 to 载 in ascending order with the powers of ten they represent in a scale.
+///
+/// ```
+/// use chinese_numerals::{units, NumChar, Scale};
+///
+/// let yi = units(Scale::Short)[1];
+/// assert_eq!((NumChar::Yi, 5), (yi.char, yi.exponent));
+/// assert_eq!(8, units(Scale::Myriad)[1].exponent);
+/// assert_eq!(80, units(Scale::Mid)[10].exponent);
+/// assert_eq!(4096, units(Scale::Long)[10].exponent);
+/// ```
+pub fn units(scale: Scale) -> &'static [UnitInfo] {
+    match scale {
+        Scale::Short => &SHORT_UNITS,
+        Scale::Myriad => &MYRIAD_UNITS,
+        Scale::Mid => &MID_UNITS,
+        Scale::Long => &LONG_UNITS,
+    }
+}
+
+impl std::str::FromStr for Scale {
+    type Err = ParseError;
+
+    /// Parses the English or Chinese name of a scale, e.g. `"short"`, `"short scale"`, or `"下数"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [Scale::Short, Scale::Myriad, Scale::Mid, Scale::Long]
+            .into_iter()
+            .find(|scale| {
+                let name = scale.name_en();
+                s.eq_ignore_ascii_case(name)
+                    || s.eq_ignore_ascii_case(
+                        name.trim_end_matches("scale").trim_end_matches([' ', '-']),
+                    )
+                    || s == scale.name_zh()
+            })
+            .ok_or_else(|| ParseError::UnknownScale(s.to_owned()))
+    }
+}
+
+/// Kinds of [`Error`].
+#[non_exhaustive]
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum ErrorKind {
+    /// The absolute value is out of range of the scale.
+    OutOfRange,
+    /// The string cannot be parsed.
+    Parse(ParseError),
+    /// The output is longer than the limit, which is given.
+    OutputTooLong(usize),
 }
 
-#[cfg(not(feature = "bigint"))]
-#[derive(Debug)]
-pub enum Error {
-    ShortScaleOutOfRange(u128),
+/// Out of range, parsing, and output length errors.
+///
+/// The error has the same shape with or without feature "bigint". Besides its [`kind`](Error::kind), it carries the offending [`value`](Error::value) and the target [`scale`](Error::scale) if known.
+///
+/// ```
+/// use chinese_numerals::{ErrorKind, Scale, ShortScaleInt};
+///
+/// let err = ShortScaleInt::try_from(-1_0000_0000_0000_0000_i64).unwrap_err();
+/// assert_eq!(&ErrorKind::OutOfRange, err.kind());
+/// assert_eq!(Some("10000000000000000"), err.value());
+/// assert_eq!(Some(Scale::Short), err.scale());
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    value: Option<String>,
+    magnitude: Option<usize>,
+    scale: Option<Scale>,
+}
+
+impl Error {
+    /// Creates an error of an absolute value out of range of the scale.
+    pub(crate) fn out_of_range(scale: Scale, value: impl std::fmt::Display) -> Self {
+        let value = value.to_string();
+        Self {
+            kind: ErrorKind::OutOfRange,
+            magnitude: Some(value.len().saturating_sub(1)),
+            value: Some(value),
+            scale: Some(scale),
+        }
+    }
+
+    /// Creates an error of an absolute value `abs` × 10<sup>`exponent`</sup> out of range of the scale, without writing out its digits.
+    pub(crate) fn out_of_range_sci(scale: Scale, abs: u128, exponent: u32) -> Self {
+        Self {
+            kind: ErrorKind::OutOfRange,
+            value: Some(format!("{abs}e{exponent}")),
+            magnitude: Some(abs.checked_ilog10().unwrap_or_default() as usize + exponent as usize),
+            scale: Some(scale),
+        }
+    }
+
+    /// Creates an error of the output longer than the limit.
+    pub(crate) fn output_too_long(max: usize) -> Self {
+        Self {
+            kind: ErrorKind::OutputTooLong(max),
+            value: None,
+            magnitude: None,
+            scale: None,
+        }
+    }
+
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// Returns the offending value in decimal, e.g. the absolute value out of range, or as `{mantissa}e{exponent}` for numbers too large to write out from `from_sci`.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Returns the target scale of the conversion.
+    pub fn scale(&self) -> Option<Scale> {
+        self.scale
+    }
+
+    /// Returns the decimal magnitude of the offending value, i.e. the exponent of 10 of its leading digit, e.g. 16 for 10000000000000000.
+    pub fn magnitude(&self) -> Option<usize> {
+        self.magnitude
+    }
+
+    /// Returns how many orders of magnitude an out of range value is over the largest number of the scale.
+    ///
+    /// ```
+    /// use chinese_numerals::MyriadScaleBigInt;
+    ///
+    /// let err = "1".repeat(52).parse::<MyriadScaleBigInt>().unwrap_err();
+    /// assert_eq!(Some(51), err.magnitude());
+    /// assert_eq!(Some(4), err.orders_over());
+    /// assert!(err.to_string().ends_with("needing a unit beyond 载 (10^44)"));
+    /// ```
+    pub fn orders_over(&self) -> Option<usize> {
+        match (&self.kind, self.scale, self.magnitude()) {
+            (ErrorKind::OutOfRange, Some(scale), Some(magnitude)) => {
+                Some(magnitude.saturating_sub(scale.max_magnitude()))
+            }
+            _ => None,
+        }
+    }
 }
 
-#[cfg(not(feature = "bigint"))]
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match *self {
-            Error::ShortScaleOutOfRange(value) => write!(
-                f,
-                "Absolute value {value} out of range for a short scale number"
-            ),
+        match &self.kind {
+            ErrorKind::OutOfRange => {
+                write!(
+                    f,
+                    "Absolute value {} out of range",
+                    self.value().unwrap_or("?")
+                )?;
+                let Some(scale) = self.scale else {
+                    return Ok(());
+                };
+                write!(f, " for a {} number", scale.name_en())?;
+                if let Some(orders) = self.orders_over() {
+                    let plural = if orders == 1 { "" } else { "s" };
+                    write!(
+                        f,
+                        ", {orders} order{plural} of magnitude over, needing a unit beyond {} (10^{})",
+                        NumChar::Zai.to_lowercase_simp(),
+                        scale.unit_exp(10)
+                    )?;
+                }
+                Ok(())
+            }
+            ErrorKind::Parse(err) => write!(f, "{err}"),
+            ErrorKind::OutputTooLong(max) => {
+                write!(f, "Output longer than the limit of {max} characters")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Stable error codes, see [`Error::code`] and [`ParseError::code`].
+///
+/// The codes are guaranteed to be stable across minor versions, so that they can be mapped to localized messages.
+pub mod codes {
+    /// Absolute value out of range for a short scale number.
+    pub const SHORT_SCALE_OUT_OF_RANGE: u16 = 1;
+    /// Absolute value out of range for a myriad scale number.
+    pub const MYRIAD_SCALE_OUT_OF_RANGE: u16 = 2;
+    /// Absolute value out of range for a mid-scale number.
+    pub const MID_SCALE_OUT_OF_RANGE: u16 = 3;
+    /// Absolute value out of range for a long scale number.
+    pub const LONG_SCALE_OUT_OF_RANGE: u16 = 4;
+    /// Output longer than the limit.
+    pub const OUTPUT_TOO_LONG: u16 = 5;
+    /// Cannot parse a number from an empty string.
+    pub const PARSE_EMPTY: u16 = 101;
+    /// Invalid character in a number.
+    pub const PARSE_INVALID_CHAR: u16 = 102;
+    /// Number too large for the type.
+    pub const PARSE_OVERFLOW: u16 = 103;
+    /// Unknown name of a scale.
+    pub const PARSE_UNKNOWN_SCALE: u16 = 104;
+}
+
+impl Error {
+    /// Returns the stable error code, listed in [`codes`].
+    pub fn code(&self) -> u16 {
+        match (&self.kind, self.scale) {
+            (ErrorKind::OutOfRange, Some(Scale::Myriad)) => codes::MYRIAD_SCALE_OUT_OF_RANGE,
+            (ErrorKind::OutOfRange, Some(Scale::Mid)) => codes::MID_SCALE_OUT_OF_RANGE,
+            (ErrorKind::OutOfRange, Some(Scale::Long)) => codes::LONG_SCALE_OUT_OF_RANGE,
+            (ErrorKind::OutOfRange, _) => codes::SHORT_SCALE_OUT_OF_RANGE,
+            (ErrorKind::Parse(err), _) => err.code(),
+            (ErrorKind::OutputTooLong(_), _) => codes::OUTPUT_TOO_LONG,
         }
     }
 }
 
-impl std::error::Error for Error {}
+/// Serializes the error as a struct with its [`code`](Error::code) and message.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Error {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Error", 2)?;
+        state.serialize_field("code", &self.code())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Self {
+            kind: ErrorKind::Parse(err),
+            value: None,
+            magnitude: None,
+            scale: None,
+        }
+    }
+}
 
 /// Provides methods to generate Chinease numeral expression for a number.
-pub trait ChineseNumeral {
+///
+/// The trait is sealed: it is implemented for the numbers of this crate only, and cannot be implemented outside it since version 0.3. Use [`DynChineseNumeral`] to handle numbers of different types together.
+pub trait ChineseNumeral: ChineseNumeralBase {
     /// Converts the number to lowercase (小写数字, used for normal contexts).
     fn to_lowercase(&self, variant: Variant) -> String;
 
@@ -199,6 +780,20 @@ pub trait ChineseNumeral {
         self.to_lowercase(Variant::Traditional)
     }
 
+    /// Converts the number to lowercase of at most `max_chars` characters, for table cells.
+    ///
+    /// A numeral too long is cut after a unit of 万 or above and followed by 余 (餘 in traditional Chinese), meaning "more than". If no unit fits, it is cut anywhere and followed by …
+    ///
+    /// ```
+    /// use chinese_numerals::{ChineseNumeral, MyriadScaleInt, Variant};
+    ///
+    /// let num = MyriadScaleInt::from(3_5200_0000_1234_u64);
+    /// assert_eq!("三兆五千二百亿余", num.to_lowercase_truncated(Variant::Simplified, 10));
+    /// assert_eq!("三兆餘", num.to_lowercase_truncated(Variant::Traditional, 5));
+    /// assert_eq!("三…", num.to_lowercase_truncated(Variant::Simplified, 2));
+    /// ```
+    fn to_lowercase_truncated(&self, variant: Variant, max_chars: usize) -> String;
+
     /// Converts the number to uppercase (大写数字, used for financial contexts).
     fn to_uppercase(&self, variant: Variant) -> String;
 
@@ -211,34 +806,617 @@ pub trait ChineseNumeral {
     fn to_uppercase_trad(&self) -> String {
         self.to_uppercase(Variant::Traditional)
     }
+
+    /// Converts the number to lowercase, or returns [`ErrorKind::OutputTooLong`] without building the string if it would be longer than `max_output_chars` characters, for services converting untrusted input.
+    ///
+    /// ```
+    /// use chinese_numerals::{ChineseNumeral, ErrorKind, MyriadScaleInt, Variant};
+    ///
+    /// let num = MyriadScaleInt::from(10305);
+    /// assert_eq!("一万零三百零五", num.to_lowercase_checked(Variant::Simplified, 7).unwrap());
+    /// let err = num.to_lowercase_checked(Variant::Simplified, 6).unwrap_err();
+    /// assert_eq!(&ErrorKind::OutputTooLong(6), err.kind());
+    /// ```
+    fn to_lowercase_checked(
+        &self,
+        variant: Variant,
+        max_output_chars: usize,
+    ) -> Result<String, Error>;
+
+    /// Converts the number to uppercase, or returns [`ErrorKind::OutputTooLong`] without building the string if it would be longer than `max_output_chars` characters.
+    fn to_uppercase_checked(
+        &self,
+        variant: Variant,
+        max_output_chars: usize,
+    ) -> Result<String, Error>;
+
+    /// Returns a view writing the number in lowercase when displayed, without building an intermediate `String`.
+    ///
+    /// ```
+    /// use chinese_numerals::{ChineseNumeral, MyriadScaleInt, Variant};
+    ///
+    /// let num = MyriadScaleInt::from(-10305);
+    /// assert_eq!("共负一万零三百零五元", format!("共{}元", num.display_lower(Variant::Simplified)));
+    /// assert_eq!("共負壹萬零叄佰零伍元", format!("共{}元", num.display_upper(Variant::Traditional)));
+    /// ```
+    fn display_lower(&self, variant: Variant) -> DisplayLower<'_, Self> {
+        DisplayLower::new(self, variant)
+    }
+
+    /// Returns a view writing the number in uppercase when displayed, without building an intermediate `String`.
+    fn display_upper(&self, variant: Variant) -> DisplayUpper<'_, Self> {
+        DisplayUpper::new(self, variant)
+    }
+
+    /// Converts the number to Chinese numerals with given options.
+    ///
+    /// ```
+    /// use chinese_numerals::{ChineseNumeral, MyriadScaleInt, Options, ZeroStyle};
+    ///
+    /// let mut options = Options::default();
+    /// options.positive_sign = true;
+    /// options.zero_style = ZeroStyle::Empty;
+    /// assert_eq!("正十二", MyriadScaleInt::from(12).to_chinese(&options));
+    /// assert_eq!("", MyriadScaleInt::from(0).to_chinese(&options));
+    /// ```
+    fn to_chinese(&self, options: &Options) -> String;
+
+    /// Converts the number to Chinese numerals with given options, or returns [`ErrorKind::OutputTooLong`] if the output exceeds [`Options::max_output_chars`].
+    ///
+    /// Unlike [`to_chinese`](ChineseNumeral::to_chinese), which truncates the output, it fails when the limit is exceeded.
+    ///
+    /// ```
+    /// use chinese_numerals::{ChineseNumeral, ErrorKind, MyriadScaleInt, Options};
+    ///
+    /// let mut options = Options::default();
+    /// options.max_output_chars = Some(4);
+    /// let num = MyriadScaleInt::from(12345);
+    /// let err = num.try_to_chinese(&options).unwrap_err();
+    /// assert_eq!(&ErrorKind::OutputTooLong(4), err.kind());
+    /// assert_eq!("一万二千", num.to_chinese(&options));
+    /// ```
+    fn try_to_chinese(&self, options: &Options) -> Result<String, Error>;
+
+    /// Splits the absolute value into groups below 10000 and their units in reading order, for custom layouts.
+    ///
+    /// ```
+    /// use chinese_numerals::{ChineseNumeral, MidScaleInt, NumChar, Segment};
+    ///
+    /// let num = MidScaleInt::from(1_0000_0000_0305_u64);
+    /// assert_eq!(
+    ///     vec![
+    ///         Segment { zero: false, value: 1, units: vec![NumChar::Wan, NumChar::Yi] },
+    ///         Segment { zero: true, value: 305, units: vec![] },
+    ///     ],
+    ///     num.segments()
+    /// );
+    /// ```
+    fn segments(&self) -> Vec<Segment>;
+
+    /// Converts the number to strings of groups ending with units of 万 and above, for aligning groups in columns of ledgers.
+    ///
+    /// The strings are the same as [`to_lowercase`](ChineseNumeral::to_lowercase) or [`to_uppercase`](ChineseNumeral::to_uppercase) when joined.
+    ///
+    /// ```
+    /// use chinese_numerals::{Case, ChineseNumeral, MyriadScaleInt, Variant};
+    ///
+    /// let num = MyriadScaleInt::from(1_0203_0405);
+    /// assert_eq!(
+    ///     vec!["一亿", "零二百零三万", "零四百零五"],
+    ///     num.to_grouped_strings(Case::Lower, Variant::Simplified)
+    /// );
+    /// ```
+    fn to_grouped_strings(&self, case: Case, variant: Variant) -> Vec<String>;
+
+    /// Explains the conversion step by step: the value and units of each group, and why each 零 is read.
+    ///
+    /// ```
+    /// use chinese_numerals::{ChineseNumeral, MyriadScaleInt};
+    ///
+    /// let explanation = MyriadScaleInt::from(10305).explain();
+    /// assert_eq!("一万零三百零五", explanation.numeral);
+    /// assert_eq!(
+    ///     "10305 in myriad scale: 一万零三百零五
+    ///   1 × 10^4 → 一万
+    ///   305 → 零三百零五
+    ///     零 stands for the zero digit at 10^3
+    ///     零 stands for the zero digit at 10^1
+    /// ",
+    ///     explanation.to_string()
+    /// );
+    /// ```
+    fn explain(&self) -> Explanation;
+
+    /// Returns the number of characters of [`to_chinese`](ChineseNumeral::to_chinese) with given options, without building the string, for layout and truncation decisions.
+    ///
+    /// ```
+    /// use chinese_numerals::{ChineseNumeral, MyriadScaleInt, Options};
+    ///
+    /// let num = MyriadScaleInt::from(-10305);
+    /// assert_eq!(8, num.char_len(&Options::default()));
+    /// assert_eq!(24, num.byte_len(&Options::default()));
+    /// ```
+    fn char_len(&self, options: &Options) -> usize;
+
+    /// Returns the number of bytes of [`to_chinese`](ChineseNumeral::to_chinese) with given options in UTF-8, without building the string.
+    fn byte_len(&self, options: &Options) -> usize;
+
+    /// Returns the highest unit read in the numeral, from 十 to 载, or `None` if the absolute value is below 10.
+    ///
+    /// ```
+    /// use chinese_numerals::{ChineseNumeral, MyriadScaleInt, NumChar, ShortScaleInt};
+    ///
+    /// assert_eq!(Some(NumChar::Yi), MyriadScaleInt::from(1_2345_0000_u64).highest_unit());
+    /// assert_eq!(Some(NumChar::Zhao), ShortScaleInt::from(-100_0000).highest_unit());
+    /// assert_eq!(Some(NumChar::Bai), MyriadScaleInt::from(305).highest_unit());
+    /// assert_eq!(None, MyriadScaleInt::from(7).highest_unit());
+    /// ```
+    fn highest_unit(&self) -> Option<NumChar>;
+
+    /// Returns the exponent of 10 of the [`highest_unit`](ChineseNumeral::highest_unit) in the scale, or 0 if no unit is read.
+    ///
+    /// ```
+    /// use chinese_numerals::{ChineseNumeral, MidScaleInt, MyriadScaleInt};
+    ///
+    /// assert_eq!(8, MyriadScaleInt::from(1_2345_0000_u64).magnitude());
+    /// assert_eq!(16, MidScaleInt::from(1_0000_0000_0000_0000_u64).magnitude());
+    /// assert_eq!(2, MyriadScaleInt::from(305).magnitude());
+    /// assert_eq!(0, MyriadScaleInt::from(7).magnitude());
+    /// ```
+    fn magnitude(&self) -> u32;
+}
+
+/// Integers of a scale, constructed uniformly from a sign and an absolute value, so that generic code can construct numbers of any scale.
+///
+/// The sign is normalized: a zero absolute value always has [`Sign::Nil`], and a nonzero absolute value with [`Sign::Nil`] is positive.
+///
+/// ```
+/// use chinese_numerals::{ChineseNumeral, MyriadScaleInt, ScaleInt, ShortScaleInt, Sign};
+///
+/// fn negate<T: ScaleInt>(num: T) -> T {
+///     let (sign, abs) = num.into_parts();
+///     let sign = if sign == Sign::Neg { Sign::Pos } else { Sign::Neg };
+///     T::new(sign, abs)
+/// }
+///
+/// assert_eq!("负十二", negate(MyriadScaleInt::from(12)).to_lowercase_simp());
+/// assert_eq!(MyriadScaleInt::from(-5), MyriadScaleInt::new_negative(5));
+/// assert!(ShortScaleInt::try_new(Sign::Pos, u64::MAX).is_err());
+/// ```
+pub trait ScaleInt: ChineseNumeral + Sized {
+    /// Type of the absolute value.
+    type Abs;
+
+    /// Constructs a number from a sign and an absolute value, or returns [`Error`] if the absolute value is out of range.
+    fn try_new(sign: Sign, abs: Self::Abs) -> Result<Self, Error>;
+
+    /// Splits the number into its sign and absolute value.
+    fn into_parts(self) -> (Sign, Self::Abs);
+
+    /// Constructs a number from a sign and an absolute value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the absolute value is out of range.
+    fn new(sign: Sign, abs: Self::Abs) -> Self {
+        match Self::try_new(sign, abs) {
+            Ok(num) => num,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Constructs a number from a sign and an absolute value, the inverse of [`into_parts`](ScaleInt::into_parts).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the absolute value is out of range.
+    fn from_parts((sign, abs): (Sign, Self::Abs)) -> Self {
+        Self::new(sign, abs)
+    }
+
+    /// Constructs a non-positive number from its absolute value, or returns [`Error`] if the absolute value is out of range.
+    fn try_new_negative(abs: Self::Abs) -> Result<Self, Error> {
+        Self::try_new(Sign::Neg, abs)
+    }
+
+    /// Constructs a non-positive number from its absolute value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the absolute value is out of range.
+    fn new_negative(abs: Self::Abs) -> Self {
+        Self::new(Sign::Neg, abs)
+    }
+}
+
+/// Collects the characters of a number in reversed order, including its sign.
+fn signed_chars<T: ChineseNumeralBase + ?Sized>(
+    num: &T,
+    case: Case,
+    variant: Variant,
+) -> Vec<NumChar> {
+    let mut chars = match case {
+        Case::Lower => num.to_chars_trimmed(),
+        Case::Upper => num.to_chars(),
+    };
+    match num.sign() {
+        Sign::Neg => chars.push(NumChar::Neg),
+        Sign::Nil => chars.push(NumChar::Zero),
+        _ => {}
+    }
+    omit_zeros_and_ones(&mut chars, case, variant);
+    chars
+}
+
+/// Writes a number in lowercase simplified Chinese to a `defmt` formatter, glyph by glyph.
+#[cfg(feature = "defmt")]
+fn write_defmt<T: ChineseNumeralBase>(num: &T, f: defmt::Formatter<'_>) {
+    for ch in signed_chars(num, Case::Lower, Variant::Simplified)
+        .into_iter()
+        .rev()
+    {
+        defmt::write!(f, "{=str}", ch.to_lowercase_simp());
+    }
+}
+
+/// Serializes a number as a struct of its value as a decimal string and its numeral with the default options in human-readable formats, e.g. JSON, or as the decimal string only in compact formats.
+#[cfg(feature = "serde")]
+fn serialize_numeral<T: ChineseNumeral, S: serde::Serializer>(
+    num: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let value = match num.sign() {
+        Sign::Neg => format!("-{}", num.data()),
+        _ => num.data().to_string(),
+    };
+    if serializer.is_human_readable() {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ChineseNumeral", 2)?;
+        state.serialize_field("value", &value)?;
+        state.serialize_field("chinese", &num.to_chinese(&default_options()))?;
+        state.end()
+    } else {
+        serializer.serialize_str(&value)
+    }
+}
+
+/// Converts a number to the case, or returns [`ErrorKind::OutputTooLong`] if it has more characters than the limit, checked before the string is allocated.
+fn checked_case<T: ChineseNumeralBase>(
+    num: &T,
+    case: Case,
+    variant: Variant,
+    max_output_chars: usize,
+) -> Result<String, Error> {
+    let chars = signed_chars(num, case, variant);
+    if chars.len() > max_output_chars {
+        return Err(Error::output_too_long(max_output_chars));
+    }
+    Ok(chars
+        .into_iter()
+        .rev()
+        .map(|ch| match case {
+            Case::Lower => ch.to_lowercase(variant),
+            Case::Upper => ch.to_uppercase(variant),
+        })
+        .collect())
+}
+
+/// Applies the rules of Japanese and Korean numerals to characters in reversed order.
+///
+/// Zero is only written for the number zero itself, and lowercase numerals omit 一 before 十, 百, and 千.
+fn omit_zeros_and_ones(chars: &mut Vec<NumChar>, case: Case, variant: Variant) {
+    if !matches!(variant, Variant::Japanese | Variant::Korean) {
+        return;
+    }
+    let keep_zero = chars.len() == 1;
+    let mut prev = None;
+    chars.retain(|&ch| {
+        let keep = match ch {
+            NumChar::Zero => keep_zero,
+            NumChar::One if case == Case::Lower => !matches!(
+                prev,
+                Some(NumChar::Shi) | Some(NumChar::Bai) | Some(NumChar::Qian)
+            ),
+            _ => true,
+        };
+        prev = Some(ch);
+        keep
+    });
 }
 
 impl<T: ChineseNumeralBase> ChineseNumeral for T {
     fn to_lowercase(&self, variant: Variant) -> String {
-        let method = match variant {
-            Variant::Simplified => NumChar::to_lowercase_simp,
-            Variant::Traditional => NumChar::to_lowercase_trad,
-        };
-        let mut chars = self.to_chars_trimmed();
-        match self.sign() {
-            Sign::Neg => chars.push(NumChar::Neg),
-            Sign::Nil => chars.push(NumChar::Zero),
-            _ => {}
-        }
-        chars.into_iter().rev().map(method).collect()
+        signed_chars(self, Case::Lower, variant)
+            .into_iter()
+            .rev()
+            .map(|ch| ch.to_lowercase(variant))
+            .collect()
     }
 
     fn to_uppercase(&self, variant: Variant) -> String {
-        let method = match variant {
-            Variant::Simplified => NumChar::to_uppercase_simp,
-            Variant::Traditional => NumChar::to_uppercase_trad,
+        signed_chars(self, Case::Upper, variant)
+            .into_iter()
+            .rev()
+            .map(|ch| ch.to_uppercase(variant))
+            .collect()
+    }
+
+    fn to_lowercase_checked(
+        &self,
+        variant: Variant,
+        max_output_chars: usize,
+    ) -> Result<String, Error> {
+        checked_case(self, Case::Lower, variant, max_output_chars)
+    }
+
+    fn to_uppercase_checked(
+        &self,
+        variant: Variant,
+        max_output_chars: usize,
+    ) -> Result<String, Error> {
+        checked_case(self, Case::Upper, variant, max_output_chars)
+    }
+
+    fn to_lowercase_truncated(&self, variant: Variant, max_chars: usize) -> String {
+        let mut chars = signed_chars(self, Case::Lower, variant);
+        chars.reverse();
+        if chars.len() <= max_chars {
+            return chars
+                .into_iter()
+                .map(|ch| ch.to_lowercase(variant))
+                .collect();
+        }
+
+        // cuts after the last complete units leaving room for 余
+        let cut = (1..max_chars)
+            .rev()
+            .find(|&i| chars[i - 1].is_myriad_unit() && !chars[i].is_myriad_unit());
+        let (len, suffix) = match (cut, variant) {
+            (Some(cut), Variant::Traditional) => (cut, "餘"),
+            (Some(cut), _) => (cut, "余"),
+            (None, _) if max_chars == 0 => return String::new(),
+            (None, _) => (max_chars - 1, "…"),
         };
-        let mut chars = self.to_chars();
-        match self.sign() {
-            Sign::Neg => chars.push(NumChar::Neg),
-            Sign::Nil => chars.push(NumChar::Zero),
-            _ => {}
+        chars[..len]
+            .iter()
+            .map(|ch| ch.to_lowercase(variant))
+            .chain(std::iter::once(suffix))
+            .collect()
+    }
+
+    fn to_chinese(&self, options: &Options) -> String {
+        let mut output = render(self, options);
+        if let Some(max) = options.max_output_chars {
+            if let Some((pos, _)) = output.char_indices().nth(max) {
+                output.truncate(pos);
+            }
+        }
+        output
+    }
+
+    fn try_to_chinese(&self, options: &Options) -> Result<String, Error> {
+        let output = render(self, options);
+        match options.max_output_chars {
+            Some(max) if output.chars().nth(max).is_some() => Err(Error::output_too_long(max)),
+            _ => Ok(output),
+        }
+    }
+
+    fn segments(&self) -> Vec<Segment> {
+        segment::segments(self.to_chars())
+    }
+
+    fn to_grouped_strings(&self, case: Case, variant: Variant) -> Vec<String> {
+        let mut chars = signed_chars(self, case, variant);
+        chars.reverse();
+        let mut groups = vec![String::new()];
+        for (i, &ch) in chars.iter().enumerate() {
+            let group = groups.last_mut().expect("Groups are not empty");
+            group.push_str(match case {
+                Case::Lower => ch.to_lowercase(variant),
+                Case::Upper => ch.to_uppercase(variant),
+            });
+            let next = chars.get(i + 1);
+            if ch.is_myriad_unit() && next.is_some_and(|next| !next.is_myriad_unit()) {
+                groups.push(String::new());
+            }
+        }
+        groups
+    }
+
+    fn explain(&self) -> Explanation {
+        explain::explain(self)
+    }
+
+    fn char_len(&self, options: &Options) -> usize {
+        measure(self, options).0
+    }
+
+    fn byte_len(&self, options: &Options) -> usize {
+        measure(self, options).1
+    }
+
+    fn highest_unit(&self) -> Option<NumChar> {
+        self.to_chars()
+            .into_iter()
+            .filter(|&ch| {
+                ch as usize >= NumChar::Shi as usize && ch as usize <= NumChar::Zai as usize
+            })
+            .max_by_key(|&ch| ch as usize)
+    }
+
+    fn magnitude(&self) -> u32 {
+        match self.highest_unit() {
+            Some(ch) if ch.is_myriad_unit() => {
+                units(Self::SCALE)[ch as usize - NumChar::Wan as usize].exponent
+            }
+            Some(ch) => (ch as usize - NumChar::Shi as usize + 1) as u32,
+            None => 0,
+        }
+    }
+}
+
+/// Renders a number with given options, ignoring the limit of output length.
+fn render<T: ChineseNumeralBase>(num: &T, options: &Options) -> String {
+    let mut output = String::new();
+    render_into(num, options, &mut Vec::new(), &mut output);
+    output
+}
+
+/// Renders a number with given options to the end of the output, ignoring the limit of output length.
+///
+/// The characters are collected in the given buffer, which is cleared first, so that it can be reused.
+pub(crate) fn render_into<T: ChineseNumeralBase>(
+    num: &T,
+    options: &Options,
+    chars: &mut Vec<NumChar>,
+    output: &mut String,
+) {
+    match options.style {
+        Style::IcuCompatible => {
+            options.render_tokens_into(icu::tokens(num, options).into_iter(), output);
+            return;
+        }
+        Style::FullWidth | Style::FullWidthWithUnits => {
+            options.render_tokens_into(fullwidth::tokens(num, options).into_iter(), output);
+            return;
+        }
+        Style::Standard => {}
+    }
+    collect_chars(num, options, chars);
+    let separator = options.group_separator.as_deref();
+    let mut prev: Option<NumChar> = None;
+    let tokens = chars.drain(..).rev().flat_map(|ch| {
+        // separates groups after runs of units of 万 and above
+        let boundary = prev.is_some_and(|prev| prev.is_myriad_unit() && !ch.is_myriad_unit());
+        prev = Some(ch);
+        let separator = separator.filter(|_| boundary);
+        separator
+            .map(|separator| Token::Text(separator.to_owned()))
+            .into_iter()
+            .chain(std::iter::once(Token::Char(ch)))
+    });
+    let zheng = options.append_zheng.then(|| Token::Text(ZHENG.to_owned()));
+    options.render_tokens_into(tokens.chain(zheng), output);
+}
+
+/// 整, appended to numerals with [`Options::append_zheng`].
+const ZHENG: &str = "整";
+
+/// Collects the characters of a number in [`Style::Standard`] in reversed order, clearing the buffer first.
+fn collect_chars<T: ChineseNumeralBase>(num: &T, options: &Options, chars: &mut Vec<NumChar>) {
+    chars.clear();
+    let (leading_one, zero_policy, colloquial) = options.rules();
+    match leading_one {
+        LeadingOneStyle::Omit => num.push_chars_trimmed(chars),
+        LeadingOneStyle::Keep => num.push_chars(chars),
+    }
+    if colloquial && segment::implied_trailing_units(&segment::segments(num.to_chars()), T::SCALE) {
+        let last_digit = chars
+            .iter()
+            .position(|&ch| characters::NUM_CHARS[1..10].contains(&ch))
+            .unwrap_or(0);
+        chars.drain(..last_digit);
+    }
+    match num.sign() {
+        Sign::Neg => chars.push(NumChar::Neg),
+        Sign::Nil if options.zero_style == ZeroStyle::Ling => chars.push(NumChar::Zero),
+        Sign::Pos if options.positive_sign => chars.push(NumChar::Pos),
+        _ => {}
+    }
+    match zero_policy {
+        ZeroPolicy::Collapse => {}
+        ZeroPolicy::Omit => {
+            let keep_zero = chars.len() == 1;
+            chars.retain(|&ch| keep_zero || ch != NumChar::Zero);
+        }
+        ZeroPolicy::Verbose => {
+            let mut runs = segment::zero_runs(&segment::segments(num.to_chars()), T::SCALE);
+            let mut expanded = Vec::with_capacity(chars.len());
+            // the runs are popped in reversed order, the same as the characters
+            for &ch in chars.iter() {
+                let count = match ch {
+                    NumChar::Zero => runs.pop().unwrap_or(1),
+                    _ => 1,
+                };
+                expanded.extend(std::iter::repeat_n(ch, count as usize));
+            }
+            *chars = expanded;
+        }
+    }
+    omit_zeros_and_ones(chars, options.case, options.variant);
+}
+
+/// Measures the numbers of characters and bytes of a number rendered with given options, respecting the limit of output length.
+///
+/// Only the characters are collected in [`Style::Standard`] without middlewares or group separators, while the output is rendered otherwise.
+fn measure<T: ChineseNumeralBase>(num: &T, options: &Options) -> (usize, usize) {
+    let limit = options.max_output_chars.unwrap_or(usize::MAX);
+    if options.style != Style::Standard
+        || !options.middlewares.is_empty()
+        || options.group_separator.is_some()
+    {
+        let output = render(num, options);
+        return output
+            .chars()
+            .take(limit)
+            .fold((0, 0), |(chars, bytes), ch| {
+                (chars + 1, bytes + ch.len_utf8())
+            });
+    }
+    let mut chars = Vec::new();
+    collect_chars(num, options, &mut chars);
+    match options.measure_chars(chars.into_iter().rev(), limit) {
+        (count, bytes) if options.append_zheng && count < limit => (count + 1, bytes + ZHENG.len()),
+        measured => measured,
+    }
+}
+
+/// Styles of connecting the two ends of a range.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum RangeStyle {
+    /// Spells out both ends and connects them with 至, e.g. 三万至五万.
+    Full,
+    /// Connects the ends with 到, and elides the units shared by both ends from the start, e.g. 三到五万.
+    Compressed,
+}
+
+/// Formats a range of numbers in lowercase.
+///
+/// In [`RangeStyle::Compressed`] style, the trailing units of 万 and above are elided from the start, if both ends are positive and end with the same units. Otherwise, both ends are spelled out.
+///
+/// ```
+/// use chinese_numerals::{format_range, MyriadScaleInt, RangeStyle, Variant};
+///
+/// let start = MyriadScaleInt::from(30_000);
+/// let end = MyriadScaleInt::from(50_000);
+/// assert_eq!("三万至五万", format_range(&start, &end, RangeStyle::Full, Variant::Simplified));
+/// assert_eq!("三到五万", format_range(&start, &end, RangeStyle::Compressed, Variant::Simplified));
+/// ```
+pub fn format_range<T: ChineseNumeral>(
+    start: &T,
+    end: &T,
+    style: RangeStyle,
+    variant: Variant,
+) -> String {
+    let method = |ch: NumChar| ch.to_lowercase(variant);
+    let mut start_chars = signed_chars(start, Case::Lower, variant);
+    let end_chars = signed_chars(end, Case::Lower, variant);
+    let conj = match style {
+        RangeStyle::Full => '至',
+        RangeStyle::Compressed => '到',
+    };
+
+    if style == RangeStyle::Compressed && start.sign() == Sign::Pos && end.sign() == Sign::Pos {
+        let units = |chars: &[NumChar]| chars.iter().take_while(|c| c.is_myriad_unit()).count();
+        let len = units(&start_chars);
+        if len > 0 && len == units(&end_chars) && start_chars[..len] == end_chars[..len] {
+            start_chars.drain(..len);
         }
-        chars.into_iter().rev().map(method).collect()
     }
+
+    let mut range: String = start_chars.into_iter().rev().map(method).collect();
+    range.push(conj);
+    range.extend(end_chars.into_iter().rev().map(method));
+    range
 }
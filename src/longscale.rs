@@ -1,21 +1,26 @@
-use crate::{characters::*, ChineseNumeralBase, MidScaleInt, Sign, Signed};
+use crate::{characters::*, ChineseInt, Mid, Scale, ScaleBase, ScaleKind, Sign};
+
+/// Marker type of long scale (上数) for [`ChineseInt`].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub struct Long;
+
+impl ScaleKind for Long {}
 
 /// Long scale integers (上数).
 ///
 /// 「上数者，数穷则变。若言万万曰亿，亿亿曰兆、兆兆曰京也。」
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, Default)]
-pub struct LongScaleInt {
-    pub(super) sign: Sign,
-    pub(super) data: u128,
-}
+pub type LongScaleInt = ChineseInt<Long>;
 
 impl LongScaleInt {
     /// Generates a new non-positive long scale integer from given absolute value.
     ///
     /// There is no way to generate Chinese numerals by `From` trait from negative primitive numbers less than [`i128::MIN`]. This associated function provides a way to generate them from the given absolute value less than or equal to [`u128::MAX`]. This crate also provides struct [`LongScaleBigInt`] for integers with absolute value larger than `u128::MAX`.
-    pub fn new_non_pos(abs: u128) -> Self {
+    pub const fn new_non_pos(abs: u128) -> Self {
         if abs == 0 {
-            Self::default()
+            Self {
+                sign: Sign::Nil,
+                data: 0,
+            }
         } else {
             Self {
                 sign: Sign::Neg,
@@ -25,59 +30,55 @@ impl LongScaleInt {
     }
 }
 
-impl ChineseNumeralBase for LongScaleInt {
-    fn to_chars(&self) -> Vec<NumChar> {
-        let mut chars = Vec::new();
-        let mut num = *self.data();
+impl ScaleBase for Long {
+    type Abs = u128;
+
+    const SCALE: Scale = Scale::Long;
+    const MAX_ABS: u128 = u128::MAX;
+
+    fn push_abs(mut num: u128, chars: &mut Vec<NumChar>) {
+        let start = chars.len();
         let mut prev_rem = 1000_0000_0000_0000;
 
         // u128 uses up to NUM_CHARS[16] = Jing (京) for long scale numerals
+        #[allow(clippy::needless_range_loop)]
         for exp in 14..=16 {
+            if num == 0 {
+                break;
+            }
             let rem = num % 1_0000_0000_0000_0000;
             num /= 1_0000_0000_0000_0000;
 
             if rem > 0 {
-                if !chars.is_empty() && prev_rem < 1000_0000_0000_0000 {
+                if chars.len() > start && prev_rem < 1000_0000_0000_0000 {
                     chars.push(NUM_CHARS[0]);
                 }
                 if exp > 14 {
                     chars.push(NUM_CHARS[exp]);
                 }
-                let mid = MidScaleInt::from(rem);
-                let mut node = mid.to_chars();
-                chars.append(&mut node);
+                Mid::push_abs(rem, chars);
             }
             prev_rem = rem;
         }
-        chars
-    }
-
-    fn to_chars_trimmed(&self) -> Vec<NumChar> {
-        let mut chars = self.to_chars();
-        let mut data = *self.data();
-        while data >= 1_0000 {
-            data /= 1_0000;
-        }
-        if data >= 10 && data <= 19 {
-            let one = chars.pop();
-            debug_assert_eq!(one, Some(NumChar::One));
-        }
-        chars
     }
 }
 
+#[cfg(feature = "bigint")]
+use crate::{ChineseNumeralBase, Signed};
 #[cfg(feature = "bigint")]
 use num_bigint::BigUint;
 #[cfg(feature = "bigint")]
 use num_integer::Integer;
 #[cfg(feature = "bigint")]
 use num_traits::{ToPrimitive, Zero};
+#[cfg(feature = "bigint")]
+use std::borrow::Cow;
 
 /// Long scale big integers (上数).
 ///
 /// Use it by turning on feature "bigint". It uses [`BigUint`](num_bigint::BigUint) to store the absolute value. Therefore, all integers that can be expressed in long scale are included.
 #[cfg(feature = "bigint")]
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug, Default)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug, Default)]
 pub struct LongScaleBigInt {
     pub(super) sign: Sign,
     pub(super) data: BigUint,
@@ -227,60 +228,52 @@ impl LongScaleBigInt {
 
 #[cfg(feature = "bigint")]
 impl ChineseNumeralBase for LongScaleBigInt {
-    fn to_chars(&self) -> Vec<NumChar> {
-        let mut chars = Vec::new();
-        let mut num = self.data().to_owned();
-        // 1000_0000_0000_0000
-        let mut prev_rem = BigUint::new(vec![2764472320, 232830]);
-        let mut lim = BigUint::new(vec![2764472320, 232830]);
-        // 1_0000_0000_0000_0000
-        let mut div = BigUint::new(vec![1874919424, 2328306]);
-        let ten = BigUint::new(vec![10]);
+    const SCALE: Scale = Scale::Long;
 
-        for exp in 14..=23 {
-            let (_, rem) = num.div_rem(&div);
-            num /= &div;
+    fn push_chars(&self, chars: &mut Vec<NumChar>) {
+        push_big(self.data(), chars);
+    }
+}
 
-            if rem > BigUint::zero() {
-                if !chars.is_empty() && prev_rem < lim {
-                    chars.push(NUM_CHARS[0]);
-                }
-                if exp > 14 {
-                    chars.push(NUM_CHARS[exp]);
-                }
-                let mut node = if exp <= 15 {
-                    let rem = rem.to_u64().unwrap();
-                    let mid = MidScaleInt::from(rem);
-                    mid.to_chars()
-                } else {
-                    let long = Self::try_from(&rem).unwrap();
-                    long.to_chars()
-                };
-                chars.append(&mut node);
+/// Pushes the characters of an absolute value in long scale in reversed order, borrowing it until the first division.
+#[cfg(feature = "bigint")]
+fn push_big(num: &BigUint, chars: &mut Vec<NumChar>) {
+    let start = chars.len();
+    let mut num = Cow::Borrowed(num);
+    // 1000_0000_0000_0000
+    let mut prev_rem = BigUint::new(vec![2764472320, 232830]);
+    let mut lim = BigUint::new(vec![2764472320, 232830]);
+    // 1_0000_0000_0000_0000
+    let mut div = BigUint::new(vec![1874919424, 2328306]);
+    let ten = BigUint::new(vec![10]);
+
+    #[allow(clippy::needless_range_loop)]
+    for exp in 14..=23 {
+        if num.is_zero() {
+            break;
+        }
+        let (quot, rem) = num.div_rem(&div);
+        num = Cow::Owned(quot);
+
+        if rem > BigUint::zero() {
+            if chars.len() > start && prev_rem < lim {
+                chars.push(NUM_CHARS[0]);
             }
-            prev_rem = rem;
             if exp > 14 {
-                prev_rem *= &div;
-                div = &div * &div;
-                lim = &div / &ten;
+                chars.push(NUM_CHARS[exp]);
+            }
+            if exp <= 15 {
+                let rem = rem.to_u64().unwrap();
+                Mid::push_abs(rem.into(), chars);
+            } else {
+                push_big(&rem, chars);
             }
         }
-        chars
-    }
-
-    fn to_chars_trimmed(&self) -> Vec<NumChar> {
-        let mut chars = self.to_chars();
-        let mut data = self.data().to_owned();
-        let div = BigUint::new(vec![1_0000]);
-        let ten = BigUint::new(vec![10]);
-        let nineteen = BigUint::new(vec![19]);
-        while data >= div {
-            data /= &div;
-        }
-        if data >= ten && data <= nineteen {
-            let one = chars.pop();
-            debug_assert_eq!(one, Some(NumChar::One));
+        prev_rem = rem;
+        if exp > 14 {
+            prev_rem *= &div;
+            div = &div * &div;
+            lim = &div / &ten;
         }
-        chars
     }
 }
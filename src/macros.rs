@@ -1,18 +1,56 @@
 use crate::{
-    ChineseNumeral, Error, LongScaleInt, MidScaleInt, MyriadScaleInt, ShortScaleInt, Sign,
+    const_fmt::format_signed,
+    default_options,
+    digits::{format_radix, format_scientific},
+    format_digits,
+    parse::{parse_numeral, split_decimal},
+    Case, ChineseNumeral, ChineseNumeralBase, ConstStr, Error, LongScaleInt, LongScaleInt32,
+    LongScaleInt64, MidScaleInt, MidScaleInt32, MidScaleInt64, MyriadScaleInt, MyriadScaleInt32,
+    MyriadScaleInt64, NumChar, ParseError, Scale, ScaleInt, ShortScaleInt, Sign, Signed, Variant,
 };
+use std::sync::Arc;
 
 #[cfg(feature = "bigint")]
 use crate::{LongScaleBigInt, MidScaleBigInt, MyriadScaleBigInt};
 #[cfg(feature = "bigint")]
 use num_bigint::{BigInt, BigUint};
 
+#[cfg(feature = "bigint")]
 macro_rules! impl_signed_int {
     ($($int:ident, $data:ty),+ $(,)?) => {
-        $(impl crate::Signed for $int {
+        $(impl $int {
+            /// Whether the number is zero.
+            pub fn is_zero(&self) -> bool {
+                self.sign() == Sign::Nil
+            }
+
+            /// Whether the number is strictly positive.
+            pub fn is_positive(&self) -> bool {
+                self.sign() == Sign::Pos
+            }
+
+            /// Whether the number is strictly negative.
+            pub fn is_negative(&self) -> bool {
+                self.sign() == Sign::Neg
+            }
+
+            /// Checks in debug builds that zero, and only zero, has no sign.
+            fn debug_check_sign(&self) {
+                debug_assert_eq!(
+                    self.sign == Sign::Nil,
+                    self.data == <$data>::default(),
+                    "sign {:?} is inconsistent with absolute value {}",
+                    self.sign,
+                    self.data,
+                );
+            }
+        }
+
+        impl Signed for $int {
             type Data = $data;
 
             fn sign(&self) -> Sign {
+                self.debug_check_sign();
                 self.sign
             }
 
@@ -23,30 +61,296 @@ macro_rules! impl_signed_int {
     };
 }
 
-impl_signed_int! {ShortScaleInt, u64, MyriadScaleInt, u128, MidScaleInt, u128, LongScaleInt, u128}
-
 #[cfg(feature = "bigint")]
 impl_signed_int! {MyriadScaleBigInt, BigUint, MidScaleBigInt, BigUint, LongScaleBigInt, BigUint}
 
+macro_rules! impl_ord {
+    ($($int:ident),+ $(,)?) => {
+        $(impl PartialOrd for $int {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $int {
+            /// Compares the numbers by their values, e.g. -5 is less than -3.
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                match (self.sign, other.sign) {
+                    (Sign::Neg, Sign::Neg) => other.data.cmp(&self.data),
+                    (sign, other_sign) if sign == other_sign => self.data.cmp(&other.data),
+                    (sign, other_sign) => sign.cmp(&other_sign),
+                }
+            }
+        })+
+    };
+}
+
+impl_ord! {ShortScaleInt, MyriadScaleInt, MidScaleInt, LongScaleInt}
+impl_ord! {MyriadScaleInt32, MidScaleInt32, LongScaleInt32, MyriadScaleInt64, MidScaleInt64, LongScaleInt64}
+
+#[cfg(feature = "bigint")]
+impl_ord! {MyriadScaleBigInt, MidScaleBigInt, LongScaleBigInt}
+
+/// Compares a number with a primitive integer by their signs and absolute values.
+fn cmp_prim<D: Ord + TryFrom<u128>>(
+    sign: Sign,
+    data: &D,
+    other_sign: Sign,
+    other_abs: u128,
+) -> std::cmp::Ordering {
+    let cmp_abs = || match D::try_from(other_abs) {
+        Ok(other_abs) => data.cmp(&other_abs),
+        Err(_) => std::cmp::Ordering::Less,
+    };
+    match (sign, other_sign) {
+        (Sign::Neg, Sign::Neg) => cmp_abs().reverse(),
+        (sign, other_sign) if sign == other_sign => cmp_abs(),
+        (sign, other_sign) => sign.cmp(&other_sign),
+    }
+}
+
+macro_rules! impl_cmp_prim {
+    ($($int:ident),+ $(,)?) => {
+        $(impl_cmp_prim!(@signed $int, i32, i64, i128, isize);
+        impl_cmp_prim!(@unsigned $int, u32, u64, u128, usize);)+
+    };
+    (@signed $int:ident, $($prim:ty),+) => {
+        $(impl_cmp_prim!(@impl $int, $prim, |value: $prim| (Sign::from(value.cmp(&0)), value.unsigned_abs() as u128));)+
+    };
+    (@unsigned $int:ident, $($prim:ty),+) => {
+        $(impl_cmp_prim!(@impl $int, $prim, |value: $prim| (Sign::from(value.cmp(&0)), value as u128));)+
+    };
+    (@impl $int:ident, $prim:ty, $parts:expr) => {
+        impl PartialEq<$prim> for $int {
+            fn eq(&self, other: &$prim) -> bool {
+                self.partial_cmp(other) == Some(std::cmp::Ordering::Equal)
+            }
+        }
+
+        impl PartialOrd<$prim> for $int {
+            /// Compares the number with a primitive integer by their values.
+            fn partial_cmp(&self, other: &$prim) -> Option<std::cmp::Ordering> {
+                let (other_sign, other_abs) = $parts(*other);
+                Some(cmp_prim(self.sign, &self.data, other_sign, other_abs))
+            }
+        }
+
+        impl PartialEq<$int> for $prim {
+            fn eq(&self, other: &$int) -> bool {
+                other == self
+            }
+        }
+
+        impl PartialOrd<$int> for $prim {
+            fn partial_cmp(&self, other: &$int) -> Option<std::cmp::Ordering> {
+                other.partial_cmp(self).map(std::cmp::Ordering::reverse)
+            }
+        }
+    };
+}
+
+impl_cmp_prim! {ShortScaleInt, MyriadScaleInt, MidScaleInt, LongScaleInt}
+impl_cmp_prim! {MyriadScaleInt32, MidScaleInt32, LongScaleInt32, MyriadScaleInt64, MidScaleInt64, LongScaleInt64}
+
+#[cfg(feature = "bigint")]
+impl_cmp_prim! {MyriadScaleBigInt, MidScaleBigInt, LongScaleBigInt}
+
+#[cfg(feature = "bigint")]
+macro_rules! impl_cmp_big {
+    ($($int:ident),+ $(,)?) => {
+        $(impl PartialEq<BigInt> for $int {
+            fn eq(&self, other: &BigInt) -> bool {
+                self.partial_cmp(other) == Some(std::cmp::Ordering::Equal)
+            }
+        }
+
+        impl PartialOrd<BigInt> for $int {
+            /// Compares the number with a [`BigInt`] by their values.
+            fn partial_cmp(&self, other: &BigInt) -> Option<std::cmp::Ordering> {
+                let other_sign = Sign::from(other.sign().cmp(&num_bigint::Sign::NoSign));
+                let cmp_abs = || self.data.cmp(other.magnitude());
+                Some(match (self.sign, other_sign) {
+                    (Sign::Neg, Sign::Neg) => cmp_abs().reverse(),
+                    (sign, other_sign) if sign == other_sign => cmp_abs(),
+                    (sign, other_sign) => sign.cmp(&other_sign),
+                })
+            }
+        }
+
+        impl PartialEq<$int> for BigInt {
+            fn eq(&self, other: &$int) -> bool {
+                other == self
+            }
+        }
+
+        impl PartialOrd<$int> for BigInt {
+            fn partial_cmp(&self, other: &$int) -> Option<std::cmp::Ordering> {
+                other.partial_cmp(self).map(std::cmp::Ordering::reverse)
+            }
+        }
+
+        impl PartialEq<BigUint> for $int {
+            fn eq(&self, other: &BigUint) -> bool {
+                self.sign != Sign::Neg && &self.data == other
+            }
+        }
+
+        impl PartialEq<$int> for BigUint {
+            fn eq(&self, other: &$int) -> bool {
+                other == self
+            }
+        })+
+    };
+}
+
+#[cfg(feature = "bigint")]
+impl_cmp_big! {MyriadScaleBigInt, MidScaleBigInt, LongScaleBigInt}
+
+/// Writes a number with the default options and the formatter flags: `#` for uppercase, `-` for traditional Chinese, precision for keeping the most significant digits with 约, and width for padding.
+pub(crate) fn write_numeral<T>(num: &T, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+where
+    T: ChineseNumeral + std::str::FromStr<Err = Error>,
+{
+    let mut options = default_options();
+    if f.alternate() {
+        Arc::make_mut(&mut options).case = Case::Upper;
+    }
+    if f.sign_minus() {
+        Arc::make_mut(&mut options).variant = Variant::Traditional;
+    }
+
+    // the digits are only needed for the precision, saving the conversion of big integers otherwise
+    let digits = f.precision().map(|_| num.data().to_string());
+    let numeral = match (f.precision(), &digits) {
+        (Some(precision), Some(digits))
+            if precision.max(1) < digits.trim_end_matches('0').len() =>
+        {
+            let (kept, rest) = digits.split_at(precision.max(1));
+            let sign = if num.sign() == Sign::Neg { "-" } else { "" };
+            let approx: T = format!("{sign}{kept}{}", "0".repeat(rest.len()))
+                .parse()
+                .expect("Truncated number is in range");
+            let about = match options.variant {
+                Variant::Simplified => '约',
+                _ => '約',
+            };
+            format!("{about}{}", approx.to_chinese(&options))
+        }
+        _ => num.to_chinese(&options),
+    };
+    write_padded(&numeral, f)
+}
+
+/// Writes a numeral padded to the width of the formatter in characters, with its fill and alignment, on the left by default as numbers are.
+pub(crate) fn write_padded(numeral: &str, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let len = numeral.chars().count();
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+    let (pre, post) = match f.align() {
+        Some(std::fmt::Alignment::Left) => (0, padding),
+        Some(std::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..pre {
+        write!(f, "{fill}")?;
+    }
+    f.write_str(numeral)?;
+    for _ in 0..post {
+        write!(f, "{fill}")?;
+    }
+    Ok(())
+}
+
 macro_rules! impl_disp {
     ($($int:ident),+ $(,)?) => {
         $(impl std::fmt::Display for $int {
+            /// Formats the number with the [default options](crate::set_default_options), in lowercase by default, or uppercase with `{:#}`.
+            ///
+            /// The `-` flag selects traditional Chinese, the precision keeps the most significant digits and prefixes 约, and the width pads the numeral on the left by default, e.g. `{:零>6.2}` writes 零约一万二千 for 12345.
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                if f.alternate() {
-                    write!(f, "{}", self.to_uppercase_simp())
-                } else {
-                    write!(f, "{}", self.to_lowercase_simp())
-                }
+                write_numeral(self, f)
             }
         })+
     };
 }
 
 impl_disp! {ShortScaleInt, MyriadScaleInt, MidScaleInt, LongScaleInt}
+impl_disp! {MyriadScaleInt32, MidScaleInt32, LongScaleInt32, MyriadScaleInt64, MidScaleInt64, LongScaleInt64}
 
 #[cfg(feature = "bigint")]
 impl_disp! {MyriadScaleBigInt, MidScaleBigInt, LongScaleBigInt}
 
+/// Chooses the case and variant of digits read one by one, from the default options and the formatter flags as [`write_numeral`] does.
+fn digits_style(f: &std::fmt::Formatter<'_>) -> (Case, Variant) {
+    let options = default_options();
+    let case = if f.alternate() {
+        Case::Upper
+    } else {
+        options.case
+    };
+    let variant = if f.sign_minus() {
+        Variant::Traditional
+    } else {
+        options.variant
+    };
+    (case, variant)
+}
+
+/// Writes the sign and digits of a number, read one by one in the style of [`digits_style`].
+fn write_digits(f: &mut std::fmt::Formatter<'_>, sign: Sign, digits: &str) -> std::fmt::Result {
+    let (case, variant) = digits_style(f);
+    if sign == Sign::Neg {
+        write!(f, "{}", NumChar::Neg.to_lowercase(variant))?;
+    }
+    let digits = format_digits(digits, case, variant).expect("Digits are valid");
+    f.write_str(&digits)
+}
+
+macro_rules! impl_radix_fmt {
+    ($($int:ident),+ $(,)?) => {
+        $(impl $int {
+            /// Reads the digits of the number in a radix one by one, for teaching other numeral systems.
+            ///
+            /// Digits above nine are read as numbers and separated by 、, e.g. 一、十五 for 0x1F, or 壹、壹拾伍 in uppercase. Panics if the radix is not in the range from 2 to 36.
+            pub fn to_digits_radix(&self, radix: u32, case: Case, variant: Variant) -> String {
+                format_radix(self.sign(), self.data(), radix, case, variant)
+            }
+        }
+
+        impl std::fmt::Binary for $int {
+            /// Reads the binary digits one by one, e.g. 负一零一一 for -11, or 負一零一一 with `{:-b}`.
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write_digits(f, self.sign, &format!("{:b}", self.data))
+            }
+        }
+
+        impl std::fmt::Octal for $int {
+            /// Reads the octal digits one by one, e.g. 一三 for 11.
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write_digits(f, self.sign, &format!("{:o}", self.data))
+            }
+        }
+
+        impl std::fmt::LowerExp for $int {
+            /// Reads the number in scientific notation, e.g. 一点二三乘十的四次方 for 12300.
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let (case, variant) = digits_style(f);
+                if self.sign == Sign::Neg {
+                    write!(f, "{}", NumChar::Neg.to_lowercase(variant))?;
+                }
+                let digits = self.data.to_string();
+                f.write_str(&format_scientific(&digits, case, variant))
+            }
+        })+
+    };
+}
+
+impl_radix_fmt! {ShortScaleInt, MyriadScaleInt, MidScaleInt, LongScaleInt}
+impl_radix_fmt! {MyriadScaleInt32, MidScaleInt32, LongScaleInt32, MyriadScaleInt64, MidScaleInt64, LongScaleInt64}
+
+#[cfg(feature = "bigint")]
+impl_radix_fmt! {MyriadScaleBigInt, MidScaleBigInt, LongScaleBigInt}
+
 macro_rules! impl_try_from_uint {
     ($($u:ty),+ $(,)?) => {
         $(impl TryFrom<$u> for ShortScaleInt {
@@ -62,7 +366,7 @@ macro_rules! impl_try_from_uint {
                         data: value as u64,
                     })
                 } else {
-                    Err(Error::ShortScaleOutOfRange(value as u128))
+                    Err(Error::out_of_range(Scale::Short, value))
                 }
             }
         })+
@@ -79,16 +383,11 @@ macro_rules! impl_try_from_int {
             /// Performs the conversion. Returns [`Error`] if the absolute value is out of range.
             fn try_from(value: $i) -> Result<Self, Self::Error> {
                 if value < -(Self::MAX_ABS as $i) || value > Self::MAX_ABS as $i {
-                    let abs = if value as i128 == i128::MIN {
-                        (i128::MAX as u128) + 1
-                    } else {
-                        value.abs() as u128
-                    };
-                    Err(Error::ShortScaleOutOfRange(abs))
+                    Err(Error::out_of_range(Scale::Short, value.unsigned_abs()))
                 } else if value.is_negative() {
                     Ok(Self {
                         sign: Sign::Neg,
-                        data: value.abs() as u64,
+                        data: value.unsigned_abs() as u64,
                     })
                 } else if value.is_positive() {
                     Ok(Self {
@@ -112,14 +411,9 @@ macro_rules! impl_from_int {
                 if value == 0 {
                     Self::default()
                 } else if value < 0 {
-                    let abs = if value == <$pre>::MIN {
-                        (<$pre>::MAX as $data) + 1
-                    } else {
-                        value.abs() as $data
-                    };
                     Self {
                         sign: Sign::Neg,
-                        data: abs,
+                        data: value.unsigned_abs() as $data,
                     }
                 } else {
                     Self {
@@ -136,6 +430,244 @@ impl_from_int! {ShortScaleInt, u64, i8, i16, i32}
 impl_from_int! {MyriadScaleInt, u128, i8, i16, i32, i64, i128, isize}
 impl_from_int! {MidScaleInt, u128, i8, i16, i32, i64, i128, isize}
 impl_from_int! {LongScaleInt, u128, i8, i16, i32, i64, i128, isize}
+impl_from_int! {MyriadScaleInt32, u32, i8, i16, i32}
+impl_from_int! {MyriadScaleInt64, u64, i8, i16, i32, i64}
+impl_from_int! {MidScaleInt32, u32, i8, i16, i32}
+impl_from_int! {MidScaleInt64, u64, i8, i16, i32, i64}
+impl_from_int! {LongScaleInt32, u32, i8, i16, i32}
+impl_from_int! {LongScaleInt64, u64, i8, i16, i32, i64}
+
+#[cfg(feature = "bigint")]
+macro_rules! impl_scale_int {
+    ($($num:ident, $abs:ty, $scale:ident, $in_range:expr),+ $(,)?) => {
+        $(impl ScaleInt for $num {
+            type Abs = $abs;
+
+            fn try_new(sign: Sign, abs: $abs) -> Result<Self, Error> {
+                if !$in_range(&abs) {
+                    return Err(Error::out_of_range(Scale::$scale, abs));
+                }
+                let sign = match sign {
+                    _ if abs == <$abs>::default() => Sign::Nil,
+                    Sign::Nil => Sign::Pos,
+                    sign => sign,
+                };
+                Ok(Self { sign, data: abs })
+            }
+
+            fn into_parts(self) -> (Sign, $abs) {
+                (self.sign, self.data)
+            }
+        })+
+    };
+}
+
+#[cfg(feature = "bigint")]
+impl_scale_int! {
+    MyriadScaleBigInt, BigUint, Myriad, |abs: &BigUint| abs <= &BigUint::from_slice(MyriadScaleBigInt::MAX_ABS_ARR),
+    MidScaleBigInt, BigUint, Mid, |abs: &BigUint| abs <= &BigUint::from_slice(MidScaleBigInt::MAX_ABS_ARR),
+    LongScaleBigInt, BigUint, Long, |abs: &BigUint| abs <= &BigUint::from_slice(LongScaleBigInt::MAX_ABS_ARR),
+}
+
+macro_rules! impl_try_from_str {
+    ($($num:ident, $abs:ty, $scale:ident),+ $(,)?) => {
+        $(impl TryFrom<&str> for $num {
+            type Error = Error;
+
+            /// Parses a Chinese numeral in the scale of the struct, where runs of Arabic digits may replace the multipliers of units, e.g. 3万5千 or 12亿. Returns [`Error`] if the string cannot be parsed or the number is out of range.
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                let (neg, abs) = parse_numeral(s, Scale::$scale)?;
+                let sign = if neg { Sign::Neg } else { Sign::Pos };
+                let value = <$abs>::try_from(abs.clone())
+                    .map_err(|_| Error::out_of_range(Scale::$scale, abs))?;
+                Self::try_new(sign, value)
+            }
+        })+
+    };
+}
+
+impl_try_from_str! {ShortScaleInt, u64, Short, MyriadScaleInt, u128, Myriad, MidScaleInt, u128, Mid, LongScaleInt, u128, Long}
+impl_try_from_str! {
+    MyriadScaleInt32, u32, Myriad, MyriadScaleInt64, u64, Myriad,
+    MidScaleInt32, u32, Mid, MidScaleInt64, u64, Mid,
+    LongScaleInt32, u32, Long, LongScaleInt64, u64, Long,
+}
+
+#[cfg(feature = "bigint")]
+impl_try_from_str! {MyriadScaleBigInt, BigUint, Myriad, MidScaleBigInt, BigUint, Mid, LongScaleBigInt, BigUint, Long}
+
+macro_rules! impl_max_min {
+    ($($num:ident, $name:literal),+ $(,)?) => {
+        $(impl $num {
+            #[doc = concat!("The maximum integer can be expressed in ", $name, " with a `u128` absolute value.")]
+            pub const MAX: Self = Self {
+                sign: Sign::Pos,
+                data: u128::MAX,
+            };
+
+            #[doc = concat!("The minimum integer can be expressed in ", $name, " with a `u128` absolute value.")]
+            pub const MIN: Self = Self {
+                sign: Sign::Neg,
+                data: u128::MAX,
+            };
+        })+
+    };
+}
+
+impl_max_min! {MyriadScaleInt, "myriad scale", MidScaleInt, "mid-scale", LongScaleInt, "long scale"}
+
+#[cfg(feature = "num-traits")]
+macro_rules! impl_bounded {
+    ($($num:ident, $min:expr, $max:expr),+ $(,)?) => {
+        $(impl num_traits::Bounded for $num {
+            fn min_value() -> Self {
+                $min
+            }
+
+            fn max_value() -> Self {
+                $max
+            }
+        })+
+    };
+}
+
+#[cfg(feature = "num-traits")]
+impl_bounded! {
+    ShortScaleInt, ShortScaleInt::MIN, ShortScaleInt::MAX,
+    MyriadScaleInt, MyriadScaleInt::MIN, MyriadScaleInt::MAX,
+    MidScaleInt, MidScaleInt::MIN, MidScaleInt::MAX,
+    LongScaleInt, LongScaleInt::MIN, LongScaleInt::MAX,
+    MyriadScaleInt32, MyriadScaleInt32::MIN, MyriadScaleInt32::MAX,
+    MyriadScaleInt64, MyriadScaleInt64::MIN, MyriadScaleInt64::MAX,
+    MidScaleInt32, MidScaleInt32::MIN, MidScaleInt32::MAX,
+    MidScaleInt64, MidScaleInt64::MIN, MidScaleInt64::MAX,
+    LongScaleInt32, LongScaleInt32::MIN, LongScaleInt32::MAX,
+    LongScaleInt64, LongScaleInt64::MIN, LongScaleInt64::MAX,
+}
+
+#[cfg(feature = "bigint")]
+impl_bounded! {
+    MyriadScaleBigInt, MyriadScaleBigInt::min_value(), MyriadScaleBigInt::max_value(),
+    MidScaleBigInt, MidScaleBigInt::min_value(), MidScaleBigInt::max_value(),
+    LongScaleBigInt, LongScaleBigInt::min_value(), LongScaleBigInt::max_value(),
+}
+
+macro_rules! impl_saturating {
+    ($($num:ident, $min:expr, $max:expr),+ $(,)?) => {
+        $(impl $num {
+            /// Converts a value, clamping values out of range to the minimum or maximum instead of failing.
+            pub fn saturating_from<T>(value: T) -> Self
+            where
+                T: PartialOrd + Default,
+                Self: TryFrom<T>,
+            {
+                let neg = value < T::default();
+                Self::try_from(value).unwrap_or_else(|_| if neg { $min } else { $max })
+            }
+        })+
+    };
+}
+
+impl_saturating! {
+    ShortScaleInt, ShortScaleInt::MIN, ShortScaleInt::MAX,
+    MyriadScaleInt, MyriadScaleInt::MIN, MyriadScaleInt::MAX,
+    MidScaleInt, MidScaleInt::MIN, MidScaleInt::MAX,
+    LongScaleInt, LongScaleInt::MIN, LongScaleInt::MAX,
+    MyriadScaleInt32, MyriadScaleInt32::MIN, MyriadScaleInt32::MAX,
+    MyriadScaleInt64, MyriadScaleInt64::MIN, MyriadScaleInt64::MAX,
+    MidScaleInt32, MidScaleInt32::MIN, MidScaleInt32::MAX,
+    MidScaleInt64, MidScaleInt64::MIN, MidScaleInt64::MAX,
+    LongScaleInt32, LongScaleInt32::MIN, LongScaleInt32::MAX,
+    LongScaleInt64, LongScaleInt64::MIN, LongScaleInt64::MAX,
+}
+
+#[cfg(feature = "bigint")]
+impl_saturating! {
+    MyriadScaleBigInt, MyriadScaleBigInt::min_value(), MyriadScaleBigInt::max_value(),
+    MidScaleBigInt, MidScaleBigInt::min_value(), MidScaleBigInt::max_value(),
+    LongScaleBigInt, LongScaleBigInt::min_value(), LongScaleBigInt::max_value(),
+}
+
+impl ShortScaleInt {
+    /// Converts an `i128` in a `const` context, wrapping the absolute value around modulo 10<sup>15</sup> and keeping the sign.
+    pub const fn wrapping_from_i128(value: i128) -> Self {
+        let abs = Self::wrapping_from_u128(value.unsigned_abs());
+        if value < 0 && abs.data > 0 {
+            Self {
+                sign: Sign::Neg,
+                data: abs.data,
+            }
+        } else {
+            abs
+        }
+    }
+
+    /// Converts a `u128` in a `const` context, wrapping the value around modulo 10<sup>15</sup>.
+    pub const fn wrapping_from_u128(value: u128) -> Self {
+        let data = (value % (Self::MAX_ABS as u128 + 1)) as u64;
+        Self {
+            sign: if data == 0 { Sign::Nil } else { Sign::Pos },
+            data,
+        }
+    }
+}
+
+macro_rules! impl_const_from {
+    ($num:ident, $data:ty, $from_int:ident, $int:ty, $from_uint:ident, $uint:ty) => {
+        impl $num {
+            #[doc = concat!("Converts an `", stringify!($int), "` in a `const` context.")]
+            pub const fn $from_int(value: $int) -> Self {
+                if value < 0 {
+                    Self {
+                        sign: Sign::Neg,
+                        data: value.unsigned_abs() as $data,
+                    }
+                } else {
+                    Self::$from_uint(value as $uint)
+                }
+            }
+
+            #[doc = concat!("Converts a `", stringify!($uint), "` in a `const` context.")]
+            pub const fn $from_uint(value: $uint) -> Self {
+                Self {
+                    sign: if value == 0 { Sign::Nil } else { Sign::Pos },
+                    data: value as $data,
+                }
+            }
+        }
+    };
+}
+
+impl_const_from! {ShortScaleInt, u64, from_i32, i32, from_u32, u32}
+impl_const_from! {MyriadScaleInt, u128, from_i128, i128, from_u128, u128}
+impl_const_from! {MidScaleInt, u128, from_i128, i128, from_u128, u128}
+impl_const_from! {LongScaleInt, u128, from_i128, i128, from_u128, u128}
+
+macro_rules! impl_array_string {
+    ($($num:ident, $scale:ident),+ $(,)?) => {
+        $(impl $num {
+            /// Converts the number to an inline string without heap allocation, e.g. for embedded targets or hot loops.
+            ///
+            /// The output is the same as [`to_lowercase`](ChineseNumeral::to_lowercase) or [`to_uppercase`](ChineseNumeral::to_uppercase).
+            pub const fn to_array_string(&self, case: Case, variant: Variant) -> ConstStr {
+                format_signed(
+                    matches!(self.sign, Sign::Neg),
+                    self.data as u128,
+                    Scale::$scale,
+                    case,
+                    variant,
+                )
+            }
+        })+
+    };
+}
+
+impl_array_string! {ShortScaleInt, Short, MyriadScaleInt, Myriad, MidScaleInt, Mid, LongScaleInt, Long}
+impl_array_string! {
+    MyriadScaleInt32, Myriad, MyriadScaleInt64, Myriad,
+    MidScaleInt32, Mid, MidScaleInt64, Mid,
+    LongScaleInt32, Long, LongScaleInt64, Long,
+}
 
 macro_rules! impl_from_uint {
     ($num:ident, $data:ty, $($pre:ty),+ $(,)?) => {
@@ -158,10 +690,42 @@ impl_from_uint! {ShortScaleInt, u64, u8, u16, u32}
 impl_from_uint! {MyriadScaleInt, u128, u8, u16, u32, u64, u128, usize}
 impl_from_uint! {MidScaleInt, u128, u8, u16, u32, u64, u128, usize}
 impl_from_uint! {LongScaleInt, u128, u8, u16, u32, u64, u128, usize}
+impl_from_uint! {MyriadScaleInt32, u32, u8, u16, u32}
+impl_from_uint! {MyriadScaleInt64, u64, u8, u16, u32, u64}
+impl_from_uint! {MidScaleInt32, u32, u8, u16, u32}
+impl_from_uint! {MidScaleInt64, u64, u8, u16, u32, u64}
+impl_from_uint! {LongScaleInt32, u32, u8, u16, u32}
+impl_from_uint! {LongScaleInt64, u64, u8, u16, u32, u64}
+
+#[cfg(feature = "bigint")]
+macro_rules! impl_from_prim_big {
+    ($num:ident, $($pre:ty),+ $(,)?) => {
+        $(impl From<$pre> for $num {
+            fn from(value: $pre) -> Self {
+                let value = BigInt::from(value);
+                Self {
+                    sign: match value.sign() {
+                        num_bigint::Sign::Minus => Sign::Neg,
+                        num_bigint::Sign::NoSign => Sign::Nil,
+                        num_bigint::Sign::Plus => Sign::Pos,
+                    },
+                    data: value.into_parts().1,
+                }
+            }
+        })+
+    };
+}
+
+#[cfg(feature = "bigint")]
+impl_from_prim_big! {MyriadScaleBigInt, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize}
+#[cfg(feature = "bigint")]
+impl_from_prim_big! {MidScaleBigInt, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize}
+#[cfg(feature = "bigint")]
+impl_from_prim_big! {LongScaleBigInt, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize}
 
 #[cfg(feature = "bigint")]
 macro_rules! impl_try_from_big {
-    ($($int:ty, $err:ident),+ $(,)?) => {
+    ($($int:ty, $scale:ident),+ $(,)?) => {
         $(
             impl TryFrom<&BigUint> for $int {
                 type Error = Error;
@@ -177,7 +741,7 @@ macro_rules! impl_try_from_big {
                             data: value.to_owned(),
                         })
                     } else {
-                        Err(Error::$err(value.to_owned()))
+                        Err(Error::out_of_range(Scale::$scale, value))
                     }
                 }
             }
@@ -199,7 +763,7 @@ macro_rules! impl_try_from_big {
                     use num_traits::{Signed, Zero};
                     if value < &BigInt::from_slice(num_bigint::Sign::Minus, Self::MAX_ABS_ARR) || value > &BigInt::from_slice(num_bigint::Sign::Plus, Self::MAX_ABS_ARR) {
                         let abs = value.abs().to_biguint().unwrap();
-                        Err(Error::$err(abs))
+                        Err(Error::out_of_range(Scale::$scale, abs))
                     } else if value == &BigInt::zero() {
                         Ok(Self::default())
                     } else if value < &BigInt::zero() {
@@ -230,7 +794,210 @@ macro_rules! impl_try_from_big {
 
 #[cfg(feature = "bigint")]
 impl_try_from_big! {
-    MyriadScaleBigInt, MyriadScaleOutOfRange,
-    MidScaleBigInt, MidScaleOutOfRange,
-    LongScaleBigInt, LongScaleOutOfRange,
+    MyriadScaleBigInt, Myriad,
+    MidScaleBigInt, Mid,
+    LongScaleBigInt, Long,
+}
+
+#[cfg(feature = "bigint")]
+macro_rules! impl_new_big {
+    ($($int:ident),+ $(,)?) => {
+        $(impl $int {
+            /// Constructs a number from a sign and an absolute value, the same as [`ScaleInt::new`] without importing the trait.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the absolute value is out of range.
+            pub fn new(sign: Sign, abs: BigUint) -> Self {
+                <Self as ScaleInt>::new(sign, abs)
+            }
+
+            /// Constructs a non-positive number from its absolute value without going through [`BigInt`], or returns [`Error`] if the absolute value is out of range.
+            pub fn from_biguint_neg(abs: BigUint) -> Result<Self, Error> {
+                Self::try_new_negative(abs)
+            }
+
+            /// Constructs the number `mantissa` × 10<sup>`exponent`</sup>, or returns [`Error`] if it is out of range.
+            ///
+            /// The magnitude is checked before the power is computed, so that enormous exponents fail fast.
+            pub fn from_sci(mantissa: i128, exponent: u32) -> Result<Self, Error> {
+                let scale = <Self as crate::ChineseNumeralBase>::SCALE;
+                let out_of_range = || Error::out_of_range_sci(scale, mantissa.unsigned_abs(), exponent);
+                if mantissa == 0 {
+                    return Ok(Self::default());
+                }
+                let abs = mantissa.unsigned_abs();
+                let magnitude = abs.ilog10() as usize;
+                if magnitude.saturating_add(exponent as usize) > scale.max_magnitude() {
+                    return Err(out_of_range());
+                }
+                let abs = BigUint::from(abs) * BigUint::from(10_u32).pow(exponent);
+                let sign = match mantissa < 0 {
+                    true => Sign::Neg,
+                    false => Sign::Pos,
+                };
+                Self::try_new(sign, abs).map_err(|_| out_of_range())
+            }
+        })+
+    };
+}
+
+#[cfg(feature = "bigint")]
+impl_new_big! {MyriadScaleBigInt, MidScaleBigInt, LongScaleBigInt}
+
+#[cfg(all(feature = "bigint", feature = "serde"))]
+macro_rules! impl_serde_big {
+    ($($int:ident),+ $(,)?) => {
+        $(impl serde::Serialize for $int {
+            /// Serializes the number as a struct of its value and numeral in human-readable formats, or as the decimal string in compact formats.
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                crate::serialize_numeral(self, serializer)
+            }
+        })+
+    };
+}
+
+#[cfg(all(feature = "bigint", feature = "serde"))]
+impl_serde_big! {MyriadScaleBigInt, MidScaleBigInt, LongScaleBigInt}
+
+#[cfg(all(feature = "bigint", feature = "defmt"))]
+macro_rules! impl_defmt_big {
+    ($($int:ident),+ $(,)?) => {
+        $(impl defmt::Format for $int {
+            /// Logs the number in lowercase simplified Chinese with `defmt`.
+            fn format(&self, f: defmt::Formatter<'_>) {
+                crate::write_defmt(self, f);
+            }
+        })+
+    };
+}
+
+#[cfg(all(feature = "bigint", feature = "defmt"))]
+impl_defmt_big! {MyriadScaleBigInt, MidScaleBigInt, LongScaleBigInt}
+
+#[cfg(all(feature = "bigint", feature = "rand"))]
+macro_rules! impl_rand_big {
+    ($($int:ident),+ $(,)?) => {
+        $(impl rand::distributions::Distribution<$int> for rand::distributions::Standard {
+            /// Samples numbers uniformly from the range of the scale.
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $int {
+                use num_bigint::RandBigInt;
+                use num_traits::Zero;
+
+                let bound = BigUint::from_slice($int::MAX_ABS_ARR) + 1_u32;
+                loop {
+                    let neg = rng.gen::<bool>();
+                    let abs = rng.gen_biguint_below(&bound);
+                    // rejects negative zero, so that zero is as likely as any other number
+                    if !(neg && abs.is_zero()) {
+                        return $int::new(if neg { Sign::Neg } else { Sign::Pos }, abs);
+                    }
+                }
+            }
+        })+
+    };
+}
+
+#[cfg(all(feature = "bigint", feature = "rand"))]
+impl_rand_big! {MyriadScaleBigInt, MidScaleBigInt, LongScaleBigInt}
+
+#[cfg(all(feature = "bigint", feature = "arbitrary"))]
+macro_rules! impl_arbitrary_big {
+    ($($int:ident),+ $(,)?) => {
+        $(impl<'a> arbitrary::Arbitrary<'a> for $int {
+            /// Generates numbers with random signs and absolute values in range of the scale, taking the remainder of larger absolute values.
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                let sign = match u.arbitrary()? {
+                    true => Sign::Neg,
+                    false => Sign::Pos,
+                };
+                let max = BigUint::from_slice(Self::MAX_ABS_ARR);
+                let len = u.arbitrary_len::<u8>()?.min(max.to_bytes_le().len());
+                let abs = BigUint::from_bytes_le(u.bytes(len)?) % (max + 1_u32);
+                Ok(Self::new(sign, abs))
+            }
+        })+
+    };
 }
+
+#[cfg(all(feature = "bigint", feature = "arbitrary"))]
+impl_arbitrary_big! {MyriadScaleBigInt, MidScaleBigInt, LongScaleBigInt}
+
+impl std::str::FromStr for ShortScaleInt {
+    type Err = Error;
+
+    /// Parses a decimal string, with an optional sign and `_` or `,` as separators.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (neg, digits) = split_decimal(s)?;
+        let abs: u128 = digits.parse().map_err(|_| ParseError::Overflow)?;
+        let mut num = Self::try_from(abs)?;
+        if neg && num.sign == Sign::Pos {
+            num.sign = Sign::Neg;
+        }
+        Ok(num)
+    }
+}
+
+macro_rules! impl_from_str {
+    ($($int:ident),+ $(,)?) => {
+        $(impl std::str::FromStr for $int {
+            type Err = Error;
+
+            /// Parses a decimal string, with an optional sign and `_` or `,` as separators.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let (neg, digits) = split_decimal(s)?;
+                let abs: u128 = digits.parse().map_err(|_| ParseError::Overflow)?;
+                if neg {
+                    Ok(Self::new_non_pos(abs))
+                } else {
+                    Ok(Self::from(abs))
+                }
+            }
+        })+
+    };
+}
+
+impl_from_str! {MyriadScaleInt, MidScaleInt, LongScaleInt}
+
+macro_rules! impl_from_str_compact {
+    ($($int:ident),+ $(,)?) => {
+        $(impl std::str::FromStr for $int {
+            type Err = Error;
+
+            /// Parses a decimal string, with an optional sign and `_` or `,` as separators.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let (neg, digits) = split_decimal(s)?;
+                let abs: u128 = digits.parse().map_err(|_| ParseError::Overflow)?;
+                let value = <Self as ScaleInt>::Abs::try_from(abs)
+                    .map_err(|_| Error::out_of_range(<Self as ChineseNumeralBase>::SCALE, abs))?;
+                let sign = if neg { Sign::Neg } else { Sign::Pos };
+                Self::try_new(sign, value)
+            }
+        })+
+    };
+}
+
+impl_from_str_compact! {MyriadScaleInt32, MyriadScaleInt64, MidScaleInt32, MidScaleInt64, LongScaleInt32, LongScaleInt64}
+
+#[cfg(feature = "bigint")]
+macro_rules! impl_from_str_big {
+    ($($int:ident),+ $(,)?) => {
+        $(impl std::str::FromStr for $int {
+            type Err = Error;
+
+            /// Parses a decimal string, with an optional sign and `_` or `,` as separators.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let (neg, digits) = split_decimal(s)?;
+                let abs = BigUint::parse_bytes(digits.as_bytes(), 10).ok_or(ParseError::Empty)?;
+                let mut num = Self::try_from(abs)?;
+                if neg && num.sign == Sign::Pos {
+                    num.sign = Sign::Neg;
+                }
+                Ok(num)
+            }
+        })+
+    };
+}
+
+#[cfg(feature = "bigint")]
+impl_from_str_big! {MyriadScaleBigInt, MidScaleBigInt, LongScaleBigInt}
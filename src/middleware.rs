@@ -0,0 +1,46 @@
+use crate::NumChar;
+
+/// Tokens of a Chinese numeral, processed by [`Middleware`]s before rendering.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Token {
+    /// A character, rendered according to the case and variant of [`Options`](crate::Options).
+    Char(NumChar),
+    /// Literal text, rendered as is.
+    Text(String),
+}
+
+/// Transformations applied to the tokens before rendering, see [`Options::middlewares`](crate::Options::middlewares).
+///
+/// It is implemented for closures taking `&mut Vec<Token>`.
+///
+/// ```
+/// use chinese_numerals::{ChineseNumeral, MyriadScaleInt, NumChar, Options, Token};
+/// use std::sync::Arc;
+///
+/// let yao = |tokens: &mut Vec<Token>| {
+///     for token in tokens.iter_mut() {
+///         if *token == Token::Char(NumChar::One) {
+///             *token = Token::Text("幺".to_owned());
+///         }
+///     }
+/// };
+/// let mut options = Options::default();
+/// options.middlewares = vec![Arc::new(yao)];
+/// assert_eq!("幺百幺十", MyriadScaleInt::from(110).to_chinese(&options));
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Transforms the tokens in reading order.
+    fn process(&self, tokens: &mut Vec<Token>);
+}
+
+impl<F: Fn(&mut Vec<Token>) + Send + Sync> Middleware for F {
+    fn process(&self, tokens: &mut Vec<Token>) {
+        self(tokens)
+    }
+}
+
+impl std::fmt::Debug for dyn Middleware {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Middleware")
+    }
+}
@@ -1,21 +1,26 @@
-use crate::{characters::*, ChineseNumeralBase, MyriadScaleInt, Sign, Signed};
+use crate::{characters::*, ChineseInt, Myriad, Scale, ScaleBase, ScaleKind, Sign};
+
+/// Marker type of mid-scale (中数) for [`ChineseInt`].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub struct Mid;
+
+impl ScaleKind for Mid {}
 
 /// Mid-scale integers (中数).
 ///
 /// 「中数者，万万变之。若言万万曰亿，万万亿曰兆，万万兆曰京也。」
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, Default)]
-pub struct MidScaleInt {
-    pub(super) sign: Sign,
-    pub(super) data: u128,
-}
+pub type MidScaleInt = ChineseInt<Mid>;
 
 impl MidScaleInt {
     /// Generates a new non-positive mid-scale integer from given absolute value.
     ///
     /// There is no way to generate Chinese numerals by `From` trait from negative primitive numbers less than [`i128::MIN`]. This associated function provides a way to generate them from the given absolute value less than or equal to [`u128::MAX`]. This crate also provides struct [`MidScaleBigInt`] for integers with absolute value larger than `u128::MAX`.
-    pub fn new_non_pos(abs: u128) -> Self {
+    pub const fn new_non_pos(abs: u128) -> Self {
         if abs == 0 {
-            Self::default()
+            Self {
+                sign: Sign::Nil,
+                data: 0,
+            }
         } else {
             Self {
                 sign: Sign::Neg,
@@ -25,59 +30,55 @@ impl MidScaleInt {
     }
 }
 
-impl ChineseNumeralBase for MidScaleInt {
-    fn to_chars(&self) -> Vec<NumChar> {
-        let mut chars = Vec::new();
-        let mut num = *self.data();
+impl ScaleBase for Mid {
+    type Abs = u128;
+
+    const SCALE: Scale = Scale::Mid;
+    const MAX_ABS: u128 = u128::MAX;
+
+    fn push_abs(mut num: u128, chars: &mut Vec<NumChar>) {
+        let start = chars.len();
         let mut prev_rem = 1000_0000;
 
         // u128 uses up to NUM_CHARS[17] = Gai (垓) for mid-scale numerals
+        #[allow(clippy::needless_range_loop)]
         for exp in 13..=17 {
+            if num == 0 {
+                break;
+            }
             let rem = num % 1_0000_0000;
             num /= 1_0000_0000;
 
             if rem > 0 {
-                if !chars.is_empty() && prev_rem < 1000_0000 {
+                if chars.len() > start && prev_rem < 1000_0000 {
                     chars.push(NUM_CHARS[0]);
                 }
                 if exp > 13 {
                     chars.push(NUM_CHARS[exp]);
                 }
-                let myriad = MyriadScaleInt::from(rem);
-                let mut node = myriad.to_chars();
-                chars.append(&mut node);
+                Myriad::push_abs(rem, chars);
             }
             prev_rem = rem;
         }
-        chars
-    }
-
-    fn to_chars_trimmed(&self) -> Vec<NumChar> {
-        let mut chars = self.to_chars();
-        let mut data = *self.data();
-        while data >= 1_0000 {
-            data /= 1_0000;
-        }
-        if data >= 10 && data <= 19 {
-            let one = chars.pop();
-            debug_assert_eq!(one, Some(NumChar::One));
-        }
-        chars
     }
 }
 
+#[cfg(feature = "bigint")]
+use crate::{ChineseNumeralBase, Signed};
 #[cfg(feature = "bigint")]
 use num_bigint::BigUint;
 #[cfg(feature = "bigint")]
 use num_integer::Integer;
 #[cfg(feature = "bigint")]
 use num_traits::{ToPrimitive, Zero};
+#[cfg(feature = "bigint")]
+use std::borrow::Cow;
 
 /// Mid-scale big integers (中数).
 ///
 /// Use it by turning on feature "bigint". It uses [`BigUint`](num_bigint::BigUint) to store the absolute value. Therefore, all integers that can be expressed in mid-scale are included.
 #[cfg(feature = "bigint")]
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug, Default)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug, Default)]
 pub struct MidScaleBigInt {
     pub(super) sign: Sign,
     pub(super) data: BigUint,
@@ -109,47 +110,34 @@ impl MidScaleBigInt {
 
 #[cfg(feature = "bigint")]
 impl ChineseNumeralBase for MidScaleBigInt {
-    fn to_chars(&self) -> Vec<NumChar> {
-        let mut chars = Vec::new();
-        let mut num = self.data().to_owned();
-        let mut prev_rem = BigUint::new(vec![1000_0000]);
-        let lim = BigUint::new(vec![1000_0000]);
+    const SCALE: Scale = Scale::Mid;
+
+    fn push_chars(&self, chars: &mut Vec<NumChar>) {
+        let start = chars.len();
+        // borrows the absolute value until the first division, without cloning it
+        let mut num = Cow::Borrowed(self.data());
+        let mut prev_rem = 1000_0000;
         let div = BigUint::new(vec![1_0000_0000]);
 
+        #[allow(clippy::needless_range_loop)]
         for exp in 13..=23 {
-            let (_, rem) = num.div_rem(&div);
-            num /= &div;
+            if num.is_zero() {
+                break;
+            }
+            let (quot, rem) = num.div_rem(&div);
+            num = Cow::Owned(quot);
+            let rem = rem.to_u32().unwrap();
 
-            if rem > BigUint::zero() {
-                if !chars.is_empty() && prev_rem < lim {
+            if rem > 0 {
+                if chars.len() > start && prev_rem < 1000_0000 {
                     chars.push(NUM_CHARS[0]);
                 }
                 if exp > 13 {
                     chars.push(NUM_CHARS[exp]);
                 }
-                let rem = rem.to_u32().unwrap();
-                let myriad = MyriadScaleInt::from(rem);
-                let mut node = myriad.to_chars();
-                chars.append(&mut node);
+                Myriad::push_abs(rem.into(), chars);
             }
             prev_rem = rem;
         }
-        chars
-    }
-
-    fn to_chars_trimmed(&self) -> Vec<NumChar> {
-        let mut chars = self.to_chars();
-        let mut data = self.data().to_owned();
-        let div = BigUint::new(vec![1_0000]);
-        let ten = BigUint::new(vec![10]);
-        let nineteen = BigUint::new(vec![19]);
-        while data >= div {
-            data /= &div;
-        }
-        if data >= ten && data <= nineteen {
-            let one = chars.pop();
-            debug_assert_eq!(one, Some(NumChar::One));
-        }
-        chars
     }
 }
@@ -0,0 +1,96 @@
+use crate::{Case, ChineseNumeral, Sign, Variant};
+
+/// Brackets enclosing the Chinese numeral in mixed formatting.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Bracket {
+    /// Full-width parentheses, e.g. （一万）.
+    FullWidth,
+    /// Half-width parentheses, e.g. (一万).
+    HalfWidth,
+    /// Lenticular brackets, e.g. 【一万】.
+    Lenticular,
+}
+
+impl Bracket {
+    fn pair(self) -> (char, char) {
+        match self {
+            Bracket::FullWidth => ('（', '）'),
+            Bracket::HalfWidth => ('(', ')'),
+            Bracket::Lenticular => ('【', '】'),
+        }
+    }
+}
+
+/// Options of mixed formatting, see [`format_mixed`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct MixedStyle<'a> {
+    /// Separator inserted between digit groups. Defaults to `","`.
+    pub separator: &'a str,
+    /// Number of digits in a group. Defaults to 4. Digits are not grouped if it is 0.
+    pub group_size: usize,
+    /// Brackets enclosing the Chinese numeral. Defaults to [`Bracket::FullWidth`].
+    pub bracket: Bracket,
+    /// Case of the Chinese numeral. Defaults to [`Case::Lower`].
+    pub case: Case,
+    /// Variant of the Chinese numeral. Defaults to [`Variant::Simplified`].
+    pub variant: Variant,
+}
+
+impl Default for MixedStyle<'_> {
+    fn default() -> Self {
+        Self {
+            separator: ",",
+            group_size: 4,
+            bracket: Bracket::FullWidth,
+            case: Case::Lower,
+            variant: Variant::Simplified,
+        }
+    }
+}
+
+/// Formats a number as Arabic digits followed by the bracketed Chinese numeral, e.g. `1,0203,0405（一亿零二百零三万零四百零五）`.
+///
+/// Both parts are generated from the same value, which is useful for exporting to spreadsheets.
+///
+/// ```
+/// use chinese_numerals::{format_mixed, Bracket, MidScaleInt, MixedStyle};
+///
+/// let num = MidScaleInt::from(1_0203_0405);
+/// assert_eq!(
+///     "1,0203,0405（一亿零二百零三万零四百零五）",
+///     format_mixed(&num, &MixedStyle::default())
+/// );
+///
+/// let style = MixedStyle {
+///     separator: "_",
+///     group_size: 3,
+///     bracket: Bracket::HalfWidth,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     "102_030_405(一亿零二百零三万零四百零五)",
+///     format_mixed(&num, &style)
+/// );
+/// ```
+pub fn format_mixed<T: ChineseNumeral>(num: &T, style: &MixedStyle) -> String {
+    let digits = num.data().to_string();
+    let mut mixed = String::new();
+    if num.sign() == Sign::Neg {
+        mixed.push('-');
+    }
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && style.group_size > 0 && (digits.len() - i).is_multiple_of(style.group_size) {
+            mixed.push_str(style.separator);
+        }
+        mixed.push(digit);
+    }
+
+    let (open, close) = style.bracket.pair();
+    mixed.push(open);
+    match style.case {
+        Case::Lower => mixed.push_str(&num.to_lowercase(style.variant)),
+        Case::Upper => mixed.push_str(&num.to_uppercase(style.variant)),
+    }
+    mixed.push(close);
+    mixed
+}
@@ -0,0 +1,124 @@
+use crate::{characters::*, ChineseNumeral, MyriadScaleInt, ParseError, Variant};
+
+/// Conventions of writing amounts of money in uppercase, see [`format_money_with`].
+///
+/// The defaults follow the most common writing, which is accepted by banks in mainland China. Whatever the style is, 零 is never repeated, and 零 is always written when 角 is zero but 分 is not.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct FinancialStyle {
+    /// Whether 零 is written before 角 when the amount in yuan ends with zero, e.g. 壹拾元零伍角 instead of 壹拾元伍角. Defaults to `false`.
+    pub zero_before_jiao: bool,
+    /// Whether 整 is written after 角 when there are no cents, e.g. 壹元伍角整 instead of 壹元伍角. Defaults to `false`.
+    pub zheng_after_jiao: bool,
+    /// Character ending amounts without cents, usually 整 or 正. Defaults to 整.
+    pub zheng: char,
+}
+
+impl Default for FinancialStyle {
+    fn default() -> Self {
+        Self {
+            zero_before_jiao: false,
+            zheng_after_jiao: false,
+            zheng: '整',
+        }
+    }
+}
+
+/// Formats an amount of money in cents (分) in uppercase, e.g. 壹佰贰拾叁元肆角伍分.
+///
+/// Amounts without cents end with 整.
+///
+/// ```
+/// use chinese_numerals::{format_money, Variant};
+///
+/// assert_eq!("壹佰贰拾叁元肆角伍分", format_money(123_45, Variant::Simplified));
+/// assert_eq!("壹拾元整", format_money(10_00, Variant::Simplified));
+/// assert_eq!("叄元零伍分", format_money(3_05, Variant::Traditional));
+/// ```
+pub fn format_money(cents: i128, variant: Variant) -> String {
+    format_money_with(cents, variant, &FinancialStyle::default())
+}
+
+/// Formats an amount of money in cents (分) in uppercase with given conventions of writing 零 and 整.
+///
+/// ```
+/// use chinese_numerals::{format_money_with, FinancialStyle, Variant};
+///
+/// let style = FinancialStyle {
+///     zero_before_jiao: true,
+///     zheng_after_jiao: true,
+///     zheng: '正',
+/// };
+/// assert_eq!("壹仟陆佰捌拾元零叁角贰分", format_money_with(168_032, Variant::Simplified, &style));
+/// assert_eq!("壹元伍角正", format_money_with(1_50, Variant::Simplified, &style));
+/// ```
+pub fn format_money_with(cents: i128, variant: Variant, style: &FinancialStyle) -> String {
+    let mut money = String::new();
+    if cents < 0 {
+        money.push_str(NumChar::Neg.to_uppercase(variant));
+    }
+    let cents = cents.unsigned_abs();
+    let (yuan, jiao, fen) = (
+        cents / 100,
+        (cents / 10 % 10) as usize,
+        (cents % 10) as usize,
+    );
+
+    if yuan > 0 || cents == 0 {
+        money += &MyriadScaleInt::from(yuan).to_uppercase(variant);
+        money.push('元');
+    }
+    if cents.is_multiple_of(100) {
+        money.push(style.zheng);
+        return money;
+    }
+    if jiao > 0 {
+        if style.zero_before_jiao && yuan > 0 && yuan.is_multiple_of(10) {
+            money.push_str(NumChar::Zero.to_uppercase(variant));
+        }
+        money.push_str(NUM_CHARS[jiao].to_uppercase(variant));
+        money.push('角');
+    } else if yuan > 0 {
+        money.push_str(NumChar::Zero.to_uppercase(variant));
+    }
+    if fen > 0 {
+        money.push_str(NUM_CHARS[fen].to_uppercase(variant));
+        money.push('分');
+    } else if style.zheng_after_jiao {
+        money.push(style.zheng);
+    }
+    money
+}
+
+/// Parses an amount of money in yuan into cents, e.g. `"123.45"` into `12345`.
+///
+/// The amount has an optional sign and at most two decimal places. Returns [`ParseError`] if the amount is empty, contains other characters, or overflows.
+///
+/// ```
+/// use chinese_numerals::parse_cents;
+///
+/// assert_eq!(Ok(123_45), parse_cents("123.45"));
+/// assert_eq!(Ok(-5_00), parse_cents("-5"));
+/// assert!(parse_cents("1.234").is_err());
+/// ```
+pub fn parse_cents(input: &str) -> Result<i128, ParseError> {
+    let (neg, input) = match input.strip_prefix('-') {
+        Some(input) => (true, input),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+    let (yuan, jiao_fen) = input.split_once('.').unwrap_or((input, ""));
+    if yuan.is_empty() && jiao_fen.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    if let Some(ch) = jiao_fen.chars().nth(2) {
+        return Err(ParseError::InvalidChar(ch));
+    }
+    let mut cents: i128 = 0;
+    for ch in yuan.chars().chain(format!("{jiao_fen:0<2}").chars()) {
+        let digit = ch.to_digit(10).ok_or(ParseError::InvalidChar(ch))?;
+        cents = cents
+            .checked_mul(10)
+            .and_then(|cents| cents.checked_add(digit as i128))
+            .ok_or(ParseError::Overflow)?;
+    }
+    Ok(if neg { -cents } else { cents })
+}
@@ -1,21 +1,26 @@
-use crate::{characters::*, ChineseNumeralBase, ShortScaleInt, Sign, Signed};
+use crate::{characters::*, const_fmt::GROUPS, ChineseInt, Scale, ScaleBase, ScaleKind, Sign};
+
+/// Marker type of myriad scale (万进) for [`ChineseInt`].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub struct Myriad;
+
+impl ScaleKind for Myriad {}
 
 /// Myriad scale integers (万进).
 ///
 /// 「以万进者，万万曰亿，万亿曰兆。」
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, Default)]
-pub struct MyriadScaleInt {
-    pub(super) sign: Sign,
-    pub(super) data: u128,
-}
+pub type MyriadScaleInt = ChineseInt<Myriad>;
 
 impl MyriadScaleInt {
     /// Generates a new non-positive myriad scale integer from given absolute value.
     ///
     /// There is no way to generate Chinese numerals by `From` trait from negative primitive numbers less than [`i128::MIN`]. This associated function provides a way to generate them from the given absolute value less than or equal to [`u128::MAX`]. This crate also provides struct [`MyriadScaleBigInt`] for integers with absolute value larger than `u128::MAX`.
-    pub fn new_non_pos(abs: u128) -> Self {
+    pub const fn new_non_pos(abs: u128) -> Self {
         if abs == 0 {
-            Self::default()
+            Self {
+                sign: Sign::Nil,
+                data: 0,
+            }
         } else {
             Self {
                 sign: Sign::Neg,
@@ -25,59 +30,75 @@ impl MyriadScaleInt {
     }
 }
 
-impl ChineseNumeralBase for MyriadScaleInt {
-    fn to_chars(&self) -> Vec<NumChar> {
-        let mut chars = Vec::new();
-        let mut num = *self.data();
+impl ScaleBase for Myriad {
+    type Abs = u128;
+
+    const SCALE: Scale = Scale::Myriad;
+    const MAX_ABS: u128 = u128::MAX;
+
+    fn push_abs(mut num: u128, chars: &mut Vec<NumChar>) {
+        if num < 1_0000_0000 {
+            return push_small(num as u32, chars);
+        }
+        let start = chars.len();
         let mut prev_rem = 1000;
 
         // u128 uses up to NUM_CHARS[21] = Jian (涧) for myriad scale numerals
+        #[allow(clippy::needless_range_loop)]
         for exp in 12..=21 {
+            if num == 0 {
+                break;
+            }
             let rem = (num % 1_0000) as u16;
             num /= 1_0000;
 
             if rem > 0 {
-                if !chars.is_empty() && prev_rem < 1000 {
+                if chars.len() > start && prev_rem < 1000 {
                     chars.push(NUM_CHARS[0]);
                 }
                 if exp > 12 {
                     chars.push(NUM_CHARS[exp]);
                 }
-                let short = ShortScaleInt::from(rem);
-                let mut node = short.to_chars();
-                chars.append(&mut node);
+                chars.extend_from_slice(GROUPS[rem as usize].chars());
             }
             prev_rem = rem;
         }
-        chars
     }
+}
 
-    fn to_chars_trimmed(&self) -> Vec<NumChar> {
-        let mut chars = self.to_chars();
-        let mut data = *self.data();
-        while data >= 1_0000 {
-            data /= 1_0000;
-        }
-        if data >= 10 && data <= 19 {
-            let one = chars.pop();
-            debug_assert_eq!(one, Some(NumChar::One));
+/// Pushes the characters of an absolute value below 1_0000_0000 in reversed order, with 32-bit arithmetic only.
+///
+/// It is the same in myriad scale, mid-scale, and long scale, and most numbers in practice, e.g. page counts and quantities, take this path.
+fn push_small(num: u32, chars: &mut Vec<NumChar>) {
+    let (high, low) = (num / 1_0000, num % 1_0000);
+    if low > 0 {
+        chars.extend_from_slice(GROUPS[low as usize].chars());
+    }
+    if high > 0 {
+        if low > 0 && low < 1000 {
+            chars.push(NumChar::Zero);
         }
-        chars
+        chars.push(NumChar::Wan);
+        chars.extend_from_slice(GROUPS[high as usize].chars());
     }
 }
 
+#[cfg(feature = "bigint")]
+use crate::{ChineseNumeralBase, Signed};
 #[cfg(feature = "bigint")]
 use num_bigint::BigUint;
 #[cfg(feature = "bigint")]
 use num_integer::Integer;
 #[cfg(feature = "bigint")]
 use num_traits::{ToPrimitive, Zero};
+#[cfg(feature = "bigint")]
+use std::borrow::Cow;
 
 /// Myriad scale big integers (万进).
 ///
 /// Use it by turning on feature "bigint". It uses [`BigUint`](num_bigint::BigUint) to store the absolute value. Therefore, all integers that can be expressed in myriad scale are included.
 #[cfg(feature = "bigint")]
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug, Default)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug, Default)]
 pub struct MyriadScaleBigInt {
     pub(super) sign: Sign,
     pub(super) data: BigUint,
@@ -107,47 +128,34 @@ impl MyriadScaleBigInt {
 
 #[cfg(feature = "bigint")]
 impl ChineseNumeralBase for MyriadScaleBigInt {
-    fn to_chars(&self) -> Vec<NumChar> {
-        let mut chars = Vec::new();
-        let mut num = self.data().to_owned();
-        let mut prev_rem = BigUint::new(vec![1000]);
-        let lim = BigUint::new(vec![1000]);
+    const SCALE: Scale = Scale::Myriad;
+
+    fn push_chars(&self, chars: &mut Vec<NumChar>) {
+        let start = chars.len();
+        // borrows the absolute value until the first division, without cloning it
+        let mut num = Cow::Borrowed(self.data());
+        let mut prev_rem = 1000;
         let div = BigUint::new(vec![1_0000]);
 
+        #[allow(clippy::needless_range_loop)]
         for exp in 12..=23 {
-            let (_, rem) = num.div_rem(&div);
-            num /= &div;
+            if num.is_zero() {
+                break;
+            }
+            let (quot, rem) = num.div_rem(&div);
+            num = Cow::Owned(quot);
+            let rem = rem.to_u16().unwrap();
 
-            if rem > BigUint::zero() {
-                if !chars.is_empty() && prev_rem < lim {
+            if rem > 0 {
+                if chars.len() > start && prev_rem < 1000 {
                     chars.push(NUM_CHARS[0]);
                 }
                 if exp > 12 {
                     chars.push(NUM_CHARS[exp]);
                 }
-                let rem = rem.to_u16().unwrap();
-                let short = ShortScaleInt::from(rem);
-                let mut node = short.to_chars();
-                chars.append(&mut node);
+                chars.extend_from_slice(GROUPS[rem as usize].chars());
             }
             prev_rem = rem;
         }
-        chars
-    }
-
-    fn to_chars_trimmed(&self) -> Vec<NumChar> {
-        let mut chars = self.to_chars();
-        let mut data = self.data().to_owned();
-        let div = BigUint::new(vec![1_0000]);
-        let ten = BigUint::new(vec![10]);
-        let nineteen = BigUint::new(vec![19]);
-        while data >= div {
-            data /= &div;
-        }
-        if data >= ten && data <= nineteen {
-            let one = chars.pop();
-            debug_assert_eq!(one, Some(NumChar::One));
-        }
-        chars
     }
 }
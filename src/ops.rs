@@ -0,0 +1,471 @@
+//! Arithmetic of the scale structs, computed on signs and absolute values.
+
+use crate::{LongScaleInt, MidScaleInt, MyriadScaleInt, ScaleInt, ShortScaleInt, Sign};
+
+#[cfg(feature = "num-traits")]
+use crate::{Error, ParseError};
+
+#[cfg(feature = "bigint")]
+use crate::{LongScaleBigInt, MidScaleBigInt, MyriadScaleBigInt};
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
+
+/// Absolute values of the scale structs.
+pub(crate) trait Magnitude: Clone + Ord + Default {
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+    /// Subtracts a value not greater than `self`.
+    fn sub(&self, rhs: &Self) -> Self;
+    fn div(&self, rhs: &Self) -> Self;
+    fn rem(&self, rhs: &Self) -> Self;
+    #[cfg(feature = "num-traits")]
+    fn from_u128(value: u128) -> Option<Self>;
+    #[cfg(feature = "num-traits")]
+    fn to_u128(&self) -> Option<u128>;
+}
+
+macro_rules! impl_magnitude {
+    ($($uint:ty),+ $(,)?) => {
+        $(impl Magnitude for $uint {
+            fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                <$uint>::checked_add(*self, *rhs)
+            }
+
+            fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                <$uint>::checked_mul(*self, *rhs)
+            }
+
+            fn sub(&self, rhs: &Self) -> Self {
+                self - rhs
+            }
+
+            fn div(&self, rhs: &Self) -> Self {
+                self / rhs
+            }
+
+            fn rem(&self, rhs: &Self) -> Self {
+                self % rhs
+            }
+
+            #[cfg(feature = "num-traits")]
+            fn from_u128(value: u128) -> Option<Self> {
+                value.try_into().ok()
+            }
+
+            #[cfg(feature = "num-traits")]
+            fn to_u128(&self) -> Option<u128> {
+                Some(*self as u128)
+            }
+        })+
+    };
+}
+
+impl_magnitude! {u64, u128}
+
+#[cfg(feature = "bigint")]
+impl Magnitude for BigUint {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        self - rhs
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        self / rhs
+    }
+
+    fn rem(&self, rhs: &Self) -> Self {
+        self % rhs
+    }
+
+    fn from_u128(value: u128) -> Option<Self> {
+        Some(value.into())
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.try_into().ok()
+    }
+}
+
+/// Returns the opposite sign.
+pub(crate) fn neg_sign(sign: Sign) -> Sign {
+    match sign {
+        Sign::Neg => Sign::Pos,
+        Sign::Nil => Sign::Nil,
+        Sign::Pos => Sign::Neg,
+    }
+}
+
+/// Returns the sign of a product or quotient.
+fn mul_sign(lhs: Sign, rhs: Sign) -> Sign {
+    match (lhs, rhs) {
+        (Sign::Nil, _) | (_, Sign::Nil) => Sign::Nil,
+        _ if lhs == rhs => Sign::Pos,
+        _ => Sign::Neg,
+    }
+}
+
+/// Adds two numbers given by their signs and absolute values.
+fn add_parts<M: Magnitude>(
+    (lsign, labs): (Sign, M),
+    (rsign, rabs): (Sign, M),
+) -> Option<(Sign, M)> {
+    Some(match (lsign, rsign) {
+        (Sign::Nil, _) => (rsign, rabs),
+        (_, Sign::Nil) => (lsign, labs),
+        _ if lsign == rsign => (lsign, labs.checked_add(&rabs)?),
+        _ if labs >= rabs => (lsign, labs.sub(&rabs)),
+        _ => (rsign, rabs.sub(&labs)),
+    })
+}
+
+/// Adds two numbers, or returns [`None`] if the sum is out of range.
+pub(crate) fn checked_add<T: ScaleInt>(lhs: T, rhs: T) -> Option<T>
+where
+    T::Abs: Magnitude,
+{
+    let (sign, abs) = add_parts(lhs.into_parts(), rhs.into_parts())?;
+    T::try_new(sign, abs).ok()
+}
+
+/// Subtracts a number from another, or returns [`None`] if the difference is out of range.
+pub(crate) fn checked_sub<T: ScaleInt>(lhs: T, rhs: T) -> Option<T>
+where
+    T::Abs: Magnitude,
+{
+    let (rsign, rabs) = rhs.into_parts();
+    let (sign, abs) = add_parts(lhs.into_parts(), (neg_sign(rsign), rabs))?;
+    T::try_new(sign, abs).ok()
+}
+
+/// Multiplies two numbers, or returns [`None`] if the product is out of range.
+pub(crate) fn checked_mul<T: ScaleInt>(lhs: T, rhs: T) -> Option<T>
+where
+    T::Abs: Magnitude,
+{
+    let ((lsign, labs), (rsign, rabs)) = (lhs.into_parts(), rhs.into_parts());
+    T::try_new(mul_sign(lsign, rsign), labs.checked_mul(&rabs)?).ok()
+}
+
+/// Divides a number by another, rounding towards zero, or returns [`None`] if the divisor is zero.
+pub(crate) fn checked_div<T: ScaleInt>(lhs: T, rhs: T) -> Option<T>
+where
+    T::Abs: Magnitude,
+{
+    let ((lsign, labs), (rsign, rabs)) = (lhs.into_parts(), rhs.into_parts());
+    if rsign == Sign::Nil {
+        return None;
+    }
+    T::try_new(mul_sign(lsign, rsign), labs.div(&rabs)).ok()
+}
+
+/// Returns the remainder of dividing a number by another, with the sign of the dividend, or [`None`] if the divisor is zero.
+pub(crate) fn checked_rem<T: ScaleInt>(lhs: T, rhs: T) -> Option<T>
+where
+    T::Abs: Magnitude,
+{
+    let ((lsign, labs), (rsign, rabs)) = (lhs.into_parts(), rhs.into_parts());
+    if rsign == Sign::Nil {
+        return None;
+    }
+    T::try_new(lsign, labs.rem(&rabs)).ok()
+}
+
+/// An iterator over consecutive numbers of a scale, see `range` and `range_inclusive` of the structs.
+#[derive(Clone, Debug)]
+pub struct ScaleRange<T> {
+    next: Option<T>,
+    end: T,
+    inclusive: bool,
+}
+
+macro_rules! impl_ops {
+    ($($num:ident),+ $(,)?) => {
+        $(impl $num {
+            /// Adds two numbers, or returns [`None`] if the sum is out of range.
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                checked_add(self, rhs)
+            }
+
+            /// Subtracts a number from another, or returns [`None`] if the difference is out of range.
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                checked_sub(self, rhs)
+            }
+
+            /// Multiplies two numbers, or returns [`None`] if the product is out of range.
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                checked_mul(self, rhs)
+            }
+
+            /// Divides a number by another, rounding towards zero, or returns [`None`] if the divisor is zero.
+            pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                checked_div(self, rhs)
+            }
+
+            /// Returns the next number, or [`None`] if it is out of range.
+            pub fn succ(&self) -> Option<Self> {
+                checked_add(self.clone(), Self::new(Sign::Pos, 1_u8.into()))
+            }
+
+            /// Returns the previous number, or [`None`] if it is out of range.
+            pub fn pred(&self) -> Option<Self> {
+                checked_sub(self.clone(), Self::new(Sign::Pos, 1_u8.into()))
+            }
+
+            /// Iterates over the numbers from `start` up to, but excluding, `end`.
+            ///
+            /// ```
+            /// use chinese_numerals::{ChineseNumeral, MyriadScaleInt};
+            ///
+            /// let start = MyriadScaleInt::from(9);
+            /// let end = MyriadScaleInt::from(12);
+            /// let numerals: Vec<_> = MyriadScaleInt::range(start, end)
+            ///     .map(|num| num.to_lowercase_simp())
+            ///     .collect();
+            /// assert_eq!(vec!["九", "十", "十一"], numerals);
+            /// ```
+            pub fn range(start: Self, end: Self) -> ScaleRange<Self> {
+                ScaleRange {
+                    next: Some(start),
+                    end,
+                    inclusive: false,
+                }
+            }
+
+            /// Iterates over the numbers from `start` up to, and including, `end`.
+            pub fn range_inclusive(start: Self, end: Self) -> ScaleRange<Self> {
+                ScaleRange {
+                    next: Some(start),
+                    end,
+                    inclusive: true,
+                }
+            }
+        }
+
+        impl Iterator for ScaleRange<$num> {
+            type Item = $num;
+
+            fn next(&mut self) -> Option<$num> {
+                let next = self.next.take()?;
+                match next.cmp(&self.end) {
+                    std::cmp::Ordering::Less => {
+                        self.next = next.succ();
+                        Some(next)
+                    }
+                    std::cmp::Ordering::Equal if self.inclusive => Some(next),
+                    _ => None,
+                }
+            }
+        }
+
+        impl std::ops::Add for $num {
+            type Output = Self;
+
+            /// Adds two numbers. Panics if the sum is out of range.
+            fn add(self, rhs: Self) -> Self {
+                checked_add(self, rhs).expect("attempt to add with overflow")
+            }
+        }
+
+        impl std::ops::Sub for $num {
+            type Output = Self;
+
+            /// Subtracts a number from another. Panics if the difference is out of range.
+            fn sub(self, rhs: Self) -> Self {
+                checked_sub(self, rhs).expect("attempt to subtract with overflow")
+            }
+        }
+
+        impl std::ops::Mul for $num {
+            type Output = Self;
+
+            /// Multiplies two numbers. Panics if the product is out of range.
+            fn mul(self, rhs: Self) -> Self {
+                checked_mul(self, rhs).expect("attempt to multiply with overflow")
+            }
+        }
+
+        impl std::ops::Div for $num {
+            type Output = Self;
+
+            /// Divides a number by another, rounding towards zero. Panics if the divisor is zero.
+            fn div(self, rhs: Self) -> Self {
+                checked_div(self, rhs).expect("attempt to divide by zero")
+            }
+        }
+
+        impl std::ops::Rem for $num {
+            type Output = Self;
+
+            /// Returns the remainder of dividing a number by another, with the sign of the dividend. Panics if the divisor is zero.
+            fn rem(self, rhs: Self) -> Self {
+                checked_rem(self, rhs).expect("attempt to calculate the remainder with a divisor of zero")
+            }
+        }
+
+        impl std::ops::Neg for $num {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                let (sign, abs) = self.into_parts();
+                Self::new(neg_sign(sign), abs)
+            }
+        })+
+    };
+}
+
+impl_ops! {ShortScaleInt, MyriadScaleInt, MidScaleInt, LongScaleInt}
+
+#[cfg(feature = "bigint")]
+impl_ops! {MyriadScaleBigInt, MidScaleBigInt, LongScaleBigInt}
+
+/// Parses digits in a radix, with an optional sign.
+#[cfg(feature = "num-traits")]
+fn from_str_radix<T: ScaleInt>(s: &str, radix: u32) -> Result<T, Error>
+where
+    T::Abs: Magnitude,
+{
+    let (sign, digits) = match s.strip_prefix('-') {
+        Some(digits) => (Sign::Neg, digits),
+        None => (Sign::Pos, s.strip_prefix('+').unwrap_or(s)),
+    };
+    if digits.is_empty() {
+        return Err(ParseError::Empty.into());
+    }
+    let radix_abs = T::Abs::from_u128(radix.into()).ok_or(ParseError::Overflow)?;
+    let mut abs = T::Abs::default();
+    for ch in digits.chars() {
+        let digit = ch.to_digit(radix).ok_or(ParseError::InvalidChar(ch))?;
+        let digit = T::Abs::from_u128(digit.into()).ok_or(ParseError::Overflow)?;
+        abs = abs
+            .checked_mul(&radix_abs)
+            .and_then(|abs| abs.checked_add(&digit))
+            .ok_or(ParseError::Overflow)?;
+    }
+    T::try_new(sign, abs)
+}
+
+/// Converts a sign and an absolute value to an `i128`, if in range.
+#[cfg(feature = "num-traits")]
+fn to_i128<M: Magnitude>(sign: Sign, abs: &M) -> Option<i128> {
+    let abs = abs.to_u128()?;
+    match sign {
+        Sign::Neg if abs <= i128::MIN.unsigned_abs() => Some((abs as i128).wrapping_neg()),
+        Sign::Neg => None,
+        _ => abs.try_into().ok(),
+    }
+}
+
+#[cfg(feature = "num-traits")]
+macro_rules! impl_num_traits {
+    ($($num:ident),+ $(,)?) => {
+        $(impl num_traits::Zero for $num {
+            fn zero() -> Self {
+                Self::default()
+            }
+
+            fn is_zero(&self) -> bool {
+                $num::is_zero(self)
+            }
+        }
+
+        impl num_traits::One for $num {
+            fn one() -> Self {
+                Self::new(Sign::Pos, 1_u8.into())
+            }
+        }
+
+        impl num_traits::Num for $num {
+            type FromStrRadixErr = Error;
+
+            fn from_str_radix(s: &str, radix: u32) -> Result<Self, Error> {
+                from_str_radix(s, radix)
+            }
+        }
+
+        impl num_traits::Signed for $num {
+            fn abs(&self) -> Self {
+                let (_, abs) = self.clone().into_parts();
+                Self::new(Sign::Pos, abs)
+            }
+
+            fn abs_sub(&self, other: &Self) -> Self {
+                let diff = self.clone() - other.clone();
+                if diff.is_negative() {
+                    Self::default()
+                } else {
+                    diff
+                }
+            }
+
+            fn signum(&self) -> Self {
+                let (sign, _) = self.clone().into_parts();
+                match sign {
+                    Sign::Neg => -<Self as num_traits::One>::one(),
+                    Sign::Nil => Self::default(),
+                    Sign::Pos => <Self as num_traits::One>::one(),
+                }
+            }
+
+            fn is_positive(&self) -> bool {
+                $num::is_positive(self)
+            }
+
+            fn is_negative(&self) -> bool {
+                $num::is_negative(self)
+            }
+        }
+
+        impl num_traits::FromPrimitive for $num {
+            fn from_i64(n: i64) -> Option<Self> {
+                <Self as num_traits::FromPrimitive>::from_i128(n.into())
+            }
+
+            fn from_u64(n: u64) -> Option<Self> {
+                <Self as num_traits::FromPrimitive>::from_u128(n.into())
+            }
+
+            fn from_i128(n: i128) -> Option<Self> {
+                let sign = if n < 0 { Sign::Neg } else { Sign::Pos };
+                Self::try_new(sign, Magnitude::from_u128(n.unsigned_abs())?).ok()
+            }
+
+            fn from_u128(n: u128) -> Option<Self> {
+                Self::try_new(Sign::Pos, Magnitude::from_u128(n)?).ok()
+            }
+        }
+
+        impl num_traits::ToPrimitive for $num {
+            fn to_i64(&self) -> Option<i64> {
+                num_traits::ToPrimitive::to_i128(self)?.try_into().ok()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                num_traits::ToPrimitive::to_u128(self)?.try_into().ok()
+            }
+
+            fn to_i128(&self) -> Option<i128> {
+                to_i128(self.sign, &self.data)
+            }
+
+            fn to_u128(&self) -> Option<u128> {
+                match self.sign {
+                    Sign::Neg => None,
+                    _ => Magnitude::to_u128(&self.data),
+                }
+            }
+        })+
+    };
+}
+
+#[cfg(feature = "num-traits")]
+impl_num_traits! {ShortScaleInt, MyriadScaleInt, MidScaleInt, LongScaleInt}
+
+#[cfg(feature = "bigint")]
+impl_num_traits! {MyriadScaleBigInt, MidScaleBigInt, LongScaleBigInt}
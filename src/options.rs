@@ -0,0 +1,278 @@
+use crate::{icu, Case, CharacterSet, Middleware, NumChar, Token, Variant};
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Renderings of the number zero.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ZeroStyle {
+    /// Renders zero as 零.
+    Ling,
+    /// Renders zero as an empty string.
+    Empty,
+}
+
+/// Policies of reading runs of zero digits inside a number, e.g. the zeros of 10305.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum ZeroPolicy {
+    /// Reads each run of zeros as a single 零, e.g. 一万零三百零五.
+    #[default]
+    Collapse,
+    /// Omits the zeros, e.g. 一万三百五.
+    Omit,
+    /// Reads a 零 for each zero digit of a run, e.g. 一千零零五 for 1005.
+    Verbose,
+}
+
+/// Whether to write the leading 一 of 十 in numbers from 10 to 19 of the highest group, e.g. 十二 or 一十二.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum LeadingOneStyle {
+    /// Omits the 一, e.g. 十二 and 十万.
+    #[default]
+    Omit,
+    /// Keeps the 一, e.g. 一十二 and 一十万, as in some formal, financial, and reading-aloud contexts.
+    Keep,
+}
+
+/// Strictness of the rules of writing 零, the 一 of 十, and colloquial omissions in [`Style::Standard`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum Strictness {
+    /// Conforms to GB/T 15835 and the accounting rules in uppercase, the financial path: each run of zeros is read as a single 零, the 壹 of 拾 is kept unless [`upper_leading_one`](Options::upper_leading_one) is set, and no trailing units are implied, e.g. 壹拾贰万零叁佰, regardless of [`zero_policy`](Options::zero_policy) and [`colloquial`](Options::colloquial). Lowercase follows the options.
+    #[default]
+    Standard,
+    /// Follows the options in both cases.
+    Lenient,
+    /// Follows classical texts in both cases: zeros are omitted, the 一 of 十 is kept, and no trailing units are implied, e.g. 一十二万三百.
+    Classical,
+}
+
+/// Words of the negative sign.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub enum NegativeStyle {
+    /// 负, or 負 in traditional Chinese.
+    #[default]
+    Fu,
+    /// 欠, as owed amounts in financial contexts.
+    Qian,
+    /// 减, or 減 in traditional Chinese, as in classical texts.
+    Jian,
+    /// A custom word, which may have multiple characters.
+    Custom(String),
+}
+
+impl NegativeStyle {
+    /// Returns the word in the given variant, or `None` for [`NegativeStyle::Fu`], which follows the case, variant, and character set of the options.
+    fn word(&self, variant: Variant) -> Option<&str> {
+        match (self, variant) {
+            (NegativeStyle::Fu, _) => None,
+            (NegativeStyle::Qian, _) => Some("欠"),
+            (NegativeStyle::Jian, Variant::Simplified) => Some("减"),
+            (NegativeStyle::Jian, _) => Some("減"),
+            (NegativeStyle::Custom(word), _) => Some(word),
+        }
+    }
+}
+
+/// Rule sets of rendering numerals.
+///
+/// ICU's `zh` and `zh_Hant` spellout rules differ from [`Style::Standard`] in the following ways:
+///
+/// - Numbers are always read in myriad scale, regardless of the struct, and numbers with absolute values of 10<sup>18</sup> or above are written in grouped Arabic digits, e.g. 负1,000,000,000,000,000,000.
+/// - Lowercase simplified Chinese writes 〇 instead of 零, and the number zero itself is 〇 in lowercase traditional Chinese.
+/// - 零 after 亿, 兆, and 京 is only inserted if the remainder is below 1,0000, 1000,0000, and 1,0000,0000,0000 respectively, e.g. 一亿一万 rather than 一亿零一万, and the 一 of 十 is omitted after them, e.g. 一百亿十万.
+/// - The leading 一 of 十 is omitted in uppercase as well, e.g. 拾贰, and uppercase traditional Chinese writes 參 instead of 叄.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Style {
+    /// Rules of this crate.
+    Standard,
+    /// Rules compatible with ICU's spellout rules (`%spellout-numbering` for lowercase, `%spellout-cardinal-financial` for uppercase), for consistency across systems.
+    IcuCompatible,
+    /// Full-width Arabic digits, e.g. －３５０００００００, for typesetting vertical CJK text.
+    FullWidth,
+    /// Groups below 10000 in full-width Arabic digits followed by their units of the scale, e.g. ３亿５０００万.
+    FullWidthWithUnits,
+}
+
+/// Options of converting numbers to Chinese numerals, see [`to_chinese`](crate::ChineseNumeral::to_chinese).
+///
+/// More options may be added in minor versions, so outside this crate it is created with [`Options::default`] and then the fields are set.
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// Case of the numeral. Defaults to [`Case::Lower`].
+    pub case: Case,
+    /// Chinese variant of the numeral. Defaults to [`Variant::Simplified`].
+    pub variant: Variant,
+    /// Whether to prefix positive numbers with 正, so that the sign is always visible. Defaults to `false`.
+    pub positive_sign: bool,
+    /// Rendering of the number zero. Defaults to [`ZeroStyle::Ling`].
+    pub zero_style: ZeroStyle,
+    /// Custom character set, overriding the characters of [`case`](Options::case) and [`variant`](Options::variant). Defaults to [`None`].
+    pub charset: Option<CharacterSet>,
+    /// Maximum number of characters in the output. [`to_chinese`](crate::ChineseNumeral::to_chinese) truncates the output exceeding the limit, while [`try_to_chinese`](crate::ChineseNumeral::try_to_chinese) fails. Defaults to [`None`], i.e. no limit.
+    pub max_output_chars: Option<usize>,
+    /// Transformations applied in order to the tokens before rendering. Defaults to empty.
+    pub middlewares: Vec<Arc<dyn Middleware>>,
+    /// Rule set of rendering. Defaults to [`Style::Standard`].
+    pub style: Style,
+    /// Reading of runs of zero digits inside a number in [`Style::Standard`]. Defaults to [`ZeroPolicy::Collapse`].
+    pub zero_policy: ZeroPolicy,
+    /// Whether to imply the trailing units as in colloquial speech in [`Style::Standard`], e.g. 三万五 for 35000 and 一千二 for 1200. Defaults to `false`.
+    ///
+    /// The units are only implied if the last digit directly follows the previous digit, which ends a group or is in the last group, so that 一千五百万 is kept.
+    pub colloquial: bool,
+    /// Whether to write the leading 一 of 十 in lowercase in [`Style::Standard`]. Defaults to [`LeadingOneStyle::Omit`].
+    pub leading_one: LeadingOneStyle,
+    /// Whether to write the leading 壹 of 拾 in uppercase in [`Style::Standard`], e.g. 拾贰 as allowed on some receipts. A set style takes precedence over [`Strictness::Standard`]. Defaults to `None`, keeping the 壹.
+    pub upper_leading_one: Option<LeadingOneStyle>,
+    /// Word of the negative sign. Defaults to [`NegativeStyle::Fu`].
+    pub negative_style: NegativeStyle,
+    /// Strictness of the rules in [`Style::Standard`], overriding [`zero_policy`](Options::zero_policy), [`colloquial`](Options::colloquial), and [`leading_one`](Options::leading_one) where it applies. Defaults to [`Strictness::Standard`].
+    pub strictness: Strictness,
+    /// Whether to append 整 to the numeral in [`Style::Standard`], e.g. 叁仟伍佰整 as on receipts, without the money API. Defaults to `false`.
+    pub append_zheng: bool,
+    /// Separator inserted between myriad groups in [`Style::Standard`], e.g. a space or U+200B, for reading very long numerals. Defaults to `None`.
+    pub group_separator: Option<String>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            case: Case::Lower,
+            variant: Variant::Simplified,
+            positive_sign: false,
+            zero_style: ZeroStyle::Ling,
+            charset: None,
+            max_output_chars: None,
+            middlewares: Vec::new(),
+            style: Style::Standard,
+            zero_policy: ZeroPolicy::Collapse,
+            colloquial: false,
+            leading_one: LeadingOneStyle::Omit,
+            upper_leading_one: None,
+            negative_style: NegativeStyle::Fu,
+            strictness: Strictness::Standard,
+            append_zheng: false,
+            group_separator: None,
+        }
+    }
+}
+
+/// Options set by [`set_default_options`], or [`None`] for the defaults of [`Options`].
+static DEFAULT_OPTIONS: RwLock<Option<Arc<Options>>> = RwLock::new(None);
+
+/// The defaults of [`Options`], shared while no options are set.
+static INITIAL_OPTIONS: OnceLock<Arc<Options>> = OnceLock::new();
+
+/// Sets the crate-wide default options, used by the [`Display`](std::fmt::Display) implementations of the structs.
+///
+/// The flags of the formatter still apply on top of the defaults: `{:#}` selects uppercase, and `{:-}` selects traditional Chinese. It is thread-safe, and takes effect in all threads.
+///
+/// ```
+/// use chinese_numerals::{default_options, set_default_options, MyriadScaleInt, Options, Variant};
+///
+/// let mut options = Options::default();
+/// options.variant = Variant::Traditional;
+/// set_default_options(options);
+/// let num = MyriadScaleInt::from(1_0000_0000);
+/// assert_eq!("一億", format!("{num}"));
+/// assert_eq!("壹億", format!("{num:#}"));
+/// assert_eq!(Variant::Traditional, default_options().variant);
+/// ```
+pub fn set_default_options(options: Options) {
+    let mut default = DEFAULT_OPTIONS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *default = Some(Arc::new(options));
+}
+
+/// Returns the crate-wide default options, see [`set_default_options`].
+///
+/// The options are shared rather than copied, so that formatting with them stays cheap. Use [`Arc::make_mut`] or clone them to change a copy.
+pub fn default_options() -> Arc<Options> {
+    DEFAULT_OPTIONS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+        .unwrap_or_else(|| Arc::clone(INITIAL_OPTIONS.get_or_init(Default::default)))
+}
+
+impl Options {
+    /// Returns the styles of the leading one, the zero policy, and whether to imply trailing units, after applying the strictness.
+    pub(crate) fn rules(&self) -> (LeadingOneStyle, ZeroPolicy, bool) {
+        let upper_leading_one = self.upper_leading_one.unwrap_or(LeadingOneStyle::Keep);
+        match (self.strictness, self.case) {
+            (Strictness::Standard, Case::Upper) => (upper_leading_one, ZeroPolicy::Collapse, false),
+            (Strictness::Classical, _) => (LeadingOneStyle::Keep, ZeroPolicy::Omit, false),
+            (_, Case::Lower) => (self.leading_one, self.zero_policy, self.colloquial),
+            (_, Case::Upper) => (upper_leading_one, self.zero_policy, self.colloquial),
+        }
+    }
+
+    /// Returns the rendered string of a character.
+    pub(crate) fn glyph(&self, ch: NumChar) -> &str {
+        if ch == NumChar::Neg {
+            if let Some(word) = self.negative_style.word(self.variant) {
+                return word;
+            }
+        }
+        match (&self.charset, self.case) {
+            (Some(charset), _) => charset.get(ch),
+            (None, case) if self.style == Style::IcuCompatible => {
+                icu::glyph(ch, case, self.variant)
+            }
+            (None, Case::Lower) => ch.to_lowercase(self.variant),
+            (None, Case::Upper) => ch.to_uppercase(self.variant),
+        }
+    }
+
+    /// Renders a character to the output.
+    pub(crate) fn render_char(&self, ch: NumChar, output: &mut String) {
+        output.push_str(self.glyph(ch));
+    }
+
+    /// Measures the numbers of characters and bytes of characters in reading order, rendered without middlewares, up to `limit` characters.
+    pub(crate) fn measure_chars(
+        &self,
+        chars: impl Iterator<Item = NumChar>,
+        limit: usize,
+    ) -> (usize, usize) {
+        let (mut count, mut bytes) = (0, 0);
+        for ch in chars {
+            for ch in self.glyph(ch).chars() {
+                if count == limit {
+                    return (count, bytes);
+                }
+                count += 1;
+                bytes += ch.len_utf8();
+            }
+        }
+        (count, bytes)
+    }
+
+    /// Renders tokens in reading order to the end of the output, after processed by the middlewares.
+    pub(crate) fn render_tokens_into(
+        &self,
+        tokens: impl Iterator<Item = Token>,
+        output: &mut String,
+    ) {
+        if self.middlewares.is_empty() {
+            for token in tokens {
+                match token {
+                    Token::Char(ch) => self.render_char(ch, output),
+                    Token::Text(text) => output.push_str(&text),
+                }
+            }
+            return;
+        }
+
+        let mut tokens = tokens.collect();
+        for middleware in &self.middlewares {
+            middleware.process(&mut tokens);
+        }
+        for token in tokens {
+            match token {
+                Token::Char(ch) => self.render_char(ch, output),
+                Token::Text(text) => output.push_str(&text),
+            }
+        }
+    }
+}
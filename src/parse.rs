@@ -0,0 +1,436 @@
+use std::ops::Range;
+
+use crate::{characters::classify, codes, convert_str, NumChar, Options, Scale};
+
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
+
+/// Errors of parsing numbers from strings.
+#[non_exhaustive]
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum ParseError {
+    /// The string contains no digits.
+    Empty,
+    /// The string contains a character which is not allowed.
+    InvalidChar(char),
+    /// The number is too large for the type to hold.
+    Overflow,
+    /// The name of a scale is unknown.
+    UnknownScale(String),
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "Cannot parse a number from an empty string"),
+            ParseError::InvalidChar(ch) => write!(f, "Invalid character {ch:?} in a number"),
+            ParseError::Overflow => write!(f, "Number too large for the type"),
+            ParseError::UnknownScale(name) => write!(f, "Unknown scale {name:?}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    /// Returns the stable error code, listed in [`codes`](crate::codes).
+    pub fn code(&self) -> u16 {
+        match self {
+            ParseError::Empty => codes::PARSE_EMPTY,
+            ParseError::InvalidChar(_) => codes::PARSE_INVALID_CHAR,
+            ParseError::Overflow => codes::PARSE_OVERFLOW,
+            ParseError::UnknownScale(_) => codes::PARSE_UNKNOWN_SCALE,
+        }
+    }
+}
+
+/// Serializes the error as a struct with its [`code`](ParseError::code) and message.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ParseError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ParseError", 2)?;
+        state.serialize_field("code", &self.code())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+/// Splits a decimal string into whether it is negative and its digits.
+///
+/// The string may start with a sign `+` or `-`, and use `_` or `,` as separators between digits.
+pub(crate) fn split_decimal(s: &str) -> Result<(bool, String), ParseError> {
+    let (neg, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let mut digits = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '0'..='9' => digits.push(ch),
+            '_' | ',' if !digits.is_empty() => {}
+            _ => return Err(ParseError::InvalidChar(ch)),
+        }
+    }
+    if digits.is_empty() {
+        Err(ParseError::Empty)
+    } else {
+        Ok((neg, digits))
+    }
+}
+
+/// Absolute values of Chinese numerals parsed by [`parse_numeral`].
+#[cfg(feature = "bigint")]
+pub(crate) type Abs = BigUint;
+/// Absolute values of Chinese numerals parsed by [`parse_numeral`].
+#[cfg(not(feature = "bigint"))]
+pub(crate) type Abs = u128;
+
+#[cfg(feature = "bigint")]
+fn pow10(exp: u32) -> Option<Abs> {
+    Some(BigUint::from(10_u32).pow(exp))
+}
+
+#[cfg(not(feature = "bigint"))]
+fn pow10(exp: u32) -> Option<Abs> {
+    10_u128.checked_pow(exp)
+}
+
+#[cfg(feature = "bigint")]
+fn checked_add(lhs: Abs, rhs: Abs) -> Option<Abs> {
+    Some(lhs + rhs)
+}
+
+#[cfg(not(feature = "bigint"))]
+fn checked_add(lhs: Abs, rhs: Abs) -> Option<Abs> {
+    lhs.checked_add(rhs)
+}
+
+#[cfg(feature = "bigint")]
+fn checked_mul(lhs: Abs, rhs: Abs) -> Option<Abs> {
+    Some(lhs * rhs)
+}
+
+#[cfg(not(feature = "bigint"))]
+fn checked_mul(lhs: Abs, rhs: Abs) -> Option<Abs> {
+    lhs.checked_mul(rhs)
+}
+
+/// A parse error with the location of the offending text, see [`check_numeral`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct SpannedParseError {
+    /// The error.
+    pub error: ParseError,
+    /// Byte range of the offending text in the input, empty at the end of the input if something is missing.
+    pub span: Range<usize>,
+    /// The offending character, or `None` if the input ends early or the number overflows.
+    pub found: Option<char>,
+    /// Description of what is expected at the span.
+    pub expected: &'static str,
+}
+
+impl std::fmt::Display for SpannedParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at {}..{}, expected {}",
+            self.error, self.span.start, self.span.end, self.expected
+        )
+    }
+}
+
+impl std::error::Error for SpannedParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<SpannedParseError> for ParseError {
+    fn from(err: SpannedParseError) -> Self {
+        err.error
+    }
+}
+
+const EXPECTED_START: &str = "a sign, a digit or a unit";
+const EXPECTED_NEXT: &str = "a digit or a unit";
+const EXPECTED_RANGE: &str = "a number in range of the scale";
+const EXPECTED_UNIT: &str = "a unit after a digit";
+const EXPECTED_ORDER: &str = "units in descending order";
+
+/// Checks whether a string can be parsed as a Chinese numeral in the given scale, returning the location of the error if not, e.g. for editors to underline invalid text.
+///
+/// The parsing is as lenient as the [`TryFrom<&str>`] implementations of the integers, but a number too large for `u128` overflows without the `bigint` feature.
+///
+/// ```
+/// use chinese_numerals::{check_numeral, ParseError, Scale};
+///
+/// assert_eq!(Ok(()), check_numeral("负三万五千", Scale::Myriad));
+/// let err = check_numeral("三万x千", Scale::Myriad).unwrap_err();
+/// assert_eq!(ParseError::InvalidChar('x'), err.error);
+/// assert_eq!(6..7, err.span);
+/// assert_eq!(Some('x'), err.found);
+/// assert_eq!("a digit or a unit", err.expected);
+/// ```
+pub fn check_numeral(s: &str, scale: Scale) -> Result<(), SpannedParseError> {
+    parse_spanned(s, scale).map(|_| ())
+}
+
+/// Parses a Chinese numeral in the given scale into whether it is negative and its absolute value.
+///
+/// The parsing is lenient: 零 is skipped, 〇 is read as 零 and 两 as 二, and the 一 before units may be omitted. A single digit right after a unit is read at the next lower place, as in speech, e.g. 三万五 as 35000. Each myriad unit multiplies the sum of the smaller terms before it, so that 一万亿 is read as 10<sup>12</sup> in scales composing units. Runs of Arabic digits are read as numbers, so that mixed inputs like 3万5千 are accepted, and `-` is read as 负.
+///
+/// Digits following digits, e.g. 一二三, and units out of descending order, e.g. 十十 or 一万一万, are rejected.
+pub(crate) fn parse_numeral(s: &str, scale: Scale) -> Result<(bool, Abs), ParseError> {
+    parse_spanned(s, scale).map_err(ParseError::from)
+}
+
+/// Parses a Chinese numeral as [`parse_numeral`], locating the error in the string.
+fn parse_spanned(s: &str, scale: Scale) -> Result<(bool, Abs), SpannedParseError> {
+    let mut chars = s.char_indices().peekable();
+    let neg = chars
+        .next_if(|&(_, ch)| ch == '-' || NumChar::from_char(ch) == Some(NumChar::Neg))
+        .is_some();
+    if chars.peek().is_none() {
+        return Err(SpannedParseError {
+            error: ParseError::Empty,
+            span: s.len()..s.len(),
+            found: None,
+            expected: if neg { EXPECTED_NEXT } else { EXPECTED_START },
+        });
+    }
+    let misplaced = |pos: usize, ch: char, expected: &'static str| SpannedParseError {
+        error: ParseError::InvalidChar(ch),
+        span: pos..pos + ch.len_utf8(),
+        found: Some(ch),
+        expected,
+    };
+    let invalid = |pos: usize, ch: char| {
+        misplaced(
+            pos,
+            ch,
+            if pos == 0 {
+                EXPECTED_START
+            } else {
+                EXPECTED_NEXT
+            },
+        )
+    };
+    let overflow = |span: Range<usize>| SpannedParseError {
+        error: ParseError::Overflow,
+        span,
+        found: None,
+        expected: EXPECTED_RANGE,
+    };
+
+    // terms of the sum in descending order, with the exponents of their units
+    let mut terms: Vec<(Abs, u32)> = Vec::new();
+    let mut digit: Option<Abs> = None;
+    // exponent of the unit just read
+    let mut last_unit: Option<u32> = None;
+    // exponent of the unit right before the pending digit, if it is a single digit read at the next lower place
+    let mut trailing: Option<u32> = None;
+    while let Some((pos, ch)) = chars.next() {
+        let end = pos + ch.len_utf8();
+        if let Some(first) = ch.to_digit(10) {
+            if digit.is_some() {
+                return Err(misplaced(pos, ch, EXPECTED_UNIT));
+            }
+            let mut value = Abs::from(first);
+            let mut single = true;
+            while let Some((next_pos, next)) = chars.next_if(|(_, ch)| ch.is_ascii_digit()) {
+                let next = Abs::from(next.to_digit(10).unwrap_or_default());
+                value = checked_mul(value, Abs::from(10_u32))
+                    .and_then(|value| checked_add(value, next))
+                    .ok_or_else(|| overflow(pos..next_pos + 1))?;
+                single = false;
+            }
+            digit = Some(value);
+            trailing = last_unit.take().filter(|_| single);
+            continue;
+        }
+        let num = classify(ch).ok_or_else(|| invalid(pos, ch))?;
+        let index = num as u32;
+        match num {
+            NumChar::Zero => last_unit = None,
+            NumChar::One
+            | NumChar::Two
+            | NumChar::Three
+            | NumChar::Four
+            | NumChar::Five
+            | NumChar::Six
+            | NumChar::Seven
+            | NumChar::Eight
+            | NumChar::Nine => {
+                if digit.is_some() {
+                    return Err(misplaced(pos, ch, EXPECTED_UNIT));
+                }
+                digit = Some(Abs::from(index));
+                trailing = last_unit.take();
+            }
+            NumChar::Shi | NumChar::Bai | NumChar::Qian => {
+                let exp = index - NumChar::Nine as u32;
+                if terms.last().is_some_and(|&(_, last)| last <= exp) {
+                    return Err(misplaced(pos, ch, EXPECTED_ORDER));
+                }
+                let value = digit.take().unwrap_or_else(|| Abs::from(1_u32));
+                let value = pow10(exp).and_then(|unit| checked_mul(value, unit));
+                terms.push((value.ok_or_else(|| overflow(pos..end))?, exp));
+                last_unit = Some(exp);
+                trailing = None;
+            }
+            _ if num.is_myriad_unit() => {
+                let exp = scale.unit_exp(index - NumChar::Wan as u32);
+                let mut sum = digit.take();
+                while let Some((term, _)) = terms.pop_if(|(_, term_exp)| *term_exp < exp) {
+                    let prev = sum.unwrap_or_else(|| Abs::from(0_u32));
+                    sum = Some(checked_add(prev, term).ok_or_else(|| overflow(pos..end))?);
+                }
+                // a unit right after a larger unit or the same unit, e.g. 亿万 or 一万一万
+                if terms
+                    .last()
+                    .is_some_and(|&(_, last)| last <= exp || sum.is_none())
+                {
+                    return Err(misplaced(pos, ch, EXPECTED_ORDER));
+                }
+                let sum = sum.unwrap_or_else(|| Abs::from(1_u32));
+                let value = pow10(exp).and_then(|unit| checked_mul(sum, unit));
+                terms.push((value.ok_or_else(|| overflow(pos..end))?, exp));
+                last_unit = Some(exp);
+                trailing = None;
+            }
+            _ => return Err(invalid(pos, ch)),
+        }
+    }
+
+    let mut abs = match (digit, trailing) {
+        (Some(digit), Some(exp)) if exp > 1 => pow10(exp - 1)
+            .and_then(|place| checked_mul(digit, place))
+            .ok_or_else(|| overflow(0..s.len()))?,
+        (digit, _) => digit.unwrap_or_else(|| Abs::from(0_u32)),
+    };
+    for (term, _) in terms {
+        abs = checked_add(abs, term).ok_or_else(|| overflow(0..s.len()))?;
+    }
+    Ok((neg, abs))
+}
+
+/// Returns the units of 十 and above written in a numeral, in order.
+fn units_of(s: &str) -> Vec<NumChar> {
+    s.chars()
+        .filter_map(NumChar::from_char)
+        .filter(|&num| (NumChar::Shi as usize..=NumChar::Zai as usize).contains(&(num as usize)))
+        .collect()
+}
+
+/// Parses a Chinese numeral of unknown scale, returning the interpretations in all scales consistent with its units, as pairs of the scale and the value as a decimal string.
+///
+/// A scale is consistent if the numeral of the value in the scale writes the same units in the same order, e.g. 一万亿 is read in mid-scale and long scale, where 亿 is 10<sup>8</sup>, but not in myriad scale, where 一万亿 would be written 一兆. The interpretations are ordered from short scale to long scale, and numerals without units above 万, which read the same in all scales, give all of them. An empty `Vec` is returned if the numeral is malformed in all scales.
+///
+/// Returns [`ParseError`] if the string cannot be parsed in any scale.
+///
+/// ```
+/// use chinese_numerals::{parse_auto, Scale};
+///
+/// assert_eq!(
+///     vec![(Scale::Mid, "1000000000000".to_owned()), (Scale::Long, "1000000000000".to_owned())],
+///     parse_auto("一万亿").unwrap()
+/// );
+/// assert_eq!(
+///     vec![(Scale::Short, "1020000".to_owned()), (Scale::Myriad, "1000000020000".to_owned())],
+///     parse_auto("一兆二万").unwrap()[..2]
+/// );
+/// assert_eq!(4, parse_auto("负三千零五").unwrap().len());
+/// ```
+pub fn parse_auto(s: &str) -> Result<Vec<(Scale, String)>, ParseError> {
+    let units = units_of(s);
+    let mut error = None;
+    let mut errors = 0;
+    let mut interpretations = Vec::new();
+    for scale in [Scale::Short, Scale::Myriad, Scale::Mid, Scale::Long] {
+        match interpret(s, scale) {
+            Ok(Some((value, numeral))) if units_of(&numeral) == units => {
+                interpretations.push((scale, value));
+            }
+            Ok(_) => {}
+            Err(err) => {
+                error.get_or_insert(err);
+                errors += 1;
+            }
+        }
+    }
+    match error {
+        Some(err) if errors == 4 => Err(err),
+        _ => Ok(interpretations),
+    }
+}
+
+/// Parses a numeral in the scale into its value as a decimal string and the numeral of the value in the scale, or `None` if it is out of range of the scale.
+fn interpret(s: &str, scale: Scale) -> Result<Option<(String, String)>, ParseError> {
+    let (neg, abs) = parse_numeral(s, scale)?;
+    let value = if neg && abs != Abs::from(0_u32) {
+        format!("-{abs}")
+    } else {
+        abs.to_string()
+    };
+    Ok(convert_str(&value, scale, &Options::default())
+        .ok()
+        .map(|numeral| (value, numeral)))
+}
+
+/// Reading of a numeral in a preferred scale, with other readings consistent with its units, see [`parse_preferring`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ScaleReading {
+    /// The scale the numeral is read in.
+    pub scale: Scale,
+    /// The value as a decimal string.
+    pub value: String,
+    /// Readings in other scales consistent with the units of the numeral, which give different values, from short scale to long scale.
+    pub alternatives: Vec<(Scale, String)>,
+}
+
+impl ScaleReading {
+    /// Whether the numeral can be read as different values, e.g. 一兆 as 10<sup>6</sup>, 10<sup>12</sup>, or 10<sup>16</sup>.
+    pub fn is_ambiguous(&self) -> bool {
+        !self.alternatives.is_empty()
+    }
+}
+
+/// Parses a Chinese numeral of unknown scale in a preferred scale, reporting the different values it has in other scales, as [`parse_auto`] finds.
+///
+/// The numeral is read in the preferred scale if it is in range, even if written differently from the numeral of the value in the scale, or in the first consistent scale otherwise.
+///
+/// Returns [`ParseError`] if the string cannot be parsed, or [`ParseError::Overflow`] if it is out of range of the preferred scale and not consistent with any other scale.
+///
+/// ```
+/// use chinese_numerals::{parse_preferring, Scale};
+///
+/// let reading = parse_preferring("一兆", Scale::Myriad).unwrap();
+/// assert_eq!("1000000000000", reading.value);
+/// assert!(reading.is_ambiguous());
+/// assert_eq!(
+///     vec![(Scale::Short, "1000000".to_owned()), (Scale::Mid, "10000000000000000".to_owned())],
+///     reading.alternatives
+/// );
+/// assert!(!parse_preferring("三万五千", Scale::Mid).unwrap().is_ambiguous());
+/// ```
+pub fn parse_preferring(s: &str, preferred: Scale) -> Result<ScaleReading, ParseError> {
+    let interpretations = parse_auto(s)?;
+    let (scale, value) = match interpret(s, preferred)? {
+        Some((value, _)) => (preferred, value),
+        None => interpretations
+            .first()
+            .cloned()
+            .ok_or(ParseError::Overflow)?,
+    };
+    let mut alternatives: Vec<(Scale, String)> = Vec::new();
+    for (other, other_value) in interpretations {
+        if other_value != value && alternatives.iter().all(|(_, seen)| *seen != other_value) {
+            alternatives.push((other, other_value));
+        }
+    }
+    Ok(ScaleReading {
+        scale,
+        value,
+        alternatives,
+    })
+}
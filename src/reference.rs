@@ -0,0 +1,199 @@
+//! A slow but straightforward reference converter, and checks of the optimized structs against it, for property tests in downstream CI. Use it by turning on feature "reference".
+//!
+//! The reference reads a string of decimal digits by the rules directly: a number below 1,0000 is read digit by digit with 千, 百, and 十, and a larger number is split at the largest unit below it into the part before the unit and the remainder, with a 零 if the remainder does not reach the digit below the unit.
+//!
+//! ```
+//! use chinese_numerals::{reference, Case, Scale, Variant};
+//!
+//! assert_eq!("一万零二十", reference::to_chinese("10020", Scale::Myriad, Case::Lower, Variant::Simplified));
+//! for value in [0, -1, 10, 1_0000_0000, i128::MIN, i128::MAX] {
+//!     reference::check_i128(value, Scale::Mid).unwrap();
+//! }
+//! ```
+
+use crate::{
+    characters::NUM_CHARS, units, Case, ChineseNumeral, LongScaleInt, MidScaleInt, MyriadScaleInt,
+    NumChar, Scale, ShortScaleInt, Variant,
+};
+
+#[cfg(feature = "bigint")]
+use crate::{LongScaleBigInt, MidScaleBigInt, MyriadScaleBigInt};
+#[cfg(feature = "bigint")]
+use num_bigint::BigInt;
+
+/// A difference between the output of a struct and the reference.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Mismatch {
+    /// The number checked, in decimal digits.
+    pub value: String,
+    /// Scale of the struct.
+    pub scale: Scale,
+    /// Case of the numerals.
+    pub case: Case,
+    /// Chinese variant of the numerals.
+    pub variant: Variant,
+    /// Output of the reference, or `None` if the number is out of range.
+    pub expected: Option<String>,
+    /// Output of the struct, or `None` if the struct rejects the number.
+    pub actual: Option<String>,
+}
+
+impl std::fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} in {} ({:?}, {:?}): expected {:?}, got {:?}",
+            self.value,
+            self.scale.name_en(),
+            self.case,
+            self.variant,
+            self.expected,
+            self.actual,
+        )
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+/// Converts a decimal string with an optional `-` sign by the reference rules.
+///
+/// # Panics
+///
+/// Panics if the string contains characters other than the sign and ASCII digits.
+pub fn to_chinese(value: &str, scale: Scale, case: Case, variant: Variant) -> String {
+    let (neg, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value),
+    };
+    assert!(
+        digits.bytes().all(|digit| digit.is_ascii_digit()),
+        "Invalid decimal string {value:?}"
+    );
+    let digits = digits.trim_start_matches('0');
+
+    let mut chars = Vec::new();
+    if digits.is_empty() {
+        chars.push(NumChar::Zero);
+    } else {
+        if neg {
+            chars.push(NumChar::Neg);
+        }
+        let start = chars.len();
+        read(digits.as_bytes(), scale, &mut chars);
+        if case == Case::Lower && chars[start..].starts_with(&[NumChar::One, NumChar::Shi]) {
+            chars.remove(start);
+        }
+    }
+    chars
+        .into_iter()
+        .map(|ch| match case {
+            Case::Lower => ch.to_lowercase(variant),
+            Case::Upper => ch.to_uppercase(variant),
+        })
+        .collect()
+}
+
+/// Reads digits without leading zeros in reading order.
+fn read(digits: &[u8], scale: Scale, chars: &mut Vec<NumChar>) {
+    let len = digits.len();
+    let Some(unit) = units(scale)
+        .iter()
+        .rev()
+        .find(|unit| (unit.exponent as usize) < len)
+    else {
+        let mut zero = false;
+        for (i, &digit) in digits.iter().enumerate() {
+            if digit == b'0' {
+                zero = true;
+                continue;
+            }
+            if zero {
+                chars.push(NumChar::Zero);
+                zero = false;
+            }
+            chars.push(NUM_CHARS[(digit - b'0') as usize]);
+            let place = len - 1 - i;
+            if place > 0 {
+                chars.push(NUM_CHARS[9 + place]);
+            }
+        }
+        return;
+    };
+    let (high, low) = digits.split_at(len - unit.exponent as usize);
+    read(high, scale, chars);
+    chars.push(unit.char);
+    if let Some(start) = low.iter().position(|&digit| digit != b'0') {
+        if start > 0 {
+            chars.push(NumChar::Zero);
+        }
+        read(&low[start..], scale, chars);
+    }
+}
+
+/// Compares the outputs of a number in both cases and in simplified and traditional Chinese with the reference.
+///
+/// The number is `None` if the struct rejects it, which is a mismatch if it has no more digits than the largest number of the scale.
+fn compare<T: ChineseNumeral>(value: &str, scale: Scale, num: Option<T>) -> Result<(), Mismatch> {
+    let digits = value.trim_start_matches('-').trim_start_matches('0').len();
+    let in_range = digits <= scale.max_magnitude() + 1;
+    for case in [Case::Lower, Case::Upper] {
+        for variant in [Variant::Simplified, Variant::Traditional] {
+            let expected = in_range.then(|| to_chinese(value, scale, case, variant));
+            let actual = num.as_ref().map(|num| match case {
+                Case::Lower => num.to_lowercase(variant),
+                Case::Upper => num.to_uppercase(variant),
+            });
+            if expected != actual {
+                return Err(Mismatch {
+                    value: value.to_owned(),
+                    scale,
+                    case,
+                    variant,
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks the struct of the scale backed by primitive integers against the reference for a number.
+///
+/// Numbers out of range of short scale must be rejected by [`ShortScaleInt`].
+pub fn check_i128(value: i128, scale: Scale) -> Result<(), Mismatch> {
+    let digits = value.to_string();
+    match scale {
+        Scale::Short => compare(&digits, scale, ShortScaleInt::try_from(value).ok()),
+        Scale::Myriad => compare(&digits, scale, Some(MyriadScaleInt::from(value))),
+        Scale::Mid => compare(&digits, scale, Some(MidScaleInt::from(value))),
+        Scale::Long => compare(&digits, scale, Some(LongScaleInt::from(value))),
+    }
+}
+
+/// Checks the struct of the scale backed by primitive integers against the reference for a number.
+///
+/// Numbers out of range of short scale must be rejected by [`ShortScaleInt`].
+pub fn check_u128(value: u128, scale: Scale) -> Result<(), Mismatch> {
+    let digits = value.to_string();
+    match scale {
+        Scale::Short => compare(&digits, scale, ShortScaleInt::try_from(value).ok()),
+        Scale::Myriad => compare(&digits, scale, Some(MyriadScaleInt::from(value))),
+        Scale::Mid => compare(&digits, scale, Some(MidScaleInt::from(value))),
+        Scale::Long => compare(&digits, scale, Some(LongScaleInt::from(value))),
+    }
+}
+
+/// Checks the big integer struct of the scale against the reference for a number, or [`ShortScaleInt`] for short scale.
+///
+/// Numbers out of range of the scale must be rejected by the struct.
+#[cfg(feature = "bigint")]
+pub fn check_bigint(value: &BigInt, scale: Scale) -> Result<(), Mismatch> {
+    let digits = value.to_string();
+    match scale {
+        Scale::Short => compare(&digits, scale, digits.parse::<ShortScaleInt>().ok()),
+        Scale::Myriad => compare(&digits, scale, MyriadScaleBigInt::try_from(value).ok()),
+        Scale::Mid => compare(&digits, scale, MidScaleBigInt::try_from(value).ok()),
+        Scale::Long => compare(&digits, scale, LongScaleBigInt::try_from(value).ok()),
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{NumChar, Scale};
+
+/// A group of a numeral below 10000 and the units following it, see [`ChineseNumeral::segments`](crate::ChineseNumeral::segments).
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct Segment {
+    /// Whether 零 is read before the group.
+    pub zero: bool,
+    /// Value of the group, below 10000.
+    pub value: u16,
+    /// Units following the group in reading order, e.g. 万 and 亿 of 一万亿 in mid scale.
+    pub units: Vec<NumChar>,
+}
+
+/// Splits the characters of an absolute value in reversed order into segments in reading order.
+pub(crate) fn segments(chars: Vec<NumChar>) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut segment = Segment::default();
+    let mut digit = 0;
+    let mut zero = false;
+    for ch in chars.into_iter().rev() {
+        match ch {
+            NumChar::Zero => zero = true,
+            NumChar::One
+            | NumChar::Two
+            | NumChar::Three
+            | NumChar::Four
+            | NumChar::Five
+            | NumChar::Six
+            | NumChar::Seven
+            | NumChar::Eight
+            | NumChar::Nine => {
+                if !segment.units.is_empty() {
+                    segments.push(std::mem::take(&mut segment));
+                    segment.zero = zero;
+                }
+                zero = false;
+                digit = ch as u16;
+            }
+            NumChar::Shi | NumChar::Bai | NumChar::Qian => {
+                segment.value += digit * 10_u16.pow(ch as u32 - NumChar::Nine as u32);
+                digit = 0;
+            }
+            NumChar::Neg | NumChar::Pos | NumChar::Point => {}
+            _ => {
+                segment.value += digit;
+                digit = 0;
+                segment.units.push(ch);
+            }
+        }
+    }
+    segment.value += digit;
+    if segment.value > 0 || !segment.units.is_empty() {
+        segments.push(segment);
+    }
+    segments
+}
+
+/// Returns the exponents of 10 of the units of segments, e.g. 12 for 一 of 一万二千亿 in mid-scale.
+///
+/// A unit multiplies the larger units after it, so the units are tracked in a stack from the end.
+pub(crate) fn exps(segments: &[Segment], scale: Scale) -> Vec<u32> {
+    let mut stack: Vec<u32> = Vec::new();
+    let mut exps: Vec<u32> = segments
+        .iter()
+        .rev()
+        .map(|segment| {
+            for &unit in segment.units.iter().rev() {
+                let exp = scale.unit_exp(unit as u32 - NumChar::Wan as u32);
+                while stack.last().is_some_and(|&last| last < exp) {
+                    stack.pop();
+                }
+                stack.push(exp);
+            }
+            stack.iter().sum()
+        })
+        .collect();
+    exps.reverse();
+    exps
+}
+
+/// Returns the numbers of zero digits each 零 of the segments stands for, in reading order.
+pub(crate) fn zero_runs(segments: &[Segment], scale: Scale) -> Vec<u32> {
+    let mut runs = Vec::new();
+    let mut prev_exp = None;
+    for (segment, exp) in segments.iter().zip(exps(segments, scale)) {
+        let top = segment.value.ilog10();
+        if let (true, Some(prev_exp)) = (segment.zero, prev_exp) {
+            runs.push(prev_exp - exp - top - 1);
+        }
+        let mut run = 0;
+        for digit_exp in (0..top).rev() {
+            match segment.value / 10_u16.pow(digit_exp) % 10 {
+                0 => run += 1,
+                _ if run > 0 => {
+                    runs.push(run);
+                    run = 0;
+                }
+                _ => {}
+            }
+        }
+        prev_exp = Some(exp);
+    }
+    runs
+}
+
+/// Whether the trailing units may be implied in colloquial speech, e.g. 千 of 三万五千, since the last digit directly follows the previous digit, which ends a group or is in the last group.
+pub(crate) fn implied_trailing_units(segments: &[Segment], scale: Scale) -> bool {
+    let exps = exps(segments, scale);
+    let Some((last, &last_exp)) = segments.last().zip(exps.last()) else {
+        return false;
+    };
+    let lowest = (0..4)
+        .find(|&exp| last.value / 10_u16.pow(exp) % 10 > 0)
+        .unwrap_or(0);
+    if last_exp + lowest == 0 {
+        return false;
+    }
+    let rest = last.value / 10_u16.pow(lowest + 1);
+    if rest > 0 {
+        // the last two digits are in the last group, e.g. 一千二 for 1200
+        return last_exp == 0 && !rest.is_multiple_of(10);
+    }
+    match segments.len().checked_sub(2) {
+        Some(prev) if !last.zero => {
+            // the units of the previous segment must not be multiplied by later ones, e.g. 万 of 一万二千亿 in mid-scale
+            let own_exp: u32 = segments[prev]
+                .units
+                .iter()
+                .map(|&unit| scale.unit_exp(unit as u32 - NumChar::Wan as u32))
+                .sum();
+            !segments[prev].value.is_multiple_of(10)
+                && exps[prev] == own_exp
+                && own_exp == last_exp + lowest + 1
+        }
+        _ => false,
+    }
+}
@@ -1,13 +1,18 @@
-use crate::{characters::*, ChineseNumeralBase, Sign, Signed};
+use crate::{
+    characters::*, default_options, ChineseInt, ChineseNumeral, Error, Options, Scale, ScaleBase,
+    ScaleKind, Sign,
+};
+
+/// Marker type of short scale (下数) for [`ChineseInt`].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub struct Short;
+
+impl ScaleKind for Short {}
 
 /// Short scale integers (下数).
 ///
 /// 「下数者，十十变之。若言十万曰亿，十亿曰兆，十兆曰京也。」
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, Default)]
-pub struct ShortScaleInt {
-    pub(super) sign: Sign,
-    pub(super) data: u64,
-}
+pub type ShortScaleInt = ChineseInt<Short>;
 
 impl ShortScaleInt {
     pub(super) const MAX_ABS: u64 = 999_9999_9999_9999;
@@ -25,18 +30,26 @@ impl ShortScaleInt {
     };
 }
 
-impl ChineseNumeralBase for ShortScaleInt {
-    fn to_chars(&self) -> Vec<NumChar> {
-        let mut chars = Vec::new();
-        let mut num = *self.data();
+impl ScaleBase for Short {
+    type Abs = u64;
+
+    const SCALE: Scale = Scale::Short;
+    const MAX_ABS: u64 = ShortScaleInt::MAX_ABS;
+
+    fn push_abs(mut num: u64, chars: &mut Vec<NumChar>) {
+        let start = chars.len();
         let mut prev_rem = 1;
 
+        #[allow(clippy::needless_range_loop)]
         for exp in 9..=23 {
+            if num == 0 {
+                break;
+            }
             let rem = num % 10;
             num /= 10;
 
             if rem > 0 {
-                if !chars.is_empty() && prev_rem < 1 {
+                if chars.len() > start && prev_rem < 1 {
                     chars.push(NUM_CHARS[0]);
                 }
                 if exp > 9 {
@@ -46,15 +59,38 @@ impl ChineseNumeralBase for ShortScaleInt {
             }
             prev_rem = rem;
         }
-        chars
     }
+}
 
-    fn to_chars_trimmed(&self) -> Vec<NumChar> {
-        let mut chars = self.to_chars();
-        if self.data() >= &10 && self.data() <= &19 {
-            let one = chars.pop();
-            debug_assert_eq!(one, Some(NumChar::One));
-        }
-        chars
-    }
+/// Extension trait converting wide primitive integers, i.e. `i64`, `u64`, `i128`, `u128`, `isize`, and `usize`, to short scale numerals directly, failing with the same [`Error`] as `ShortScaleInt::try_from` if out of range.
+///
+/// ```
+/// use chinese_numerals::{ErrorKind, TryToChinese};
+///
+/// assert_eq!("一垓二京三兆四亿五万", 12345_0000_u64.try_to_short_scale().unwrap());
+/// let err = 1234567890123456_u64.try_to_short_scale().unwrap_err();
+/// assert_eq!(&ErrorKind::OutOfRange, err.kind());
+/// ```
+pub trait TryToChinese {
+    /// Converts the number to a short scale numeral with the default options set by [`set_default_options`](crate::set_default_options), or returns [`Error`] if it is out of range.
+    fn try_to_short_scale(self) -> Result<String, Error>;
+
+    /// Converts the number to a short scale numeral with given options, or returns [`Error`] if it is out of range or the output exceeds [`Options::max_output_chars`].
+    fn try_to_short_scale_with(self, options: &Options) -> Result<String, Error>;
 }
+
+macro_rules! impl_try_to_chinese {
+    ($($int:ty),+ $(,)?) => {
+        $(impl TryToChinese for $int {
+            fn try_to_short_scale(self) -> Result<String, Error> {
+                Ok(ShortScaleInt::try_from(self)?.to_chinese(&default_options()))
+            }
+
+            fn try_to_short_scale_with(self, options: &Options) -> Result<String, Error> {
+                ShortScaleInt::try_from(self)?.try_to_chinese(options)
+            }
+        })+
+    };
+}
+
+impl_try_to_chinese! {i64, u64, i128, u128, isize, usize}
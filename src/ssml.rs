@@ -0,0 +1,114 @@
+//! Speech Synthesis Markup Language (SSML) output for speech synthesis pipelines.
+
+use crate::characters::{NumChar, YAO};
+
+/// A character of a numeral with its Mandarin reading, see [`speech_tokens`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct SpeechToken {
+    /// The character.
+    pub text: char,
+    /// The reading in pinyin with a tone number, or [`None`] if the character is not a numeral character.
+    pub pinyin: Option<&'static str>,
+    /// Whether speech synthesizers may misread the character, i.e. 正 and the units above 亿, which are rare in speech.
+    pub needs_hint: bool,
+}
+
+/// Recognizes a character of a numeral, including 〇 and 幺.
+fn classify(ch: char) -> Option<(NumChar, &'static str)> {
+    match ch {
+        '〇' => Some((NumChar::Zero, NumChar::Zero.pinyin())),
+        _ if ch.encode_utf8(&mut [0; 4]) == YAO => Some((NumChar::One, "yao1")),
+        _ => NumChar::from_char(ch).map(|num| (num, num.pinyin())),
+    }
+}
+
+/// Splits a numeral into characters with their readings.
+///
+/// ```
+/// use chinese_numerals::{speech_tokens, SpeechToken};
+///
+/// assert_eq!(
+///     vec![
+///         SpeechToken { text: '一', pinyin: Some("yi1"), needs_hint: false },
+///         SpeechToken { text: '兆', pinyin: Some("zhao4"), needs_hint: true },
+///     ],
+///     speech_tokens("一兆")
+/// );
+/// ```
+pub fn speech_tokens(numeral: &str) -> Vec<SpeechToken> {
+    numeral
+        .chars()
+        .map(|ch| match classify(ch) {
+            Some((num, pinyin)) => SpeechToken {
+                text: ch,
+                pinyin: Some(pinyin),
+                needs_hint: (NumChar::Zhao as usize..=NumChar::Zai as usize)
+                    .contains(&(num as usize)),
+            },
+            None => SpeechToken {
+                text: ch,
+                pinyin: None,
+                needs_hint: false,
+            },
+        })
+        .collect()
+}
+
+/// Escapes the special characters of XML.
+fn escape(text: &str, output: &mut String) {
+    for ch in text.chars() {
+        match ch {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&apos;"),
+            _ => output.push(ch),
+        }
+    }
+}
+
+/// Converts a numeral to an SSML fragment, wrapping the characters which may be misread in `<phoneme>` tags with their pinyin.
+///
+/// ```
+/// use chinese_numerals::{to_ssml, ChineseNumeral, MyriadScaleInt};
+///
+/// let numeral = MyriadScaleInt::from(3_0000_0000_0000_u64).to_lowercase_simp();
+/// assert_eq!(
+///     r#"三<phoneme alphabet="x-pinyin" ph="zhao4">兆</phoneme>"#,
+///     to_ssml(&numeral)
+/// );
+/// ```
+pub fn to_ssml(numeral: &str) -> String {
+    let mut output = String::with_capacity(numeral.len());
+    for token in speech_tokens(numeral) {
+        match token.pinyin {
+            Some(pinyin) if token.needs_hint => {
+                output.push_str(r#"<phoneme alphabet="x-pinyin" ph=""#);
+                output.push_str(pinyin);
+                output.push_str(r#"">"#);
+                output.push(token.text);
+                output.push_str("</phoneme>");
+            }
+            _ => escape(token.text.encode_utf8(&mut [0; 4]), &mut output),
+        }
+    }
+    output
+}
+
+/// Wraps written text in an SSML `<sub>` tag, so that it is displayed as written but read as the numeral.
+///
+/// ```
+/// use chinese_numerals::{to_ssml_sub, ChineseNumeral, MyriadScaleInt};
+///
+/// let numeral = MyriadScaleInt::from(12000).to_lowercase_simp();
+/// assert_eq!(r#"<sub alias="一万二千">12,000</sub>"#, to_ssml_sub("12,000", &numeral));
+/// ```
+pub fn to_ssml_sub(written: &str, numeral: &str) -> String {
+    let mut output = String::from(r#"<sub alias=""#);
+    escape(numeral, &mut output);
+    output.push_str(r#"">"#);
+    escape(written, &mut output);
+    output.push_str("</sub>");
+    output
+}
@@ -0,0 +1,172 @@
+use crate::{
+    characters::{NumChar, NUM_CHARS},
+    const_fmt::GROUPS,
+    omit_zeros_and_ones, Case, Error, ParseError, Scale, Variant,
+};
+
+/// Formatter fed with decimal digits most significant first, emitting characters of the numeral as soon as each group completes, without holding the whole number in memory.
+///
+/// The number of digits must be known in advance, since it determines the unit of each group. A leading `-` may be pushed before the digits for negative numbers.
+///
+/// ```
+/// use chinese_numerals::{Case, DigitStream, Scale, Variant};
+///
+/// let mut stream = DigitStream::new(Scale::Myriad, 9, Case::Lower, Variant::Simplified);
+/// let mut output = String::new();
+/// for ch in "-102030405".chars() {
+///     stream.push(ch, &mut output).unwrap();
+///     if ch == '1' {
+///         assert_eq!("负一亿", output);
+///     }
+/// }
+/// stream.finish(&mut output).unwrap();
+/// assert_eq!("负一亿零二百零三万零四百零五", output);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DigitStream {
+    scale: Scale,
+    case: Case,
+    variant: Variant,
+    /// Number of digits yet to be pushed, which is also the exponent of the next digit plus one.
+    remaining: usize,
+    group: u16,
+    neg: bool,
+    started: bool,
+    emitted: bool,
+    /// Whether a zero group follows the last characters emitted.
+    gap: bool,
+    /// Exponent of the last nonzero group.
+    last_nonzero: Option<usize>,
+}
+
+impl DigitStream {
+    /// Creates a formatter for a number of `len` digits in the scale, counting leading zeros but not the sign.
+    pub fn new(scale: Scale, len: usize, case: Case, variant: Variant) -> Self {
+        Self {
+            scale,
+            case,
+            variant,
+            remaining: len,
+            group: 0,
+            neg: false,
+            started: false,
+            emitted: false,
+            gap: false,
+            last_nonzero: None,
+        }
+    }
+
+    /// Number of digits in a group, read as a whole with units.
+    fn group_len(&self) -> usize {
+        match self.scale {
+            Scale::Short => 1,
+            _ => 4,
+        }
+    }
+
+    /// Number of digits that can be expressed in the scale.
+    fn max_len(&self) -> usize {
+        self.scale.max_exp() as usize
+    }
+
+    /// Returns the unit read after the group at the exponent, and the number of digits it applies to.
+    fn unit(&self, exp: usize) -> (NumChar, usize) {
+        let groups = exp / 4;
+        match self.scale {
+            Scale::Short => (NUM_CHARS[9 + exp], 1),
+            Scale::Myriad => (NUM_CHARS[12 + groups], 4),
+            Scale::Mid if groups % 2 == 1 => (NumChar::Wan, 4),
+            Scale::Mid => (NUM_CHARS[13 + groups / 2], 8),
+            Scale::Long => {
+                let level = groups.trailing_zeros() as usize;
+                (NUM_CHARS[13 + level], 4 << level)
+            }
+        }
+    }
+
+    /// Pushes the next digit, or a leading `-`, and writes the characters of the group it completes.
+    ///
+    /// Returns [`ParseError::InvalidChar`] for other characters, and [`ParseError::Overflow`] for digits more than declared or nonzero digits out of range of the scale.
+    pub fn push(&mut self, ch: char, output: &mut String) -> Result<(), Error> {
+        if ch == '-' && !self.started && !self.neg {
+            self.neg = true;
+            return Ok(());
+        }
+        let digit = ch.to_digit(10).ok_or(ParseError::InvalidChar(ch))?;
+        if self.remaining == 0 {
+            return Err(ParseError::Overflow.into());
+        }
+        self.started = true;
+        self.remaining -= 1;
+        let exp = self.remaining;
+        if digit != 0 && exp >= self.max_len() {
+            return Err(ParseError::Overflow.into());
+        }
+        self.group = self.group * 10 + digit as u16;
+        if exp.is_multiple_of(self.group_len()) {
+            self.complete_group(exp, output);
+        }
+        Ok(())
+    }
+
+    /// Writes the characters of the group ending at the exponent, in reversed order first.
+    fn complete_group(&mut self, exp: usize, output: &mut String) {
+        let value = std::mem::take(&mut self.group);
+        let mut chars = Vec::new();
+        if exp > 0 {
+            let (unit, len) = self.unit(exp);
+            let nonzero = value > 0 || self.last_nonzero.is_some_and(|last| last < exp + len);
+            if nonzero {
+                chars.push(unit);
+            }
+        }
+        if value > 0 {
+            chars.extend_from_slice(GROUPS[value as usize].chars());
+            let leading_zero = (value as usize) < 10_usize.pow(self.group_len() as u32 - 1);
+            if self.emitted && (self.gap || leading_zero) {
+                chars.push(NumChar::Zero);
+            }
+            self.last_nonzero = Some(exp);
+        }
+        // zeros before a unit are read within the unit
+        self.gap = chars.is_empty() && (self.gap || self.emitted);
+        self.write(chars, output);
+    }
+
+    /// Writes characters in reversed order, with the sign and the trimmed 一 before them at the start.
+    fn write(&mut self, mut chars: Vec<NumChar>, output: &mut String) {
+        if chars.is_empty() {
+            return;
+        }
+        if !self.emitted {
+            if self.case == Case::Lower && chars.ends_with(&[NumChar::Shi, NumChar::One]) {
+                chars.pop();
+            }
+            if self.neg {
+                chars.push(NumChar::Neg);
+            }
+        }
+        omit_zeros_and_ones(&mut chars, self.case, self.variant);
+        for ch in chars.into_iter().rev() {
+            output.push_str(match self.case {
+                Case::Lower => ch.to_lowercase(self.variant),
+                Case::Upper => ch.to_uppercase(self.variant),
+            });
+        }
+        self.emitted = true;
+    }
+
+    /// Finishes the number, writing 零 if nothing has been written.
+    ///
+    /// Returns [`ParseError::Empty`] if fewer digits than declared have been pushed.
+    pub fn finish(mut self, output: &mut String) -> Result<(), Error> {
+        if self.remaining > 0 {
+            return Err(ParseError::Empty.into());
+        }
+        if !self.emitted {
+            self.neg = false;
+            self.write(vec![NumChar::Zero], output);
+        }
+        Ok(())
+    }
+}
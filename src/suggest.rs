@@ -0,0 +1,93 @@
+use crate::{convert_str, format_digits, format_money, parse_cents, Case, Options, Scale, Variant};
+
+/// Styles of candidates generated by [`suggest`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CandidateStyle {
+    /// Lowercase numeral, e.g. 一百二十三.
+    Lowercase,
+    /// Uppercase numeral, e.g. 壹佰贰拾叁.
+    Uppercase,
+    /// Digits read one by one, e.g. 一二三.
+    Digits,
+    /// Amount of money in yuan, e.g. 壹佰贰拾叁元整.
+    Money,
+}
+
+/// A candidate rendering of the input, see [`suggest`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Candidate {
+    /// Style of the candidate.
+    pub style: CandidateStyle,
+    /// Text of the candidate.
+    pub text: String,
+}
+
+/// Options of generating candidates, see [`suggest`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct SuggestOptions {
+    /// Scale of the numerals. Defaults to [`Scale::Myriad`].
+    pub scale: Scale,
+    /// Chinese variant of the candidates. Defaults to [`Variant::Simplified`].
+    pub variant: Variant,
+    /// Styles of candidates, in order of preference. Styles not listed are not generated. Defaults to all styles in declaration order.
+    pub preference: Vec<CandidateStyle>,
+}
+
+impl Default for SuggestOptions {
+    fn default() -> Self {
+        Self {
+            scale: Scale::Myriad,
+            variant: Variant::Simplified,
+            preference: vec![
+                CandidateStyle::Lowercase,
+                CandidateStyle::Uppercase,
+                CandidateStyle::Digits,
+                CandidateStyle::Money,
+            ],
+        }
+    }
+}
+
+/// Generates candidate Chinese renderings of an Arabic input, ranked by [`SuggestOptions::preference`], for input methods and autocompletion.
+///
+/// Styles that cannot render the input are skipped, as are candidates identical to a preferred one.
+///
+/// ```
+/// use chinese_numerals::{suggest, SuggestOptions};
+///
+/// let candidates: Vec<_> = suggest("123", &SuggestOptions::default())
+///     .into_iter()
+///     .map(|candidate| candidate.text)
+///     .collect();
+/// assert_eq!(vec!["一百二十三", "壹佰贰拾叁", "一二三", "壹佰贰拾叁元整"], candidates);
+/// ```
+pub fn suggest(input: &str, options: &SuggestOptions) -> Vec<Candidate> {
+    let mut candidates: Vec<Candidate> = Vec::new();
+    for &style in &options.preference {
+        let text = match style {
+            CandidateStyle::Lowercase | CandidateStyle::Uppercase => {
+                let case = if style == CandidateStyle::Lowercase {
+                    Case::Lower
+                } else {
+                    Case::Upper
+                };
+                let numeral_options = Options {
+                    case,
+                    variant: options.variant,
+                    ..Default::default()
+                };
+                convert_str(input, options.scale, &numeral_options).ok()
+            }
+            CandidateStyle::Digits => format_digits(input, Case::Lower, options.variant).ok(),
+            CandidateStyle::Money => parse_cents(input)
+                .ok()
+                .map(|cents| format_money(cents, options.variant)),
+        };
+        if let Some(text) = text {
+            if candidates.iter().all(|candidate| candidate.text != text) {
+                candidates.push(Candidate { style, text });
+            }
+        }
+    }
+    candidates
+}
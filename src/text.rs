@@ -0,0 +1,279 @@
+use crate::{
+    characters::*, convert_str, format_digits, format_year, parse::parse_numeral, Options, Scale,
+};
+
+/// Kinds of Arabic numbers found in text.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Kind {
+    /// A plain number, e.g. `-12` or `3.14`.
+    Number,
+    /// A percentage, e.g. `35%`.
+    Percent,
+    /// A four-digit year followed by 年, e.g. `2024年`.
+    Year,
+}
+
+/// An Arabic number found in text.
+struct Number {
+    neg: bool,
+    int: String,
+    frac: String,
+    kind: Kind,
+}
+
+/// Scans an Arabic number at the start of the text, returning it with the number of bytes it takes.
+///
+/// Numbers that are part of a word, a code, or a version are skipped, e.g. `MP3`, `A4`, or `1.2.3`.
+fn scan(s: &str, prev: Option<char>) -> Option<(Number, usize)> {
+    if prev.is_some_and(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '.') {
+        return None;
+    }
+    let bytes = s.as_bytes();
+    let digits_from = |start: usize| {
+        bytes[start.min(bytes.len())..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+
+    let neg = bytes.first() == Some(&b'-');
+    let mut pos = neg as usize;
+    let len = digits_from(pos);
+    if len == 0 {
+        return None;
+    }
+    let mut int = s[pos..pos + len].to_owned();
+    pos += len;
+    let mut grouped = false;
+    // thousands separators, e.g. 1,234,567
+    while len <= 3 && bytes.get(pos) == Some(&b',') && digits_from(pos + 1) == 3 {
+        int.push_str(&s[pos + 1..pos + 4]);
+        pos += 4;
+        grouped = true;
+    }
+    let mut frac = String::new();
+    if bytes.get(pos) == Some(&b'.') && digits_from(pos + 1) > 0 {
+        let len = digits_from(pos + 1);
+        frac.push_str(&s[pos + 1..pos + 1 + len]);
+        pos += 1 + len;
+    }
+    match bytes.get(pos) {
+        Some(b) if b.is_ascii_alphabetic() || *b == b'_' => return None,
+        Some(b'.') if digits_from(pos + 1) > 0 => return None,
+        _ => {}
+    }
+
+    let kind = if bytes.get(pos) == Some(&b'%') {
+        pos += 1;
+        Kind::Percent
+    } else if s[pos..].starts_with('年') && int.len() == 4 && !(neg || grouped) && frac.is_empty()
+    {
+        pos += '年'.len_utf8();
+        Kind::Year
+    } else {
+        Kind::Number
+    };
+    Some((
+        Number {
+            neg,
+            int,
+            frac,
+            kind,
+        },
+        pos,
+    ))
+}
+
+/// Renders the absolute value of a number, or returns `None` if it is out of range.
+fn render_abs(number: &Number, options: &Options, output: &mut String) -> Option<()> {
+    if number.int.len() > 1 && number.int.starts_with('0') && number.frac.is_empty() {
+        // reads codes with leading zeros digit by digit, e.g. 零零七
+        output.push_str(&format_digits(&number.int, options.case, options.variant).ok()?);
+        return Some(());
+    }
+    output.push_str(&convert_str(&number.int, Scale::Myriad, options).ok()?);
+    if !number.frac.is_empty() {
+        options.render_char(NumChar::Point, output);
+        for digit in number.frac.bytes() {
+            options.render_char(NUM_CHARS[(digit - b'0') as usize], output);
+        }
+    }
+    Some(())
+}
+
+/// Renders a number found in text, or returns `None` if it is out of range.
+fn render(number: &Number, options: &Options) -> Option<String> {
+    let mut output = String::new();
+    if number.kind == Kind::Year {
+        return Some(format_year(number.int.parse().ok()?));
+    }
+    if number.neg && number.int.trim_start_matches('0').len() + number.frac.len() > 0 {
+        options.render_char(NumChar::Neg, &mut output);
+    }
+    if number.kind == Kind::Percent {
+        output.push_str("百分之");
+    }
+    render_abs(number, options, &mut output)?;
+    Some(output)
+}
+
+/// Replaces the Arabic numbers in text with Chinese numerals in myriad scale, e.g. for localizing documents.
+///
+/// Digits after a decimal point are read one by one, percentages are read with 百分之, four-digit years followed by 年 are read digit by digit, and numbers with leading zeros, such as codes, are read digit by digit. Thousands separators are recognized, and a leading `-` is read as the negative sign. Numbers that are part of a word, a code, or a version, e.g. `MP3` or `1.2.3`, and numbers out of range are kept.
+///
+/// ```
+/// use chinese_numerals::{convert_numbers_in_text, Options};
+///
+/// assert_eq!(
+///     "二〇二四年营收增长百分之十二点五，达到三千四百五十六万元。",
+///     convert_numbers_in_text("2024年营收增长12.5%，达到34,560,000元。", &Options::default())
+/// );
+/// assert_eq!(
+///     "第三章：MP3 格式，版本 1.2.3，气温负五度",
+///     convert_numbers_in_text("第3章：MP3 格式，版本 1.2.3，气温-5度", &Options::default())
+/// );
+/// ```
+pub fn convert_numbers_in_text(input: &str, options: &Options) -> String {
+    let mut output = String::with_capacity(input.len() * 2);
+    let mut rest = input;
+    let mut prev = None;
+    while let Some(ch) = rest.chars().next() {
+        if ch.is_ascii_digit() || ch == '-' {
+            if let Some((number, len)) = scan(rest, prev) {
+                if let Some(rendered) = render(&number, options) {
+                    output.push_str(&rendered);
+                    prev = rest[..len].chars().next_back();
+                    rest = &rest[len..];
+                    continue;
+                }
+            }
+        }
+        output.push(ch);
+        prev = Some(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+    output
+}
+
+/// Returns the digit of a character in a numeral, Chinese or Arabic.
+fn digit_of(ch: char) -> Option<u32> {
+    match classify(ch) {
+        Some(num) if (num as usize) <= NumChar::Nine as usize => Some(num as u32),
+        _ => ch.to_digit(10),
+    }
+}
+
+/// Whether the character can be in the integer part of a Chinese numeral, i.e. a digit or a unit.
+fn is_numeral_char(ch: char) -> bool {
+    digit_of(ch).is_some() || classify(ch).is_some_and(|num| num as usize <= NumChar::Zai as usize)
+}
+
+/// Converts a run of numeral characters to Arabic digits, or returns `None` if it cannot be parsed.
+///
+/// Runs without units, e.g. 二〇二四, are read digit by digit, and other runs are parsed as numerals in the scale.
+fn to_arabic(run: &[char], scale: Scale) -> Option<String> {
+    let neg = classify(run[0]) == Some(NumChar::Neg);
+    let run = &run[neg as usize..];
+    let point = run
+        .iter()
+        .position(|&ch| classify(ch) == Some(NumChar::Point));
+    let (int, frac) = match point {
+        Some(pos) if pos + 1 < run.len() => (&run[..pos], &run[pos + 1..]),
+        Some(_) => return None,
+        None => (run, &[][..]),
+    };
+
+    let mut arabic = String::new();
+    if int.iter().all(|&ch| digit_of(ch).is_some()) {
+        arabic.extend(
+            int.iter()
+                .filter_map(|&ch| char::from_digit(digit_of(ch)?, 10)),
+        );
+    } else {
+        let int: String = int
+            .iter()
+            .map(|&ch| match ch {
+                '〇' => '零',
+                '两' | '兩' => '二',
+                _ => ch,
+            })
+            .collect();
+        arabic = parse_numeral(&int, scale).ok()?.1.to_string();
+    }
+    if !frac.is_empty() {
+        arabic.push('.');
+        arabic.extend(
+            frac.iter()
+                .filter_map(|&ch| char::from_digit(digit_of(ch)?, 10)),
+        );
+    }
+    if neg && arabic.trim_start_matches(['0', '.']).is_empty() {
+        return Some(arabic);
+    }
+    Some(if neg { format!("-{arabic}") } else { arabic })
+}
+
+/// Replaces the Chinese numerals in text with Arabic numbers, e.g. for normalizing text before indexing and search.
+///
+/// Numerals in all cases and variants are recognized, including mixed ones like 3万5千, negative numerals with 负, and decimals with 点. Runs of digits without units, e.g. 二〇二四, are read digit by digit. As the scan is lexical, words containing numeral characters, such as 一些 or 万一, are converted as well, and numerals out of range are kept.
+///
+/// ```
+/// use chinese_numerals::{convert_numerals_in_text, Scale};
+///
+/// assert_eq!(
+///     "2024年营收增长12.5%，达到35000元，亏损-300000000元",
+///     convert_numerals_in_text("二〇二四年营收增长12.5%，达到3万5千元，亏损负三亿元", Scale::Myriad)
+/// );
+/// assert_eq!("第10章，共1200000字", convert_numerals_in_text("第十章，共壹佰贰拾萬字", Scale::Myriad));
+/// ```
+pub fn convert_numerals_in_text(input: &str, scale: Scale) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut output = String::with_capacity(input.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let sign = (classify(chars[i]) == Some(NumChar::Neg)) as usize;
+        let starts = chars
+            .get(i + sign)
+            .is_some_and(|&ch| digit_of(ch).is_some() || classify(ch) == Some(NumChar::Shi));
+        if !starts {
+            output.push(chars[i]);
+            i += 1;
+            continue;
+        }
+
+        let mut end = i + sign;
+        while end < chars.len() && is_numeral_char(chars[end]) {
+            end += 1;
+        }
+        if end + 1 < chars.len()
+            && classify(chars[end]) == Some(NumChar::Point)
+            && digit_of(chars[end + 1]).is_some()
+        {
+            end += 1;
+            while end < chars.len() && digit_of(chars[end]).is_some() {
+                end += 1;
+            }
+        }
+        let run = &chars[i..end];
+        if run.iter().all(|ch| ch.is_ascii()) {
+            output.extend(run);
+            i = end;
+            continue;
+        }
+        // keeps the longest prefix that can be parsed
+        match (i + sign + 1..=end)
+            .rev()
+            .find_map(|end| Some((to_arabic(&chars[i..end], scale)?, end)))
+        {
+            Some((arabic, end)) => {
+                output.push_str(&arabic);
+                i = end;
+            }
+            None => {
+                output.push(chars[i]);
+                i += 1;
+            }
+        }
+    }
+    output
+}
@@ -0,0 +1,97 @@
+use crate::{
+    convert_str, parse::parse_numeral, Case, ErrorKind, NumChar, Options, ParseError, Scale,
+    Variant,
+};
+
+/// Errors of validating Chinese numerals, see [`validate`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum ValidationError {
+    /// The string cannot be read as a numeral.
+    Parse(ParseError),
+    /// The number is out of range of the scale.
+    OutOfRange,
+    /// The string is read as a number, but not written in its well-formed expression, which is given.
+    Malformed(String),
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::Parse(err) => write!(f, "{err}"),
+            ValidationError::OutOfRange => write!(f, "Number out of range of the scale"),
+            ValidationError::Malformed(expected) => {
+                write!(f, "Malformed numeral, expected {expected:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl From<ParseError> for ValidationError {
+    fn from(err: ParseError) -> Self {
+        ValidationError::Parse(err)
+    }
+}
+
+const STYLES: [(Case, Variant); 4] = [
+    (Case::Lower, Variant::Simplified),
+    (Case::Upper, Variant::Simplified),
+    (Case::Lower, Variant::Traditional),
+    (Case::Upper, Variant::Traditional),
+];
+
+/// Checks whether a string is a well-formed Chinese numeral in the given scale.
+///
+/// A numeral is well-formed if it is exactly what this crate renders for its value, in lowercase or uppercase, simplified or traditional Chinese. Otherwise, [`ValidationError::Malformed`] gives the expected expression in the style the string seems to be written in.
+///
+/// ```
+/// use chinese_numerals::{validate, Scale, ValidationError};
+///
+/// assert_eq!(Ok(()), validate("一亿零二百万", Scale::Myriad));
+/// assert_eq!(Ok(()), validate("壹拾萬", Scale::Myriad));
+/// assert_eq!(
+///     Err(ValidationError::Malformed("一亿零二百万".to_owned())),
+///     validate("一亿二百万", Scale::Myriad)
+/// );
+/// assert_eq!(Err(ValidationError::OutOfRange), validate("一万载", Scale::Short));
+/// ```
+pub fn validate(s: &str, scale: Scale) -> Result<(), ValidationError> {
+    let (neg, abs) = parse_numeral(s, scale)?;
+    let value = if neg {
+        format!("-{abs}")
+    } else {
+        abs.to_string()
+    };
+
+    // styles in which all characters of the string can be written
+    let mut styles = STYLES.iter().filter(|(case, variant)| {
+        s.chars().all(|ch| {
+            NumChar::from_char(ch).is_some_and(|num| {
+                let glyph = match case {
+                    Case::Lower => num.to_lowercase(*variant),
+                    Case::Upper => num.to_uppercase(*variant),
+                };
+                glyph == ch.encode_utf8(&mut [0; 4])
+            })
+        })
+    });
+    let (case, variant) = *styles.clone().next().unwrap_or(&STYLES[0]);
+    let render = |case, variant| {
+        let options = Options {
+            case,
+            variant,
+            ..Default::default()
+        };
+        convert_str(&value, scale, &options).map_err(|err| match err.kind() {
+            ErrorKind::Parse(err) => ValidationError::Parse(err.clone()),
+            _ => ValidationError::OutOfRange,
+        })
+    };
+
+    if styles.any(|&(case, variant)| render(case, variant).as_deref() == Ok(s)) {
+        Ok(())
+    } else {
+        Err(ValidationError::Malformed(render(case, variant)?))
+    }
+}
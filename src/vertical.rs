@@ -0,0 +1,100 @@
+//! Layout of numerals in vertical writing.
+
+/// Options of laying out text in vertical writing, see [`to_vertical`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct VerticalLayout {
+    /// Maximum number of cells in a column, or [`None`] for a single column. Defaults to [`None`].
+    pub column_height: Option<usize>,
+    /// Maximum number of consecutive ASCII digits set horizontally in one cell (縦中横, tate-chu-yoko), e.g. 2 for `12` in one cell. Digits are not grouped if it is 0 or 1. Defaults to 2.
+    pub group_digits: usize,
+    /// Whether to convert punctuation to the presentation forms for vertical writing, e.g. （ to ︵. Defaults to `true`.
+    pub presentation_forms: bool,
+}
+
+impl Default for VerticalLayout {
+    fn default() -> Self {
+        Self {
+            column_height: None,
+            group_digits: 2,
+            presentation_forms: true,
+        }
+    }
+}
+
+/// Converts a character to its presentation form for vertical writing, e.g. （ to ︵, or returns it unchanged.
+///
+/// ```
+/// use chinese_numerals::vertical_form;
+///
+/// assert_eq!('︵', vertical_form('（'));
+/// assert_eq!('一', vertical_form('一'));
+/// ```
+pub fn vertical_form(ch: char) -> char {
+    match ch {
+        '，' => '︐',
+        '、' => '︑',
+        '。' => '︒',
+        '：' => '︓',
+        '；' => '︔',
+        '！' => '︕',
+        '？' => '︖',
+        '…' => '︙',
+        '—' => '︱',
+        '（' | '(' => '︵',
+        '）' | ')' => '︶',
+        '｛' => '︷',
+        '｝' => '︸',
+        '〔' => '︹',
+        '〕' => '︺',
+        '【' => '︻',
+        '】' => '︼',
+        '《' => '︽',
+        '》' => '︾',
+        '〈' => '︿',
+        '〉' => '﹀',
+        '「' => '﹁',
+        '」' => '﹂',
+        '『' => '﹃',
+        '』' => '﹄',
+        _ => ch,
+    }
+}
+
+/// Lays out text in vertical writing, returning the columns in reading order, each of which is a list of cells from top to bottom.
+///
+/// ```
+/// use chinese_numerals::{to_vertical, VerticalLayout};
+///
+/// let layout = VerticalLayout {
+///     column_height: Some(3),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     vec![vec!["一", "万", "二"], vec!["千", "︵", "12"], vec!["千", "︶"]],
+///     to_vertical("一万二千（12千）", &layout)
+/// );
+/// ```
+pub fn to_vertical(text: &str, layout: &VerticalLayout) -> Vec<Vec<String>> {
+    let mut cells: Vec<String> = Vec::new();
+    let mut digits = 0;
+    for ch in text.chars() {
+        if ch.is_ascii_digit() && digits > 0 && digits < layout.group_digits {
+            cells.last_mut().unwrap().push(ch);
+            digits += 1;
+            continue;
+        }
+        digits = usize::from(ch.is_ascii_digit());
+        let ch = if layout.presentation_forms {
+            vertical_form(ch)
+        } else {
+            ch
+        };
+        cells.push(ch.to_string());
+    }
+
+    match layout.column_height {
+        Some(height) if height > 0 => cells.chunks(height).map(<[String]>::to_vec).collect(),
+        _ if cells.is_empty() => Vec::new(),
+        _ => vec![cells],
+    }
+}
@@ -0,0 +1,72 @@
+//! Bindings for JavaScript with [`wasm_bindgen`].
+
+use crate::{convert_str, Case, Options, Scale, Variant, ZeroStyle};
+use js_sys::Reflect;
+use wasm_bindgen::prelude::*;
+
+/// Error message of an unknown `variant` option.
+const INVALID_VARIANT: &str = concat!(
+    "Option `variant` should be \"simplified\", \"traditional\", ",
+    "\"japanese\", \"korean\", or \"vietnamese\"",
+);
+
+/// Gets a field of the options object, if it is defined.
+fn field(options: &JsValue, key: &str) -> Option<JsValue> {
+    if !options.is_object() {
+        return None;
+    }
+    Reflect::get(options, &JsValue::from_str(key))
+        .ok()
+        .filter(|value| !value.is_undefined() && !value.is_null())
+}
+
+/// Reads options from an object like `{ case: "upper", variant: "traditional", positiveSign: true, zeroStyle: "empty" }`, where all fields are optional.
+fn parse_options(options: &JsValue) -> Result<Options, JsError> {
+    let mut parsed = Options::default();
+    if let Some(case) = field(options, "case") {
+        parsed.case = match case.as_string().as_deref() {
+            Some("lower") => Case::Lower,
+            Some("upper") => Case::Upper,
+            _ => {
+                return Err(JsError::new(
+                    "Option `case` should be \"lower\" or \"upper\"",
+                ))
+            }
+        };
+    }
+    if let Some(variant) = field(options, "variant") {
+        parsed.variant = match variant.as_string().as_deref() {
+            Some("simplified") => Variant::Simplified,
+            Some("traditional") => Variant::Traditional,
+            Some("japanese") => Variant::Japanese,
+            Some("korean") => Variant::Korean,
+            Some("vietnamese") => Variant::Vietnamese,
+            _ => return Err(JsError::new(INVALID_VARIANT)),
+        };
+    }
+    if let Some(positive_sign) = field(options, "positiveSign") {
+        parsed.positive_sign = positive_sign
+            .as_bool()
+            .ok_or_else(|| JsError::new("Option `positiveSign` should be a boolean"))?;
+    }
+    if let Some(zero_style) = field(options, "zeroStyle") {
+        parsed.zero_style = match zero_style.as_string().as_deref() {
+            Some("ling") => ZeroStyle::Ling,
+            Some("empty") => ZeroStyle::Empty,
+            _ => {
+                return Err(JsError::new(
+                    "Option `zeroStyle` should be \"ling\" or \"empty\"",
+                ))
+            }
+        };
+    }
+    Ok(parsed)
+}
+
+/// Converts a decimal string to Chinese numerals in the given scale, see [`convert_str`].
+#[wasm_bindgen(js_name = toChinese)]
+pub fn to_chinese(value: &str, scale: &str, options: JsValue) -> Result<String, JsError> {
+    let scale: Scale = scale.parse()?;
+    let options = parse_options(&options)?;
+    Ok(convert_str(value, scale, &options)?)
+}
@@ -2,6 +2,8 @@
 fn short() {
     use chinese_numerals::ShortScaleInt;
 
+    let _defaults = shared_defaults();
+
     let num = ShortScaleInt::try_from(-1_0023_0456_7890_i64).unwrap();
     assert_eq!(
         "负一涧零二秭三垓零四兆五亿六万七千八百九十",
@@ -60,6 +62,8 @@ fn short() {
 fn myriad() {
     use chinese_numerals::MyriadScaleInt;
 
+    let _defaults = shared_defaults();
+
     let num = MyriadScaleInt::from(-1_0023_0456_7890_i64);
     assert_eq!(
         "负一兆零二十三亿零四百五十六万七千八百九十",
@@ -139,6 +143,8 @@ fn big_myriad() {
     use chinese_numerals::MyriadScaleBigInt;
     use num_bigint::{BigInt, Sign};
 
+    let _defaults = shared_defaults();
+
     // -1_0023_0456_7890_0987_6540_3200_1001_0023_0456_7890_0000
     let num = BigInt::new(
         Sign::Minus,
@@ -184,6 +190,8 @@ fn big_myriad() {
 fn mid() {
     use chinese_numerals::MidScaleInt;
 
+    let _defaults = shared_defaults();
+
     let num = MidScaleInt::from(-10_0023_0456_7890_i64);
     assert_eq!(
         "负十万零二十三亿零四百五十六万七千八百九十",
@@ -255,6 +263,8 @@ fn big_mid() {
     use chinese_numerals::MidScaleBigInt;
     use num_bigint::{BigInt, Sign};
 
+    let _defaults = shared_defaults();
+
     // -1_0023_0456_7890_0987_6540_3200_1001_0023_0456_7890_0000
     let num = BigInt::new(
         Sign::Minus,
@@ -308,6 +318,8 @@ fn big_mid() {
 fn long() {
     use chinese_numerals::LongScaleInt;
 
+    let _defaults = shared_defaults();
+
     let num = LongScaleInt::from(-10_0023_0456_7890_9876_5432_1023_i128);
     assert_eq!(
         "负十亿零二十三万零四百五十六兆七千八百九十万九千八百七十六亿五千四百三十二万一千零二十三",
@@ -379,6 +391,8 @@ fn big_long() {
     use chinese_numerals::LongScaleBigInt;
     use num_bigint::{BigInt, Sign};
 
+    let _defaults = shared_defaults();
+
     // -1_0023_0456_7890_0987_6540_3200_1001_0023_0456_7890_0000
     let num = BigInt::new(
         Sign::Minus,
@@ -812,3 +826,3659 @@ fn big_long() {
         format!("{}", LongScaleBigInt::max_value())
     );
 }
+
+#[test]
+fn range() {
+    use chinese_numerals::{format_range, MidScaleInt, MyriadScaleInt, RangeStyle, Variant};
+
+    let range = |start: i32, end: i32, style| {
+        format_range(
+            &MyriadScaleInt::from(start),
+            &MyriadScaleInt::from(end),
+            style,
+            Variant::Simplified,
+        )
+    };
+    assert_eq!("三万至五万", range(3_0000, 5_0000, RangeStyle::Full));
+    assert_eq!("三到五万", range(3_0000, 5_0000, RangeStyle::Compressed));
+    assert_eq!(
+        "三十到五十万",
+        range(30_0000, 50_0000, RangeStyle::Compressed)
+    );
+    assert_eq!(
+        "十到二十万",
+        range(10_0000, 20_0000, RangeStyle::Compressed)
+    );
+    assert_eq!("三十到五十", range(30, 50, RangeStyle::Compressed));
+    assert_eq!(
+        "三万五千到五万",
+        range(3_5000, 5_0000, RangeStyle::Compressed)
+    );
+    assert_eq!(
+        "三亿到五万亿",
+        format_range(
+            &MidScaleInt::from(3_0000_0000),
+            &MidScaleInt::from(5_0000_0000_0000_i64),
+            RangeStyle::Compressed,
+            Variant::Simplified,
+        )
+    );
+    assert_eq!(
+        "负五万到负三万",
+        range(-5_0000, -3_0000, RangeStyle::Compressed)
+    );
+    assert_eq!("零到五万", range(0, 5_0000, RangeStyle::Compressed));
+    assert_eq!(
+        "三到五萬",
+        format_range(
+            &MyriadScaleInt::from(3_0000),
+            &MyriadScaleInt::from(5_0000),
+            RangeStyle::Compressed,
+            Variant::Traditional,
+        )
+    );
+}
+
+#[test]
+fn scale() {
+    use chinese_numerals::Scale;
+
+    assert_eq!("下数", Scale::Short.name_zh());
+    assert_eq!("mid-scale", Scale::Mid.name_en());
+    assert_eq!("以万进者，万万曰亿，万亿曰兆。", Scale::Myriad.citation());
+    assert!(Scale::Long.citation().starts_with("上数者"));
+}
+
+#[test]
+fn mixed() {
+    use chinese_numerals::{format_mixed, Bracket, Case, MixedStyle, MyriadScaleInt, Variant};
+
+    let style = MixedStyle::default();
+    assert_eq!(
+        "-1,2345（负一万二千三百四十五）",
+        format_mixed(&MyriadScaleInt::from(-1_2345), &style)
+    );
+    assert_eq!("0（零）", format_mixed(&MyriadScaleInt::default(), &style));
+    assert_eq!(
+        "1234（一千二百三十四）",
+        format_mixed(&MyriadScaleInt::from(1234), &style)
+    );
+
+    let style = MixedStyle {
+        separator: "",
+        bracket: Bracket::Lenticular,
+        case: Case::Upper,
+        variant: Variant::Traditional,
+        ..Default::default()
+    };
+    assert_eq!(
+        "120000【壹拾貳萬】",
+        format_mixed(&MyriadScaleInt::from(12_0000), &style)
+    );
+}
+
+#[test]
+fn fraction() {
+    use chinese_numerals::{format_fraction, to_percentage, PercentStyle, Variant};
+
+    let percent = |ratio: f64, style| to_percentage(ratio, style, Variant::Simplified).unwrap();
+    assert_eq!("百分之三十五", percent(0.35, PercentStyle::Percent));
+    assert_eq!("百分之零点五", percent(0.005, PercentStyle::Percent));
+    assert_eq!("百分之一百二十", percent(1.2, PercentStyle::Percent));
+    assert_eq!("负百分之五", percent(-0.05, PercentStyle::Percent));
+    assert_eq!("百分之零", percent(0.0, PercentStyle::Percent));
+    assert_eq!("三成五", percent(0.35, PercentStyle::Cheng));
+    assert_eq!("三成", percent(0.3, PercentStyle::Cheng));
+    assert_eq!("十成", percent(1.0, PercentStyle::Cheng));
+    assert_eq!("百分之五", percent(0.05, PercentStyle::Cheng));
+    assert_eq!("一成三", percent(0.125, PercentStyle::Cheng));
+    assert_eq!("负三成五", percent(-0.35, PercentStyle::Cheng));
+    assert_eq!("百分之零", percent(-1e-9, PercentStyle::Percent));
+    assert_eq!("百分之零", percent(-1e-9, PercentStyle::Cheng));
+    assert_eq!(
+        Some("百分之三十三點三三三三三三".to_owned()),
+        to_percentage((1, 3), PercentStyle::Percent, Variant::Traditional)
+    );
+    assert_eq!(
+        None,
+        to_percentage(f64::NAN, PercentStyle::Percent, Variant::Simplified)
+    );
+
+    assert_eq!(
+        Some("一百分之十三".to_owned()),
+        format_fraction(13, 100, Variant::Simplified)
+    );
+    assert_eq!(
+        Some("二分之零".to_owned()),
+        format_fraction(0, 2, Variant::Simplified)
+    );
+    assert_eq!(None, format_fraction(1, 0, Variant::Simplified));
+}
+
+#[test]
+fn options() {
+    use chinese_numerals::{
+        Case, ChineseNumeral, MidScaleInt, Options, ShortScaleInt, Variant, ZeroStyle,
+    };
+
+    let num = MidScaleInt::from(1_0203_0405);
+    assert_eq!(num.to_lowercase_simp(), num.to_chinese(&Options::default()));
+    let mut options = Options::default();
+    options.case = Case::Upper;
+    options.variant = Variant::Traditional;
+    assert_eq!(num.to_uppercase_trad(), num.to_chinese(&options));
+
+    let mut options = Options::default();
+    options.positive_sign = true;
+    assert_eq!("正十三", ShortScaleInt::from(13).to_chinese(&options));
+    assert_eq!("负十三", ShortScaleInt::from(-13).to_chinese(&options));
+    assert_eq!("零", ShortScaleInt::from(0).to_chinese(&options));
+
+    let mut options = Options::default();
+    options.case = Case::Upper;
+    options.positive_sign = true;
+    options.zero_style = ZeroStyle::Empty;
+    assert_eq!("正壹拾叁", ShortScaleInt::from(13).to_chinese(&options));
+    assert_eq!("", ShortScaleInt::from(0).to_chinese(&options));
+}
+
+#[test]
+fn year() {
+    use chinese_numerals::{
+        expand_two_digit_year, format_two_digit_year, format_year, parse_two_digit_year,
+    };
+
+    assert_eq!("一九四九年", format_year(1949));
+    assert_eq!("〇年", format_year(0));
+    assert_eq!("〇〇年", format_two_digit_year(2000));
+    assert_eq!("四九年", format_two_digit_year(1949));
+
+    assert_eq!(Some(2049), expand_two_digit_year(49, 1950));
+    assert_eq!(Some(1950), expand_two_digit_year(50, 1950));
+    assert_eq!(Some(-50), expand_two_digit_year(50, -99));
+    assert_eq!(None, expand_two_digit_year(100, 1950));
+
+    assert_eq!(Some(2024), parse_two_digit_year("二四年", 1950));
+    assert_eq!(Some(1999), parse_two_digit_year("九九", 1950));
+    assert_eq!(Some(2024), parse_two_digit_year("貳肆年", 1950));
+    assert_eq!(Some(2005), parse_two_digit_year("05年", 1950));
+    assert_eq!(None, parse_two_digit_year("二〇〇五年", 1950));
+    assert_eq!(None, parse_two_digit_year("五年", 1950));
+    assert_eq!(None, parse_two_digit_year("十五年", 1950));
+}
+
+#[test]
+fn from_str() {
+    use chinese_numerals::{
+        ErrorKind, LongScaleBigInt, MidScaleInt, MyriadScaleInt, ParseError, ShortScaleInt,
+    };
+
+    let _defaults = shared_defaults();
+
+    let num: MidScaleInt = "1_0203_0405".parse().unwrap();
+    assert_eq!(MidScaleInt::from(1_0203_0405), num);
+    let num: MyriadScaleInt = "-1,234".parse().unwrap();
+    assert_eq!(MyriadScaleInt::from(-1234), num);
+    let num: ShortScaleInt = "+42".parse().unwrap();
+    assert_eq!(ShortScaleInt::from(42), num);
+    let num: ShortScaleInt = "-0".parse().unwrap();
+    assert_eq!(ShortScaleInt::default(), num);
+
+    let num: MyriadScaleInt = "-340282366920938463463374607431768211455".parse().unwrap();
+    assert_eq!(MyriadScaleInt::new_non_pos(u128::MAX), num);
+
+    assert!(matches!(
+        "".parse::<MidScaleInt>().map_err(|err| err.kind().clone()),
+        Err(ErrorKind::Parse(ParseError::Empty))
+    ));
+    assert!(matches!(
+        "_1".parse::<MidScaleInt>()
+            .map_err(|err| err.kind().clone()),
+        Err(ErrorKind::Parse(ParseError::InvalidChar('_')))
+    ));
+    assert!(matches!(
+        "12a"
+            .parse::<MidScaleInt>()
+            .map_err(|err| err.kind().clone()),
+        Err(ErrorKind::Parse(ParseError::InvalidChar('a')))
+    ));
+    assert!(matches!(
+        "340282366920938463463374607431768211456"
+            .parse::<MidScaleInt>()
+            .map_err(|err| err.kind().clone()),
+        Err(ErrorKind::Parse(ParseError::Overflow))
+    ));
+    assert!(matches!(
+        "1000_0000_0000_0000"
+            .parse::<ShortScaleInt>()
+            .map_err(|err| err.kind().clone()),
+        Err(ErrorKind::OutOfRange)
+    ));
+
+    let num: LongScaleBigInt = "-130_5480_5271_5637_0597_2964".parse().unwrap();
+    assert_eq!(
+        "负一百三十万五千四百八十兆五千二百七十一万\
+        五千六百三十七亿零五百九十七万二千九百六十四",
+        format!("{}", num)
+    );
+}
+
+#[test]
+fn error_code() {
+    use chinese_numerals::{codes, Error, MidScaleBigInt, MidScaleInt, ParseError, ShortScaleInt};
+    use num_bigint::BigUint;
+
+    let err = ShortScaleInt::try_from(u128::MAX).unwrap_err();
+    assert_eq!(codes::SHORT_SCALE_OUT_OF_RANGE, err.code());
+    let err = MidScaleBigInt::try_from(BigUint::from_slice(&[u32::MAX; 11])).unwrap_err();
+    assert_eq!(codes::MID_SCALE_OUT_OF_RANGE, err.code());
+    let err = "1x".parse::<MidScaleInt>().unwrap_err();
+    assert_eq!(codes::PARSE_INVALID_CHAR, err.code());
+    assert_eq!(codes::PARSE_OVERFLOW, ParseError::Overflow.code());
+
+    assert_eq!(
+        r#"{"code":101,"message":"Cannot parse a number from an empty string"}"#,
+        serde_json::to_string(&Error::from(ParseError::Empty)).unwrap()
+    );
+    assert_eq!(
+        r#"{"code":1,"message":"Absolute value 10000000000000000 out of range for a short scale number, 2 orders of magnitude over, needing a unit beyond 载 (10^14)"}"#,
+        serde_json::to_string(&ShortScaleInt::try_from(1_0000_0000_0000_0000_u64).unwrap_err())
+            .unwrap()
+    );
+}
+
+#[test]
+fn digits() {
+    use chinese_numerals::{format_digits, Case, ParseError, Variant};
+
+    assert_eq!(
+        Ok("一二三四五".to_owned()),
+        format_digits("12345", Case::Lower, Variant::Simplified)
+    );
+    assert_eq!(
+        Ok("壹零零".to_owned()),
+        format_digits("100", Case::Upper, Variant::Traditional)
+    );
+    assert_eq!(
+        Err(ParseError::InvalidChar('-')),
+        format_digits("010-1234", Case::Lower, Variant::Simplified)
+    );
+    assert_eq!(
+        Err(ParseError::Empty),
+        format_digits("", Case::Lower, Variant::Simplified)
+    );
+}
+
+#[test]
+fn money() {
+    use chinese_numerals::{format_money, Variant};
+
+    assert_eq!("零元整", format_money(0, Variant::Simplified));
+    assert_eq!("伍分", format_money(5, Variant::Simplified));
+    assert_eq!("壹元伍角", format_money(1_50, Variant::Simplified));
+    assert_eq!("负贰万元整", format_money(-200_0000, Variant::Simplified));
+    assert_eq!(
+        "壹拾萬零叄佰元零肆分",
+        format_money(1003_0004, Variant::Traditional)
+    );
+}
+
+#[test]
+fn financial_style() {
+    use chinese_numerals::{format_money, format_money_with, FinancialStyle, Variant};
+
+    let default = FinancialStyle::default();
+    for cents in [0, 5, 1_50, 10_05, 168_032, 100_456, -200_0000] {
+        assert_eq!(
+            format_money(cents, Variant::Simplified),
+            format_money_with(cents, Variant::Simplified, &default)
+        );
+    }
+
+    let style = FinancialStyle {
+        zero_before_jiao: true,
+        ..Default::default()
+    };
+    let money = |cents| format_money_with(cents, Variant::Simplified, &style);
+    assert_eq!("壹仟陆佰捌拾元零叁角贰分", money(168_032));
+    assert_eq!("壹拾万柒仟元零伍角叁分", money(10_700_053));
+    assert_eq!("壹仟零肆元伍角陆分", money(100_456));
+    assert_eq!("壹拾元零伍分", money(10_05));
+    assert_eq!("伍角", money(50));
+
+    let style = FinancialStyle {
+        zheng_after_jiao: true,
+        zheng: '正',
+        ..Default::default()
+    };
+    let money = |cents| format_money_with(cents, Variant::Simplified, &style);
+    assert_eq!("壹元伍角正", money(1_50));
+    assert_eq!("壹元伍角叁分", money(1_53));
+    assert_eq!("壹拾元正", money(10_00));
+}
+
+#[test]
+fn middleware() {
+    use chinese_numerals::{ChineseNumeral, MidScaleInt, NumChar, Options, Token};
+    use std::sync::Arc;
+
+    // Uses 两 before 百, 千, and 万
+    let liang = |tokens: &mut Vec<Token>| {
+        for i in 1..tokens.len() {
+            if tokens[i - 1] == Token::Char(NumChar::Two)
+                && matches!(
+                    tokens[i],
+                    Token::Char(NumChar::Bai | NumChar::Qian | NumChar::Wan)
+                )
+            {
+                tokens[i - 1] = Token::Text("两".to_owned());
+            }
+        }
+    };
+    let spaces = |tokens: &mut Vec<Token>| {
+        let mut i = 1;
+        while i < tokens.len() {
+            tokens.insert(i, Token::Text(" ".to_owned()));
+            i += 2;
+        }
+    };
+
+    let num = MidScaleInt::from(2_2222);
+    let mut options = Options::default();
+    options.middlewares = vec![Arc::new(liang)];
+    assert_eq!("两万两千两百二十二", num.to_chinese(&options));
+
+    let mut options = Options::default();
+    options.middlewares = vec![Arc::new(liang), Arc::new(spaces)];
+    assert_eq!("两 万 两 千 两 百 二 十 二", num.to_chinese(&options));
+    assert_eq!("零", MidScaleInt::default().to_chinese(&options));
+}
+
+#[test]
+fn const_chars() {
+    use chinese_numerals::{NumChar, Variant};
+
+    const WAN: &str = NumChar::Wan.to_lowercase(Variant::Traditional);
+    const TWO: &str = NumChar::Two.to_uppercase_trad();
+    assert_eq!("萬", WAN);
+    assert_eq!("貳", TWO);
+    assert_eq!("叁", NumChar::Three.to_uppercase_simp());
+    assert_eq!("点", NumChar::Point.to_uppercase_simp());
+    assert_eq!("零", NumChar::Zero.to_uppercase_trad());
+    assert_eq!("𥝱", NumChar::Zi.to_lowercase(Variant::Japanese));
+}
+
+#[test]
+fn multi_char_units() {
+    use chinese_numerals::{
+        Case, CharacterSet, ChineseNumeral, MyriadScaleInt, NumChar, Options, Variant,
+    };
+
+    let mut options = Options::default();
+    options.charset =
+        Some(CharacterSet::new(Case::Lower, Variant::Simplified).with(NumChar::Zhao, "万亿"));
+    let num = MyriadScaleInt::from(3_0000_0000_0005_i64);
+    assert_eq!("三万亿零五", num.to_chinese(&options));
+    assert_eq!(5, num.char_len(&options));
+    assert_eq!(
+        "三万",
+        num.to_chinese(&{
+            let mut options = options;
+            options.max_output_chars = Some(2);
+            options
+        })
+    );
+}
+
+#[test]
+fn convert() {
+    use chinese_numerals::{convert_str, Case, ErrorKind, Options, ParseError, Scale};
+
+    assert_eq!(Ok(Scale::Short), "short".parse());
+    assert_eq!(Ok(Scale::Mid), "Mid-Scale".parse());
+    assert_eq!(Ok(Scale::Long), "上数".parse());
+    assert_eq!(
+        Err(ParseError::UnknownScale("huge".to_owned())),
+        "huge".parse::<Scale>()
+    );
+
+    let mut options = Options::default();
+    options.case = Case::Upper;
+    assert_eq!(
+        "壹拾叁万",
+        convert_str("13_0000", Scale::Myriad, &options).unwrap()
+    );
+    assert_eq!(
+        "十亿京",
+        convert_str(
+            "100000000000000000000000000000000000000000",
+            Scale::Long,
+            &Options::default()
+        )
+        .unwrap()
+    );
+    assert!(matches!(
+        convert_str("1_0000_0000_0000_0000", Scale::Short, &options)
+            .map_err(|err| err.kind().clone()),
+        Err(ErrorKind::OutOfRange)
+    ));
+}
+
+#[test]
+fn charset() {
+    use chinese_numerals::{
+        Case, CharacterSet, ChineseNumeral, MidScaleInt, NumChar, Options, Variant,
+    };
+
+    let charset = CharacterSet::new(Case::Lower, Variant::Traditional);
+    assert_eq!("萬", charset.get(NumChar::Wan));
+    let mut options = Options::default();
+    options.charset = Some(
+        charset
+            .with(NumChar::Zero, "〇")
+            .with(NumChar::Neg, "マイナス"),
+    );
+    let num = MidScaleInt::from(-1_0203_0405);
+    assert_eq!(
+        "マイナス一億〇二百〇三萬〇四百〇五",
+        num.to_chinese(&options)
+    );
+    assert_eq!("〇", MidScaleInt::default().to_chinese(&options));
+
+    let mut charset = CharacterSet::new(Case::Upper, Variant::Simplified);
+    charset.set(NumChar::Three, "参");
+    let mut options = Options::default();
+    options.case = Case::Upper;
+    options.charset = Some(charset);
+    assert_eq!("壹拾参", MidScaleInt::from(13).to_chinese(&options));
+}
+
+#[test]
+fn max_output_chars() {
+    use chinese_numerals::{ChineseNumeral, ErrorKind, LongScaleBigInt, Options};
+
+    let mut options = Options::default();
+    options.max_output_chars = Some(16);
+    let num = LongScaleBigInt::max_value();
+    assert_eq!(16, num.to_chinese(&options).chars().count());
+    let err = num.try_to_chinese(&options).unwrap_err();
+    assert_eq!(&ErrorKind::OutputTooLong(16), err.kind());
+    assert_eq!(5, err.code());
+    let num: LongScaleBigInt = "123".parse().unwrap();
+    assert_eq!("一百二十三", num.try_to_chinese(&options).unwrap());
+}
+
+#[test]
+fn japanese() {
+    use chinese_numerals::{
+        format_range, ChineseNumeral, MyriadScaleInt, Options, RangeStyle, ShortScaleInt, Variant,
+    };
+
+    let ja = Variant::Japanese;
+    assert_eq!("〇", MyriadScaleInt::from(0).to_lowercase(ja));
+    assert_eq!("零", MyriadScaleInt::from(0).to_uppercase(ja));
+    assert_eq!("十五", MyriadScaleInt::from(15).to_lowercase(ja));
+    assert_eq!("千百十一", MyriadScaleInt::from(1111).to_lowercase(ja));
+    assert_eq!(
+        "一万二千三百四十五",
+        MyriadScaleInt::from(1_2345).to_lowercase(ja)
+    );
+    assert_eq!(
+        "一億二百三万四百五",
+        MyriadScaleInt::from(1_0203_0405).to_lowercase(ja)
+    );
+    assert_eq!("負百五", MyriadScaleInt::from(-105).to_lowercase(ja));
+    assert_eq!("壱万壱拾", MyriadScaleInt::from(1_0010).to_uppercase(ja));
+    assert_eq!(
+        "一垓二兆三万四百五",
+        ShortScaleInt::from(1_0203_0405).to_lowercase(ja)
+    );
+
+    let mut options = Options::default();
+    options.variant = ja;
+    assert_eq!(
+        "三千万",
+        MyriadScaleInt::from(3000_0000).to_chinese(&options)
+    );
+    let start = MyriadScaleInt::from(3_0000);
+    let end = MyriadScaleInt::from(5_0000);
+    assert_eq!(
+        "三到五万",
+        format_range(&start, &end, RangeStyle::Compressed, ja)
+    );
+}
+
+#[test]
+fn sign_queries() {
+    use chinese_numerals::{LongScaleBigInt, MyriadScaleInt, ShortScaleInt};
+
+    let zero = MyriadScaleInt::from(0);
+    assert!(zero.is_zero() && !zero.is_positive() && !zero.is_negative());
+    assert!(MyriadScaleInt::from(-1).is_negative());
+    assert!(MyriadScaleInt::from(1).is_positive());
+    assert!(MyriadScaleInt::new_non_pos(0).is_zero());
+    assert!(ShortScaleInt::try_from(0_u64).unwrap().is_zero());
+    assert!("-0".parse::<ShortScaleInt>().unwrap().is_zero());
+    assert!("-0".parse::<MyriadScaleInt>().unwrap().is_zero());
+    assert!("-0".parse::<LongScaleBigInt>().unwrap().is_zero());
+    assert!(LongScaleBigInt::try_from(num_bigint::BigInt::from(0))
+        .unwrap()
+        .is_zero());
+    assert!(LongScaleBigInt::min_value().is_negative());
+}
+
+#[test]
+fn radix_fmt() {
+    use chinese_numerals::{MyriadScaleBigInt, MyriadScaleInt, ShortScaleInt};
+
+    let _defaults = shared_defaults();
+
+    assert_eq!("一零一一", format!("{:b}", MyriadScaleInt::from(11)));
+    assert_eq!("负壹零壹壹", format!("{:#b}", ShortScaleInt::from(-11)));
+    assert_eq!("負一零一一", format!("{:-b}", ShortScaleInt::from(-11)));
+    assert_eq!("負壹叄", format!("{:-#o}", MyriadScaleInt::from(-11)));
+    assert_eq!("一三", format!("{:o}", MyriadScaleInt::from(11)));
+    assert_eq!("零", format!("{:o}", MyriadScaleInt::from(0)));
+    assert_eq!(
+        "一点二三乘十的四次方",
+        format!("{:e}", MyriadScaleInt::from(12300))
+    );
+    assert_eq!(
+        "负五乘十的零次方",
+        format!("{:e}", MyriadScaleInt::from(-5))
+    );
+    assert_eq!(
+        "負五乘十的零次方",
+        format!("{:-e}", MyriadScaleInt::from(-5))
+    );
+    assert_eq!(
+        "壹点伍乘拾的壹拾次方",
+        format!("{:#e}", MyriadScaleInt::from(15_000_000_000_u64))
+    );
+    let num: MyriadScaleBigInt = "1".repeat(30).parse().unwrap();
+    assert_eq!(
+        "一点一一一一一一一一一一一一一一一一一一一一一一一一一一一一一乘十的二十九次方",
+        format!("{num:e}")
+    );
+}
+
+#[test]
+fn korean_vietnamese() {
+    use chinese_numerals::{ChineseNumeral, MyriadScaleInt, Options, Variant};
+
+    let num = MyriadScaleInt::from(1_0203_0405);
+    assert_eq!("一億二百三萬四百五", num.to_lowercase(Variant::Korean));
+    assert_eq!("壹億貳百參萬四百五", num.to_uppercase(Variant::Korean));
+    assert_eq!(
+        "𠬠億零𠄩𤾓零𠀧萬零𦊚𤾓零𠄼",
+        num.to_lowercase(Variant::Vietnamese)
+    );
+    assert_eq!(
+        "壹億零貳佰零叄萬零肆佰零伍",
+        num.to_uppercase(Variant::Vietnamese)
+    );
+    assert_eq!(
+        "𨑮𠄼",
+        MyriadScaleInt::from(15).to_lowercase(Variant::Vietnamese)
+    );
+    assert_eq!(
+        "十五",
+        MyriadScaleInt::from(15).to_lowercase(Variant::Korean)
+    );
+    assert_eq!("零", MyriadScaleInt::from(0).to_lowercase(Variant::Korean));
+
+    let mut options = Options::default();
+    options.variant = Variant::Korean;
+    assert_eq!(
+        "一萬二千",
+        MyriadScaleInt::from(1_2000).to_chinese(&options)
+    );
+}
+
+#[test]
+fn suggest() {
+    use chinese_numerals::{suggest, Candidate, CandidateStyle, Scale, SuggestOptions, Variant};
+
+    let options = SuggestOptions {
+        scale: Scale::Short,
+        variant: Variant::Traditional,
+        preference: vec![CandidateStyle::Money, CandidateStyle::Lowercase],
+    };
+    assert_eq!(
+        vec![Candidate {
+            style: CandidateStyle::Money,
+            text: "壹拾貳元叄角".to_owned()
+        },],
+        suggest("12.3", &options)
+    );
+    let styles: Vec<_> = suggest("007", &SuggestOptions::default())
+        .into_iter()
+        .map(|candidate| candidate.style)
+        .collect();
+    assert_eq!(
+        vec![
+            CandidateStyle::Lowercase,
+            CandidateStyle::Uppercase,
+            CandidateStyle::Digits,
+            CandidateStyle::Money
+        ],
+        styles
+    );
+    assert!(suggest("abc", &SuggestOptions::default()).is_empty());
+    assert!(suggest("1", &SuggestOptions::default())
+        .iter()
+        .any(|candidate| candidate.text == "一"));
+}
+
+#[test]
+fn validate() {
+    use chinese_numerals::{validate, ParseError, Scale, ValidationError};
+
+    assert_eq!(Ok(()), validate("零", Scale::Short));
+    assert_eq!(Ok(()), validate("负十二", Scale::Myriad));
+    assert_eq!(Ok(()), validate("壹拾貳萬", Scale::Mid));
+    assert_eq!(Ok(()), validate("一万亿", Scale::Mid));
+    assert_eq!(Ok(()), validate("十亿京", Scale::Long));
+    assert_eq!(
+        Err(ValidationError::Malformed("十二".to_owned())),
+        validate("一十二", Scale::Myriad)
+    );
+    assert_eq!(
+        Err(ValidationError::Malformed("壹拾贰".to_owned())),
+        validate("拾贰", Scale::Myriad)
+    );
+    assert_eq!(
+        Err(ValidationError::Malformed("零".to_owned())),
+        validate("负零", Scale::Myriad)
+    );
+    assert_eq!(
+        Err(ValidationError::Parse(ParseError::InvalidChar('x'))),
+        validate("一x", Scale::Myriad)
+    );
+    assert_eq!(
+        Err(ValidationError::Parse(ParseError::Empty)),
+        validate("", Scale::Myriad)
+    );
+}
+
+mod validate_props {
+    use chinese_numerals::{
+        validate, ChineseNumeral, LongScaleInt, MidScaleInt, MyriadScaleInt, Scale, ShortScaleInt,
+        Variant,
+    };
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn short_round_trip(n in -999_9999_9999_9999_i64..=999_9999_9999_9999) {
+            let num = ShortScaleInt::try_from(n).unwrap();
+            prop_assert_eq!(Ok(()), validate(&num.to_lowercase(Variant::Simplified), Scale::Short));
+            prop_assert_eq!(Ok(()), validate(&num.to_uppercase(Variant::Traditional), Scale::Short));
+        }
+
+        #[test]
+        fn myriad_round_trip(n: i128) {
+            let num = MyriadScaleInt::from(n);
+            prop_assert_eq!(Ok(()), validate(&num.to_lowercase(Variant::Traditional), Scale::Myriad));
+            prop_assert_eq!(Ok(()), validate(&num.to_uppercase(Variant::Simplified), Scale::Myriad));
+        }
+
+        #[test]
+        fn mid_round_trip(n: i128) {
+            let num = MidScaleInt::from(n);
+            prop_assert_eq!(Ok(()), validate(&num.to_lowercase(Variant::Simplified), Scale::Mid));
+        }
+
+        #[test]
+        fn long_round_trip(n: u128) {
+            let num = LongScaleInt::from(n);
+            prop_assert_eq!(Ok(()), validate(&num.to_uppercase(Variant::Simplified), Scale::Long));
+        }
+    }
+}
+
+#[test]
+fn error_details() {
+    use chinese_numerals::{ErrorKind, MyriadScaleBigInt, ParseError, Scale, ShortScaleInt};
+    use std::error::Error as _;
+
+    let err = ShortScaleInt::try_from(1_0000_0000_0000_0000_u64).unwrap_err();
+    assert_eq!(&ErrorKind::OutOfRange, err.kind());
+    assert_eq!(Some("10000000000000000"), err.value());
+    assert_eq!(Some(Scale::Short), err.scale());
+    assert!(err.source().is_none());
+
+    let err = MyriadScaleBigInt::try_from(-num_bigint::BigInt::from(10).pow(200)).unwrap_err();
+    assert_eq!(Some(Scale::Myriad), err.scale());
+    assert_eq!(Some(format!("1{}", "0".repeat(200)).as_str()), err.value());
+
+    let err = "1x".parse::<ShortScaleInt>().unwrap_err();
+    assert_eq!(&ErrorKind::Parse(ParseError::InvalidChar('x')), err.kind());
+    assert_eq!(None, err.scale());
+    assert!(err.source().is_some());
+}
+
+#[test]
+fn const_fmt() {
+    use chinese_numerals::{
+        chinese, format_const, Case, ChineseNumeral, LongScaleInt, MidScaleInt, MyriadScaleInt,
+        Scale, ShortScaleInt, Variant,
+    };
+
+    const CHAPTER: &str = chinese!(12);
+    assert_eq!("第十二章", format!("第{CHAPTER}章"));
+    assert_eq!(
+        "负一垓零二兆零三万零四百零五",
+        chinese!(-1_0203_0405, short, lower, simp)
+    );
+    assert_eq!(
+        "一億二百三万四百五",
+        chinese!(1_0203_0405, myriad, lower, ja)
+    );
+    assert_eq!("零", chinese!(0, long, upper, trad));
+
+    const NUM: MyriadScaleInt = MyriadScaleInt::from_i128(-15);
+    assert_eq!(MyriadScaleInt::from(-15), NUM);
+    assert_eq!(ShortScaleInt::from(0), ShortScaleInt::from_u32(0));
+    assert!(MidScaleInt::from_u128(0).is_zero());
+
+    for n in [
+        0,
+        7,
+        10,
+        19,
+        105,
+        1_0010,
+        10_0000_0000,
+        i128::MAX,
+        i128::MIN + 1,
+    ] {
+        for variant in [Variant::Simplified, Variant::Traditional, Variant::Japanese] {
+            let lower = |scale| format_const(n, scale, Case::Lower, variant);
+            let upper = |scale| format_const(n, scale, Case::Upper, variant);
+            assert_eq!(
+                MyriadScaleInt::from(n).to_lowercase(variant),
+                lower(Scale::Myriad).as_str()
+            );
+            assert_eq!(
+                MidScaleInt::from(n).to_uppercase(variant),
+                upper(Scale::Mid).as_str()
+            );
+            assert_eq!(
+                LongScaleInt::from(n).to_lowercase(variant),
+                lower(Scale::Long).as_str()
+            );
+            if let Ok(short) = ShortScaleInt::try_from(n) {
+                assert_eq!(short.to_uppercase(variant), upper(Scale::Short).as_str());
+            }
+        }
+    }
+}
+
+#[test]
+fn icu_compatible() {
+    use chinese_numerals::{Case, ChineseNumeral, MyriadScaleInt, Options, Style, Variant};
+
+    let fixtures = [
+        (include_str!("icu/zh.tsv"), Case::Lower, Variant::Simplified),
+        (
+            include_str!("icu/zh_Hant.tsv"),
+            Case::Lower,
+            Variant::Traditional,
+        ),
+        (
+            include_str!("icu/zh_financial.tsv"),
+            Case::Upper,
+            Variant::Simplified,
+        ),
+        (
+            include_str!("icu/zh_Hant_financial.tsv"),
+            Case::Upper,
+            Variant::Traditional,
+        ),
+    ];
+    for (fixture, case, variant) in fixtures {
+        let mut options = Options::default();
+        options.case = case;
+        options.variant = variant;
+        options.style = Style::IcuCompatible;
+        for line in fixture.lines() {
+            let (num, expected) = line.split_once('\t').unwrap();
+            let num = MyriadScaleInt::from(num.parse::<i64>().unwrap());
+            assert_eq!(expected, num.to_chinese(&options), "{case:?} {variant:?}");
+        }
+    }
+}
+
+/// Serializes the tests reading the crate-wide default options against [`global_default_options`], which changes them.
+static DEFAULTS: std::sync::RwLock<()> = std::sync::RwLock::new(());
+
+/// Holds the default options unchanged while the test formats with them.
+fn shared_defaults() -> std::sync::RwLockReadGuard<'static, ()> {
+    DEFAULTS
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+#[test]
+fn global_default_options() {
+    use chinese_numerals::{
+        default_options, set_default_options, ChineseNumeral, MyriadScaleInt, Options, Variant,
+    };
+
+    let _defaults = DEFAULTS
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let previous = default_options();
+    let num = MyriadScaleInt::from(-1_0000_0011);
+
+    let mut options = Options::default();
+    options.variant = Variant::Traditional;
+    set_default_options(options);
+    assert_eq!("負一億零一十一", format!("{num}"));
+    assert_eq!("負壹億零壹拾壹", format!("{num:#}"));
+    assert_eq!("負一億零一十一", num.to_chinese(&default_options()));
+    assert_eq!("負一零一一", format!("{:b}", MyriadScaleInt::from(-11)));
+    assert_eq!("負一三", format!("{:o}", MyriadScaleInt::from(-11)));
+    assert_eq!(
+        "負五乘十的零次方",
+        format!("{:e}", MyriadScaleInt::from(-5))
+    );
+
+    set_default_options((*previous).clone());
+    assert_eq!("负一亿零一十一", format!("{num}"));
+    assert_eq!("负一亿零一十一", num.to_chinese(&default_options()));
+    assert_eq!("负一零一一", format!("{:b}", MyriadScaleInt::from(-11)));
+}
+
+#[test]
+fn display_flags() {
+    use chinese_numerals::{LongScaleInt, MyriadScaleInt};
+
+    let _defaults = shared_defaults();
+
+    let num = MyriadScaleInt::from(12345);
+    assert_eq!("一万二千三百四十五", format!("{}", num));
+    assert_eq!("一萬二千三百四十五", format!("{:-}", num));
+    assert_eq!("壹萬貳仟叄佰肆拾伍", format!("{:-#}", num));
+    assert_eq!("约一万二千", format!("{:.2}", num));
+    assert_eq!("約壹萬貳仟", format!("{:-#.2}", num));
+    assert_eq!("约一万", format!("{:.0}", num));
+    assert_eq!("一万二千三百四十五", format!("{:.5}", num));
+    assert_eq!("零零约一万二千", format!("{:零>7.2}", num));
+    assert_eq!("约一万二千  ", format!("{:<7.2}", num));
+    assert_eq!("  一万二千三百四十五", format!("{:11}", num));
+    assert_eq!(" 十二 ", format!("{:^4}", MyriadScaleInt::from(12)));
+    assert_eq!("一万二千", format!("{:.2}", MyriadScaleInt::from(12000)));
+    assert_eq!(
+        "约负一万二千",
+        format!("{:.2}", MyriadScaleInt::from(-12345))
+    );
+    assert_eq!("约九千九百", format!("{:.2}", LongScaleInt::from(9999)));
+}
+
+#[test]
+fn digits_radix() {
+    use chinese_numerals::{Case, LongScaleBigInt, MyriadScaleInt, ShortScaleInt, Variant};
+
+    let num = MyriadScaleInt::from(0x1F);
+    assert_eq!(
+        "一、十五",
+        num.to_digits_radix(16, Case::Lower, Variant::Simplified)
+    );
+    assert_eq!(
+        "壹、壹拾伍",
+        num.to_digits_radix(16, Case::Upper, Variant::Simplified)
+    );
+    assert_eq!(
+        "一一一一一",
+        num.to_digits_radix(2, Case::Lower, Variant::Simplified)
+    );
+    assert_eq!(
+        "三七",
+        num.to_digits_radix(8, Case::Lower, Variant::Simplified)
+    );
+    assert_eq!(
+        "负一零",
+        ShortScaleInt::from(-2).to_digits_radix(2, Case::Lower, Variant::Simplified)
+    );
+    assert_eq!(
+        "零",
+        ShortScaleInt::from(0).to_digits_radix(16, Case::Lower, Variant::Simplified)
+    );
+    assert_eq!(
+        "一、零、三十五",
+        "1331".parse::<LongScaleBigInt>().unwrap().to_digits_radix(
+            36,
+            Case::Lower,
+            Variant::Simplified
+        )
+    );
+    assert_eq!(
+        "負一零",
+        MyriadScaleInt::from(-3).to_digits_radix(3, Case::Lower, Variant::Traditional)
+    );
+}
+
+#[test]
+fn segments() {
+    use chinese_numerals::{
+        ChineseNumeral, LongScaleBigInt, MyriadScaleInt, NumChar, Segment, ShortScaleInt,
+    };
+
+    let segment = |zero, value, units: &[NumChar]| Segment {
+        zero,
+        value,
+        units: units.to_vec(),
+    };
+    assert_eq!(
+        vec![
+            segment(false, 1, &[NumChar::Yi]),
+            segment(true, 200, &[NumChar::Wan]),
+            segment(true, 5, &[]),
+        ],
+        MyriadScaleInt::from(1_0200_0005).segments()
+    );
+    assert_eq!(
+        vec![segment(false, 1015, &[])],
+        MyriadScaleInt::from(-1015).segments()
+    );
+    assert!(MyriadScaleInt::from(0).segments().is_empty());
+    assert_eq!(
+        vec![
+            segment(false, 1, &[NumChar::Jian]),
+            segment(true, 2, &[NumChar::Zi]),
+            segment(false, 3, &[NumChar::Gai]),
+            segment(true, 4, &[NumChar::Zhao]),
+            segment(false, 5, &[NumChar::Yi]),
+            segment(false, 6, &[NumChar::Wan]),
+            segment(false, 7890, &[]),
+        ],
+        ShortScaleInt::try_from(1_0023_0456_7890_i64)
+            .unwrap()
+            .segments()
+    );
+    let num: LongScaleBigInt = "10000000000000000".parse().unwrap();
+    assert_eq!(vec![segment(false, 1, &[NumChar::Zhao])], num.segments());
+}
+
+#[test]
+fn formatter() {
+    use chinese_numerals::{
+        Case, ChineseNumeral, Formatter, MidScaleInt, MyriadScaleBigInt, Options, Variant,
+    };
+
+    let mut options = Options::default();
+    options.case = Case::Upper;
+    options.variant = Variant::Traditional;
+    let mut formatter = Formatter::new(options.clone());
+    let mut output = String::from("合計：");
+    formatter.format_into(&mut output, &MidScaleInt::from(-10_0203));
+    assert_eq!("合計：負壹拾萬零貳佰零叄", output);
+
+    let mut output = String::new();
+    for n in [0, 15, 1_0000_0001, -7] {
+        let num = MidScaleInt::from(n);
+        output.clear();
+        formatter.format_into(&mut output, &num);
+        assert_eq!(num.to_chinese(&options), output);
+    }
+
+    let mut formatter = Formatter::new({
+        let mut options = Options::default();
+        options.max_output_chars = Some(3);
+        options
+    });
+    let mut output = String::from("第");
+    formatter.format_into(&mut output, &"12345".parse::<MyriadScaleBigInt>().unwrap());
+    assert_eq!("第一万二", output);
+    assert_eq!(Some(3), formatter.options().max_output_chars);
+}
+
+#[test]
+fn array_string() {
+    use chinese_numerals::{
+        Case, ChineseNumeral, ConstStr, LongScaleInt, MidScaleInt, MyriadScaleInt, ShortScaleInt,
+        Variant,
+    };
+
+    fn expected<T: ChineseNumeral>(num: &T, case: Case, variant: Variant) -> String {
+        match case {
+            Case::Lower => num.to_lowercase(variant),
+            Case::Upper => num.to_uppercase(variant),
+        }
+    }
+
+    const NUM: ConstStr =
+        MyriadScaleInt::from_i128(-12).to_array_string(Case::Lower, Variant::Simplified);
+    assert_eq!("负十二", &*NUM);
+    assert_eq!(
+        "壹萬零貳佰",
+        MidScaleInt::from(10200)
+            .to_array_string(Case::Upper, Variant::Traditional)
+            .to_string()
+    );
+
+    let variants = [
+        Variant::Simplified,
+        Variant::Traditional,
+        Variant::Japanese,
+        Variant::Korean,
+        Variant::Vietnamese,
+    ];
+    let mut values = vec![0, 1, 10, 15, 1_0010, u128::MAX, i128::MAX as u128];
+    // numbers with many zeros between nonzero digits and groups
+    values.extend((1..=3).map(|n| u128::MAX / 10_u128.pow(n) / 99 * 98));
+    values.push(u128::MAX / 1_0001 * 1_0000);
+    for value in values {
+        let (myriad, mid, long) = (
+            MyriadScaleInt::from(value),
+            MidScaleInt::from(value),
+            LongScaleInt::from(value),
+        );
+        for variant in variants {
+            for case in [Case::Lower, Case::Upper] {
+                let array = myriad.to_array_string(case, variant);
+                assert_eq!(expected(&myriad, case, variant), array.as_str());
+                let array = mid.to_array_string(case, variant);
+                assert_eq!(expected(&mid, case, variant), array.as_str());
+                let array = long.to_array_string(case, variant);
+                assert_eq!(expected(&long, case, variant), array.as_str());
+            }
+        }
+    }
+    let num = ShortScaleInt::MIN;
+    let array = num.to_array_string(Case::Upper, Variant::Japanese);
+    assert_eq!(
+        expected(&num, Case::Upper, Variant::Japanese),
+        array.as_str()
+    );
+}
+
+#[test]
+fn scale_int() {
+    use chinese_numerals::{
+        ChineseNumeral, ErrorKind, LongScaleBigInt, MidScaleInt, MyriadScaleBigInt, ScaleInt,
+        ShortScaleInt, Sign,
+    };
+    use num_bigint::BigUint;
+
+    fn round_trip<T: ScaleInt + PartialEq + std::fmt::Debug + Clone>(num: T) {
+        assert_eq!(num, T::from_parts(num.clone().into_parts()));
+    }
+
+    assert_eq!(MidScaleInt::from(12), MidScaleInt::new(Sign::Pos, 12));
+    assert_eq!(MidScaleInt::from(12), MidScaleInt::new(Sign::Nil, 12));
+    assert_eq!(MidScaleInt::from(0), MidScaleInt::new(Sign::Neg, 0));
+    assert_eq!(MidScaleInt::from(-12), MidScaleInt::new_negative(12));
+    assert_eq!((Sign::Neg, 12), MidScaleInt::from(-12).into_parts());
+    assert_eq!((Sign::Nil, 0), ShortScaleInt::from(0).into_parts());
+    round_trip(ShortScaleInt::MIN);
+    round_trip(MidScaleInt::from(i128::MIN));
+    round_trip(MyriadScaleBigInt::max_value());
+
+    let err = ShortScaleInt::try_new(Sign::Pos, 1000_0000_0000_0000).unwrap_err();
+    assert_eq!(&ErrorKind::OutOfRange, err.kind());
+    assert!(ShortScaleInt::try_new_negative(999_9999_9999_9999).is_ok());
+    let (_, max) = LongScaleBigInt::max_value().into_parts();
+    assert!(LongScaleBigInt::try_new(Sign::Pos, max.clone()).is_ok());
+    assert!(LongScaleBigInt::try_new_negative(max + 1_u32).is_err());
+    assert_eq!(
+        "负一万",
+        MyriadScaleBigInt::new_negative(BigUint::from(1_0000_u32)).to_lowercase_simp()
+    );
+}
+
+#[test]
+fn try_from_mixed_str() {
+    use chinese_numerals::{
+        ErrorKind, LongScaleInt, MidScaleBigInt, MyriadScaleInt, ParseError, ShortScaleInt,
+    };
+
+    let parse = |s| MyriadScaleInt::try_from(s);
+    assert_eq!(Ok(MyriadScaleInt::from(3_5000)), parse("3万5千"));
+    assert_eq!(Ok(MyriadScaleInt::from(12_0000_0000)), parse("12亿"));
+    assert_eq!(Ok(MyriadScaleInt::from(12_0305)), parse("12万零305"));
+    assert_eq!(Ok(MyriadScaleInt::from(-1_2000)), parse("-1万2千"));
+    assert_eq!(Ok(MyriadScaleInt::from(-1_2000)), parse("负一万二千"));
+    assert_eq!(Ok(MyriadScaleInt::from(250)), parse("250"));
+    assert_eq!(Ok(MyriadScaleInt::from(0)), parse("零"));
+    assert_eq!(Ok(MyriadScaleInt::from(3_5000)), parse("三万五"));
+    assert_eq!(Ok(MyriadScaleInt::from(150)), parse("一百五"));
+    assert_eq!(Ok(MyriadScaleInt::from(1_0005)), parse("一万零五"));
+    assert_eq!(Ok(MyriadScaleInt::from(2_0000)), parse("两万"));
+    assert_eq!(Ok(MyriadScaleInt::from(2_0000)), parse("兩萬"));
+    assert_eq!(Ok(MyriadScaleInt::from(1005)), parse("一千〇五"));
+    assert_eq!(
+        Ok(MyriadScaleInt::from(1_0000_0000_0000_i64)),
+        parse("一万亿")
+    );
+    for s in [
+        "一二三",
+        "五五",
+        "十十",
+        "百千",
+        "亿亿",
+        "一万一万",
+        "一亿一亿",
+        "一亿万",
+        "3万5万",
+    ] {
+        assert!(
+            matches!(
+                parse(s).unwrap_err().kind(),
+                ErrorKind::Parse(ParseError::InvalidChar(_))
+            ),
+            "{s}"
+        );
+    }
+    assert_eq!(
+        &ErrorKind::Parse(ParseError::InvalidChar('二')),
+        parse("一二三").unwrap_err().kind()
+    );
+    assert_eq!(
+        &ErrorKind::Parse(ParseError::InvalidChar('x')),
+        parse("3x").unwrap_err().kind()
+    );
+    assert_eq!(
+        &ErrorKind::Parse(ParseError::Empty),
+        parse("").unwrap_err().kind()
+    );
+
+    assert_eq!(
+        Ok(ShortScaleInt::from(3_0000)),
+        ShortScaleInt::try_from("3万")
+    );
+    assert_eq!(
+        Ok(ShortScaleInt::from(30_0000)),
+        ShortScaleInt::try_from("3亿")
+    );
+    assert_eq!(
+        &ErrorKind::OutOfRange,
+        ShortScaleInt::try_from("1万载").unwrap_err().kind()
+    );
+    assert_eq!(
+        Ok(LongScaleInt::from(5_0000_0000_0000_0000_u128)),
+        LongScaleInt::try_from("5兆")
+    );
+    assert_eq!(
+        "5000000000000000".parse::<MidScaleBigInt>(),
+        MidScaleBigInt::try_from("5000万亿")
+    );
+}
+
+#[test]
+fn bounded() {
+    use chinese_numerals::{
+        ChineseNumeral, LongScaleBigInt, LongScaleInt, MidScaleInt, MidScaleInt64,
+        MyriadScaleBigInt, MyriadScaleInt, ShortScaleInt,
+    };
+    use num_traits::Bounded;
+
+    let _defaults = shared_defaults();
+
+    fn bounds<T: Bounded + ChineseNumeral>() -> (String, String) {
+        (
+            T::min_value().to_lowercase_simp(),
+            T::max_value().to_lowercase_simp(),
+        )
+    }
+
+    assert_eq!(
+        (
+            ShortScaleInt::MIN.to_string(),
+            ShortScaleInt::MAX.to_string()
+        ),
+        bounds::<ShortScaleInt>()
+    );
+    assert_eq!(MyriadScaleInt::from(u128::MAX), MyriadScaleInt::MAX);
+    assert_eq!(MidScaleInt::new_non_pos(u128::MAX), MidScaleInt::MIN);
+    assert_eq!(
+        (LongScaleInt::MIN.to_string(), LongScaleInt::MAX.to_string()),
+        bounds::<LongScaleInt>()
+    );
+    assert_eq!(
+        (
+            MidScaleInt64::MIN.to_string(),
+            MidScaleInt64::MAX.to_string()
+        ),
+        bounds::<MidScaleInt64>()
+    );
+    assert_eq!(
+        MyriadScaleBigInt::max_value().to_string(),
+        bounds::<MyriadScaleBigInt>().1
+    );
+    assert_eq!(
+        LongScaleBigInt::min_value().to_string(),
+        bounds::<LongScaleBigInt>().0
+    );
+}
+
+#[test]
+fn arithmetic() {
+    use chinese_numerals::{MidScaleInt, MyriadScaleBigInt, ShortScaleInt};
+
+    let num = |n: i128| MidScaleInt::from(n);
+    assert_eq!(num(5), num(2) + num(3));
+    assert_eq!(num(-1), num(2) - num(3));
+    assert_eq!(num(0), num(-3) + num(3));
+    assert_eq!(num(-6), num(2) * num(-3));
+    assert_eq!(num(-2), num(-7) / num(3));
+    assert_eq!(num(-1), num(-7) % num(3));
+    assert_eq!(num(1), num(7) % num(-3));
+    assert_eq!(num(3), -num(-3));
+    assert_eq!(num(0), -num(0));
+    assert_eq!(
+        MidScaleInt::new_non_pos(u128::MAX),
+        MidScaleInt::from(0) - MidScaleInt::from(u128::MAX)
+    );
+
+    let big: MyriadScaleBigInt = "100000000000000000000000000000000000000000"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        "99999999999999999999999999999999999999999".parse::<MyriadScaleBigInt>(),
+        Ok(big - "1".parse().unwrap())
+    );
+
+    let result = std::panic::catch_unwind(|| ShortScaleInt::MAX + ShortScaleInt::from(1));
+    assert!(result.is_err());
+    let result = std::panic::catch_unwind(|| num(1) / num(0));
+    assert!(result.is_err());
+}
+
+#[test]
+fn num_traits() {
+    use chinese_numerals::{ChineseNumeral, LongScaleBigInt, MidScaleInt, ShortScaleInt};
+    use num_traits::{FromPrimitive, Num, One, Signed, ToPrimitive, Zero};
+
+    fn sum<T: Zero + ChineseNumeral>(nums: Vec<T>) -> String {
+        nums.into_iter()
+            .fold(T::zero(), |acc, num| acc + num)
+            .to_lowercase_simp()
+    }
+
+    let nums = (1..=4).map(ShortScaleInt::from).collect();
+    assert_eq!("十", sum(nums));
+    assert_eq!(MidScaleInt::from(1), MidScaleInt::one());
+    assert!(MidScaleInt::zero().is_zero());
+    assert_eq!(
+        Ok(MidScaleInt::from(-255)),
+        MidScaleInt::from_str_radix("-ff", 16)
+    );
+    assert!(ShortScaleInt::from_str_radix("zzzzzzzzzzzz", 36).is_err());
+    assert!(ShortScaleInt::from_str_radix("12", 2).is_err());
+
+    let num = MidScaleInt::from(-12);
+    assert_eq!(MidScaleInt::from(12), num.abs());
+    assert_eq!(MidScaleInt::from(-1), num.signum());
+    assert_eq!(MidScaleInt::from(0), num.abs_sub(&MidScaleInt::from(3)));
+    assert_eq!(MidScaleInt::from(15), MidScaleInt::from(3).abs_sub(&num));
+    assert!(Signed::is_negative(&num));
+
+    assert_eq!(Some(MidScaleInt::from(-12)), MidScaleInt::from_i64(-12));
+    assert_eq!(None, ShortScaleInt::from_u64(u64::MAX));
+    assert_eq!(
+        u128::MAX.to_string().parse().ok(),
+        LongScaleBigInt::from_u128(u128::MAX)
+    );
+    assert_eq!(Some(-12), num.to_i64());
+    assert_eq!(None, num.to_u64());
+    assert_eq!(Some(i128::MIN), MidScaleInt::from(i128::MIN).to_i128());
+    assert_eq!(None, MidScaleInt::from(u128::MAX).to_i128());
+    assert_eq!(Some(u128::MAX), MidScaleInt::from(u128::MAX).to_u128());
+}
+
+#[test]
+fn ordering() {
+    use chinese_numerals::{LongScaleBigInt, MyriadScaleInt, ShortScaleInt};
+    use std::collections::{BTreeSet, HashMap};
+
+    let mut nums: Vec<_> = [3, -5, 0, -3, 12, -12, 5]
+        .into_iter()
+        .map(ShortScaleInt::from)
+        .collect();
+    nums.sort();
+    let expected: Vec<_> = [-12, -5, -3, 0, 3, 5, 12]
+        .into_iter()
+        .map(ShortScaleInt::from)
+        .collect();
+    assert_eq!(expected, nums);
+    assert!(MyriadScaleInt::from(-5) < MyriadScaleInt::from(-3));
+    assert!(MyriadScaleInt::MIN < MyriadScaleInt::from(i128::MIN));
+    assert!(LongScaleBigInt::min_value() < "-1".parse::<LongScaleBigInt>().unwrap());
+    assert_eq!(
+        Some(&LongScaleBigInt::max_value()),
+        BTreeSet::from([LongScaleBigInt::max_value(), LongScaleBigInt::default()]).last()
+    );
+
+    let mut counts = HashMap::new();
+    for n in [1, -1, 1, 0] {
+        *counts.entry(MyriadScaleInt::from(n)).or_insert(0) += 1;
+    }
+    assert_eq!(2, counts[&MyriadScaleInt::from(1)]);
+    assert_eq!(3, counts.len());
+}
+
+#[test]
+fn stepping() {
+    use chinese_numerals::{ChineseNumeral, MidScaleBigInt, MyriadScaleInt, ShortScaleInt};
+
+    let num = |n: i128| MyriadScaleInt::from(n);
+    assert_eq!(Some(num(5)), num(2).checked_add(num(3)));
+    assert_eq!(Some(num(-1)), num(2).checked_sub(num(3)));
+    assert_eq!(Some(num(-6)), num(2).checked_mul(num(-3)));
+    assert_eq!(None, num(2).checked_div(num(0)));
+    assert_eq!(None, MyriadScaleInt::MAX.checked_add(num(1)));
+    assert_eq!(None, ShortScaleInt::MIN.pred());
+    assert_eq!(
+        Some(ShortScaleInt::MAX),
+        ShortScaleInt::MAX.pred().unwrap().succ()
+    );
+    assert_eq!(Some(num(0)), num(-1).succ());
+
+    let numerals: Vec<_> = ShortScaleInt::range_inclusive(1.into(), 1000.into())
+        .map(|num| num.to_lowercase_simp())
+        .collect();
+    assert_eq!(1000, numerals.len());
+    assert_eq!("一千", numerals[999]);
+    assert_eq!(3, MyriadScaleInt::range(num(-2), num(1)).count());
+    assert_eq!(0, MyriadScaleInt::range(num(1), num(1)).count());
+    assert_eq!(1, MyriadScaleInt::range_inclusive(num(1), num(1)).count());
+    assert_eq!(0, MyriadScaleInt::range_inclusive(num(2), num(1)).count());
+    assert_eq!(
+        1,
+        ShortScaleInt::range_inclusive(ShortScaleInt::MAX, ShortScaleInt::MAX).count()
+    );
+    assert_eq!(
+        2,
+        MidScaleBigInt::range(
+            MidScaleBigInt::max_value().pred().unwrap().pred().unwrap(),
+            MidScaleBigInt::max_value()
+        )
+        .count()
+    );
+}
+
+#[test]
+fn error_magnitude() {
+    use chinese_numerals::{LongScaleBigInt, MidScaleBigInt, MyriadScaleBigInt, ShortScaleInt};
+    use num_bigint::BigUint;
+
+    let err = ShortScaleInt::try_from(u64::MAX).unwrap_err();
+    assert_eq!(Some(19), err.magnitude());
+    assert_eq!(Some(5), err.orders_over());
+    assert!(err
+        .to_string()
+        .ends_with("5 orders of magnitude over, needing a unit beyond 载 (10^14)"));
+
+    let pow10 = |exp: u32| BigUint::from(10_u32).pow(exp);
+    let err = MyriadScaleBigInt::try_from(pow10(48)).unwrap_err();
+    assert_eq!(Some(1), err.orders_over());
+    let err = MidScaleBigInt::try_from(pow10(100)).unwrap_err();
+    assert_eq!(Some(13), err.orders_over());
+    assert!(err.to_string().ends_with("(10^80)"));
+    let err = LongScaleBigInt::try_from(pow10(8192)).unwrap_err();
+    assert_eq!(Some(1), err.orders_over());
+    assert!(err.to_string().ends_with("(10^4096)"));
+
+    let err = "x".parse::<ShortScaleInt>().unwrap_err();
+    assert_eq!(None, err.magnitude());
+    assert_eq!(None, err.orders_over());
+}
+
+#[test]
+fn group_table() {
+    use chinese_numerals::{ChineseNumeral, MyriadScaleBigInt, MyriadScaleInt, ShortScaleInt};
+
+    for n in 0..1_0000_u16 {
+        let short = ShortScaleInt::from(n);
+        let myriad = MyriadScaleInt::from(n);
+        assert_eq!(short.to_uppercase_simp(), myriad.to_uppercase_simp());
+        assert_eq!(short.to_lowercase_trad(), myriad.to_lowercase_trad());
+    }
+    let big = MyriadScaleBigInt::try_from(num_bigint::BigUint::from(1_0203_0405_u32)).unwrap();
+    assert_eq!("一亿零二百零三万零四百零五", big.to_lowercase_simp());
+}
+
+#[test]
+fn explain() {
+    use chinese_numerals::{
+        ChineseNumeral, LongScaleInt, MidScaleInt, MyriadScaleInt, ShortScaleInt, Sign,
+    };
+
+    let explanation = MyriadScaleInt::from(1_0200_0005).explain();
+    assert_eq!(
+        vec![8, 4, 0],
+        explanation
+            .steps
+            .iter()
+            .map(|step| step.exp)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!("零二百万", explanation.steps[1].text);
+    assert_eq!(
+        vec!["零 stands for the zero digit at 10^7"],
+        explanation.steps[1].notes
+    );
+    assert_eq!(
+        vec!["零 stands for the zero digits from 10^3 to 10^1"],
+        explanation.steps[2].notes
+    );
+
+    let explanation = MyriadScaleInt::from(-15).explain();
+    assert_eq!(Sign::Neg, explanation.sign);
+    assert_eq!("负十五", explanation.numeral);
+    assert_eq!(
+        "-15 in myriad scale: 负十五\n  负 marks a negative number\n  15 → 十五\n    the 一 of the leading 十 is omitted\n",
+        explanation.to_string()
+    );
+
+    let explanation = MyriadScaleInt::from(0).explain();
+    assert!(explanation.steps.is_empty());
+    assert_eq!("零", explanation.numeral);
+
+    let explanation = ShortScaleInt::from(120_0003).explain();
+    assert_eq!(
+        vec![6, 5, 0],
+        explanation
+            .steps
+            .iter()
+            .map(|step| step.exp)
+            .collect::<Vec<_>>()
+    );
+
+    for value in [
+        1,
+        10,
+        1005,
+        10_0000_0305,
+        1000_0000_0000_0001,
+        12_3400_5678_0009_u64,
+    ] {
+        let num = MidScaleInt::from(value);
+        assert_eq!(num.to_lowercase_simp(), num.explain().numeral);
+        let num = LongScaleInt::from(value);
+        assert_eq!(num.to_lowercase_simp(), num.explain().numeral);
+        let num = MyriadScaleInt::from(value);
+        assert_eq!(num.to_lowercase_simp(), num.explain().numeral);
+    }
+}
+
+#[test]
+fn glyph_profile() {
+    use chinese_numerals::{Case, ChineseNumeral, GlyphProfile, MyriadScaleInt, Options, Variant};
+
+    let render = |profile: GlyphProfile, variant| {
+        let mut options = Options::default();
+        options.case = Case::Upper;
+        options.variant = variant;
+        options.charset = Some(profile.charset(variant));
+        MyriadScaleInt::from(3_2105).to_chinese(&options)
+    };
+    assert_eq!(
+        MyriadScaleInt::from(3_2105).to_uppercase_trad(),
+        render(GlyphProfile::Standard, Variant::Traditional)
+    );
+    assert_eq!(
+        "弎萬弍仟弌佰零伍",
+        render(GlyphProfile::Ancient, Variant::Traditional)
+    );
+    assert_eq!(
+        "叁万贰阡壹陌零伍",
+        render(GlyphProfile::Alternative, Variant::Simplified)
+    );
+    assert_eq!(GlyphProfile::Standard, GlyphProfile::default());
+}
+
+#[test]
+fn saturating_and_wrapping() {
+    use chinese_numerals::{
+        LongScaleBigInt, LongScaleInt, LongScaleInt64, MidScaleInt, MidScaleInt32,
+        MyriadScaleBigInt, MyriadScaleInt, MyriadScaleInt32, ShortScaleInt,
+    };
+    use num_bigint::BigInt;
+
+    let _defaults = shared_defaults();
+
+    assert_eq!(
+        ShortScaleInt::MAX,
+        ShortScaleInt::saturating_from(u128::MAX)
+    );
+    assert_eq!(
+        ShortScaleInt::MIN,
+        ShortScaleInt::saturating_from(i128::MIN)
+    );
+    assert_eq!(ShortScaleInt::MIN, ShortScaleInt::saturating_from(i64::MIN));
+    assert_eq!(
+        ShortScaleInt::from(-12),
+        ShortScaleInt::saturating_from(-12_i64)
+    );
+    assert_eq!(
+        ShortScaleInt::default(),
+        ShortScaleInt::saturating_from(0_u64)
+    );
+
+    assert_eq!(
+        MyriadScaleInt::from(i128::MIN),
+        MyriadScaleInt::saturating_from(i128::MIN)
+    );
+    assert_eq!(MidScaleInt::MAX, MidScaleInt::saturating_from(u128::MAX));
+    assert_eq!(
+        MyriadScaleInt32::MAX,
+        MyriadScaleInt32::saturating_from(MyriadScaleInt::MAX)
+    );
+    assert_eq!(
+        LongScaleInt64::MIN,
+        LongScaleInt64::saturating_from(LongScaleInt::MIN)
+    );
+    assert_eq!(
+        MidScaleInt32::from(-12),
+        MidScaleInt32::saturating_from(MidScaleInt::from(-12))
+    );
+    assert_eq!(
+        "负一百七十涧一千四百一十一沟八千三百四十六穰零四百六十九秭二千三百一十七垓三千一百六十八京七千三百零三兆七千一百五十八亿八千四百一十万五千七百二十八",
+        MyriadScaleInt::from(i128::MIN).to_string()
+    );
+
+    let huge: BigInt = "1".repeat(100).parse().unwrap();
+    assert_eq!(
+        MyriadScaleBigInt::max_value(),
+        MyriadScaleBigInt::saturating_from(huge.clone())
+    );
+    assert_eq!(
+        MyriadScaleBigInt::min_value(),
+        MyriadScaleBigInt::saturating_from(-huge)
+    );
+    assert_eq!(
+        "340282366920938463463374607431768211455"
+            .parse::<LongScaleBigInt>()
+            .unwrap(),
+        LongScaleBigInt::from(u128::MAX)
+    );
+    assert_eq!(
+        "-170141183460469231731687303715884105728"
+            .parse::<MyriadScaleBigInt>()
+            .unwrap(),
+        MyriadScaleBigInt::from(i128::MIN)
+    );
+    assert_eq!(MyriadScaleBigInt::default(), MyriadScaleBigInt::from(0_i8));
+
+    assert_eq!(
+        ShortScaleInt::from(1),
+        ShortScaleInt::wrapping_from_u128(1_0000_0000_0000_0001)
+    );
+    assert_eq!(
+        ShortScaleInt::from(-1),
+        ShortScaleInt::wrapping_from_i128(-1_0000_0000_0000_0001)
+    );
+    assert_eq!(
+        ShortScaleInt::default(),
+        ShortScaleInt::wrapping_from_i128(-1_0000_0000_0000_0000)
+    );
+    // the absolute values wrap around at 10^15
+    assert_eq!(
+        ShortScaleInt::from(7),
+        ShortScaleInt::wrapping_from_u128(1000_0000_0000_0007)
+    );
+    assert_eq!(
+        ShortScaleInt::MAX,
+        ShortScaleInt::wrapping_from_u128(999_9999_9999_9999)
+    );
+    assert_eq!(
+        ShortScaleInt::default(),
+        ShortScaleInt::wrapping_from_u128(1000_0000_0000_0000)
+    );
+    assert_eq!(
+        ShortScaleInt::from(-7),
+        ShortScaleInt::wrapping_from_i128(-1000_0000_0000_0007)
+    );
+}
+
+#[test]
+fn decimal() {
+    use bigdecimal::BigDecimal;
+    use chinese_numerals::{ChineseDecimal, ErrorKind, MyriadScaleBigInt, ShortScaleInt, Variant};
+    use rust_decimal::Decimal;
+
+    let _defaults = shared_defaults();
+
+    let num: ChineseDecimal = "1,0305.070".parse().unwrap();
+    assert!(!num.is_negative());
+    assert_eq!("070", num.frac_digits());
+    assert_eq!(
+        "一万零三百零五点零七零",
+        num.to_lowercase(Variant::Simplified)
+    );
+    assert_eq!(
+        "壹萬零叄佰零伍點零柒零",
+        num.to_uppercase(Variant::Traditional)
+    );
+
+    let num: ChineseDecimal = "-0.00".parse().unwrap();
+    assert!(!num.is_negative());
+    assert_eq!("零点零零", num.to_string());
+    assert!("1.2a".parse::<ChineseDecimal>().is_err());
+
+    let num = ChineseDecimal::<MyriadScaleBigInt>::try_from(Decimal::new(-123_4567, 2)).unwrap();
+    assert_eq!("负一万二千三百四十五点六七", num.to_string());
+    let num = ChineseDecimal::<ShortScaleInt>::try_from(Decimal::new(5, 3)).unwrap();
+    assert_eq!("零点零零五", num.to_string());
+    let err = ChineseDecimal::<ShortScaleInt>::try_from(Decimal::MAX).unwrap_err();
+    assert_eq!(&ErrorKind::OutOfRange, err.kind());
+
+    let value: BigDecimal = "-98765432109876543210.5".parse().unwrap();
+    let num = ChineseDecimal::<MyriadScaleBigInt>::try_from(&value).unwrap();
+    assert_eq!(
+        "负九千八百七十六京五千四百三十二兆一千零九十八亿七千六百五十四万三千二百一十点五",
+        num.to_string()
+    );
+    let value = BigDecimal::new(12.into(), -3);
+    let num: ChineseDecimal = value.try_into().unwrap();
+    assert_eq!("一万二千", num.to_string());
+}
+
+#[test]
+fn chinese_date() {
+    use chinese_numerals::{format_date, ToChineseDate};
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use time::{Date, Month, OffsetDateTime};
+
+    let date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    assert_eq!("二〇二四年三月十五日", date.to_chinese_date());
+    assert_eq!("星期五", date.to_chinese_weekday());
+    let date = date.and_hms_opt(23, 0, 0).unwrap();
+    assert_eq!("二〇二四年三月十五日", date.to_chinese_date());
+    let date = Utc.with_ymd_and_hms(2000, 1, 2, 0, 0, 0).unwrap();
+    assert_eq!("二〇〇〇年一月二日", date.to_chinese_date());
+    assert_eq!("星期日", date.to_chinese_weekday());
+
+    let date = Date::from_calendar_date(2024, Month::December, 31).unwrap();
+    assert_eq!("二〇二四年十二月三十一日", date.to_chinese_date());
+    assert_eq!("星期二", date.to_chinese_weekday());
+    assert_eq!(
+        "一九七〇年一月一日",
+        OffsetDateTime::UNIX_EPOCH.to_chinese_date()
+    );
+    assert_eq!("星期四", OffsetDateTime::UNIX_EPOCH.to_chinese_weekday());
+
+    assert_eq!("公元前四五年三月十五日", format_date(-44, 3, 15));
+    let date = NaiveDate::from_ymd_opt(-43, 3, 15).unwrap();
+    assert_eq!("公元前四四年三月十五日", date.to_chinese_date());
+    assert_eq!("公元前一年一月一日", format_date(0, 1, 1));
+}
+
+#[test]
+fn digits_yao() {
+    use chinese_numerals::{
+        format_digits, format_digits_with, parse_two_digit_year, Case, DigitStyle, Variant,
+    };
+
+    assert_eq!(
+        "幺三八零零幺三八零零零",
+        format_digits_with(
+            "13800138000",
+            Case::Lower,
+            Variant::Simplified,
+            DigitStyle::Yao
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        "壹壹零",
+        format_digits_with("110", Case::Upper, Variant::Simplified, DigitStyle::Yao).unwrap()
+    );
+    assert_eq!(
+        format_digits("3.1", Case::Lower, Variant::Traditional),
+        format_digits_with(
+            "3.1",
+            Case::Lower,
+            Variant::Traditional,
+            DigitStyle::Standard
+        )
+    );
+    assert_eq!(Some(2011), parse_two_digit_year("幺幺", 1950));
+}
+
+#[test]
+fn charset_overrides() {
+    use chinese_numerals::{
+        Case, CharacterSet, ChineseNumeral, MyriadScaleInt, NumChar, Options, Variant,
+    };
+    use std::collections::HashMap;
+
+    let overrides = HashMap::from([(NumChar::Three, '参'), (NumChar::Bai, '陌')]);
+    let charset = CharacterSet::new(Case::Upper, Variant::Simplified).with_overrides(&overrides);
+    assert_eq!("参", charset.get(NumChar::Three));
+    assert_eq!("贰", charset.get(NumChar::Two));
+    let mut options = Options::default();
+    options.case = Case::Upper;
+    options.charset = Some(charset);
+    assert_eq!("参陌贰拾参", MyriadScaleInt::from(323).to_chinese(&options));
+
+    let charset = CharacterSet::new(Case::Lower, Variant::Simplified);
+    assert_eq!(charset.clone(), charset.with_overrides(&HashMap::new()));
+}
+
+#[test]
+fn ssml() {
+    use chinese_numerals::{
+        speech_tokens, to_ssml, to_ssml_sub, ChineseNumeral, LongScaleInt, NumChar,
+    };
+
+    assert_eq!("zhao4", NumChar::Zhao.pinyin());
+    assert_eq!("zheng4", NumChar::Pos.pinyin());
+
+    let tokens = speech_tokens("负幺〇正x");
+    assert_eq!(
+        vec![
+            Some("fu4"),
+            Some("yao1"),
+            Some("ling2"),
+            Some("zheng4"),
+            None
+        ],
+        tokens.iter().map(|token| token.pinyin).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![false, false, false, true, false],
+        tokens
+            .iter()
+            .map(|token| token.needs_hint)
+            .collect::<Vec<_>>()
+    );
+
+    let numeral = LongScaleInt::from(1_0000_0000_0000_0000_u64).to_lowercase_trad();
+    assert_eq!("一兆", numeral);
+    assert_eq!(
+        r#"一<phoneme alphabet="x-pinyin" ph="zhao4">兆</phoneme>"#,
+        to_ssml(&numeral)
+    );
+    assert_eq!("一万二千", to_ssml("一万二千"));
+    assert_eq!("a&amp;b", to_ssml("a&b"));
+    assert_eq!(
+        r#"<sub alias="三&lt;">&quot;3&quot;</sub>"#,
+        to_ssml_sub("\"3\"", "三<")
+    );
+}
+
+#[test]
+fn full_width() {
+    use chinese_numerals::{
+        ChineseNumeral, Formatter, MidScaleInt, MyriadScaleInt, Options, Style, Variant, ZeroStyle,
+    };
+
+    let mut options = Options::default();
+    options.style = Style::FullWidth;
+    assert_eq!(
+        "－１２０５",
+        MyriadScaleInt::from(-1205).to_chinese(&options)
+    );
+    assert_eq!("０", MyriadScaleInt::from(0).to_chinese(&options));
+
+    let mut options = Options::default();
+    options.style = Style::FullWidthWithUnits;
+    options.variant = Variant::Japanese;
+    assert_eq!(
+        "３億５０００万",
+        MyriadScaleInt::from(3_5000_0000).to_chinese(&options)
+    );
+    assert_eq!(
+        "１億５",
+        MyriadScaleInt::from(1_0000_0005).to_chinese(&options)
+    );
+    assert_eq!(
+        "１万億２",
+        MidScaleInt::from(1_0000_0000_0002_u64).to_chinese(&options)
+    );
+
+    let mut options = Options::default();
+    options.style = Style::FullWidthWithUnits;
+    options.positive_sign = true;
+    options.zero_style = ZeroStyle::Empty;
+    let mut formatter = Formatter::new(options);
+    let mut output = String::new();
+    formatter.format_into(&mut output, &MyriadScaleInt::from(12_0300));
+    formatter.format_into(&mut output, &MyriadScaleInt::from(0));
+    assert_eq!("＋１２万３００", output);
+}
+
+#[test]
+fn vertical() {
+    use chinese_numerals::{
+        format_mixed, to_vertical, ChineseNumeral, MixedStyle, MyriadScaleInt, VerticalLayout,
+    };
+
+    let numeral = MyriadScaleInt::from(305).to_lowercase_simp();
+    assert_eq!(
+        vec![vec!["三", "百", "零", "五"]],
+        to_vertical(&numeral, &VerticalLayout::default())
+    );
+    assert!(to_vertical("", &VerticalLayout::default()).is_empty());
+
+    let mixed = format_mixed(&MyriadScaleInt::from(12345), &MixedStyle::default());
+    let layout = VerticalLayout {
+        column_height: Some(4),
+        group_digits: 3,
+        presentation_forms: false,
+    };
+    assert_eq!(
+        vec![
+            vec!["1", ",", "234", "5"],
+            vec!["（", "一", "万", "二"],
+            vec!["千", "三", "百", "四"],
+            vec!["十", "五", "）"],
+        ],
+        to_vertical(&mixed, &layout)
+    );
+}
+
+#[test]
+fn char_len() {
+    use chinese_numerals::{
+        Case, CharacterSet, ChineseNumeral, LongScaleBigInt, MyriadScaleInt, NumChar, Options,
+        Style, Token, Variant, ZeroStyle,
+    };
+    use std::sync::Arc;
+
+    let charset = CharacterSet::new(Case::Lower, Variant::Simplified).with(NumChar::Wan, "wan");
+    let mut options_list = vec![
+        Options::default(),
+        {
+            let mut options = Options::default();
+            options.case = Case::Upper;
+            options.variant = Variant::Traditional;
+            options.positive_sign = true;
+            options
+        },
+        {
+            let mut options = Options::default();
+            options.variant = Variant::Japanese;
+            options.zero_style = ZeroStyle::Empty;
+            options
+        },
+        {
+            let mut options = Options::default();
+            options.charset = Some(charset.clone());
+            options
+        },
+        {
+            let mut options = Options::default();
+            options.style = Style::IcuCompatible;
+            options
+        },
+        {
+            let mut options = Options::default();
+            options.style = Style::FullWidthWithUnits;
+            options
+        },
+        {
+            let mut options = Options::default();
+            options.middlewares = vec![Arc::new(|tokens: &mut Vec<Token>| tokens.reverse())];
+            options
+        },
+    ];
+    for max in [0, 1, 3, 100] {
+        options_list.push({
+            let mut options = Options::default();
+            options.max_output_chars = Some(max);
+            options
+        });
+        options_list.push({
+            let mut options = Options::default();
+            options.max_output_chars = Some(max);
+            options.charset = Some(charset.clone());
+            options
+        });
+    }
+    for options in &options_list {
+        for value in [0_i64, 1, -10, 15, 10305, -1_0000_0000_0001, 1234_5678_9012] {
+            let num = MyriadScaleInt::from(value);
+            let output = num.to_chinese(options);
+            assert_eq!(output.chars().count(), num.char_len(options));
+            assert_eq!(output.len(), num.byte_len(options));
+        }
+    }
+
+    let num: LongScaleBigInt = "1".repeat(60).parse().unwrap();
+    assert_eq!(
+        num.to_chinese(&Options::default()).chars().count(),
+        num.char_len(&Options::default())
+    );
+}
+
+#[test]
+fn truncated() {
+    use chinese_numerals::{ChineseNumeral, LongScaleBigInt, MidScaleInt, MyriadScaleInt, Variant};
+
+    let num = MyriadScaleInt::from(-12_0000_0305);
+    assert_eq!(
+        "负十二亿零三百零五",
+        num.to_lowercase_truncated(Variant::Simplified, 9)
+    );
+    assert_eq!(
+        "负十二亿余",
+        num.to_lowercase_truncated(Variant::Simplified, 8)
+    );
+    assert_eq!(
+        "负十二亿余",
+        num.to_lowercase_truncated(Variant::Simplified, 5)
+    );
+    assert_eq!(
+        "负十二…",
+        num.to_lowercase_truncated(Variant::Simplified, 4)
+    );
+    assert_eq!("…", num.to_lowercase_truncated(Variant::Simplified, 1));
+    assert_eq!("", num.to_lowercase_truncated(Variant::Simplified, 0));
+
+    // never cuts between 万 and 亿 of mid-scale
+    let num = MidScaleInt::from(1_0000_0000_0001_u64);
+    assert_eq!("一万亿零一", num.to_lowercase_simp());
+    assert_eq!("一万…", num.to_lowercase_truncated(Variant::Simplified, 3));
+    assert_eq!(
+        "一万亿余",
+        num.to_lowercase_truncated(Variant::Simplified, 4)
+    );
+
+    let num: LongScaleBigInt = "3".repeat(40).parse().unwrap();
+    let truncated = num.to_lowercase_truncated(Variant::Simplified, 12);
+    assert!(truncated.chars().count() <= 12);
+    assert!(truncated.ends_with('余'));
+}
+
+#[test]
+fn zero_policy() {
+    use chinese_numerals::{
+        ChineseNumeral, MidScaleInt, MyriadScaleInt, Options, ShortScaleInt, ZeroPolicy,
+    };
+
+    let options = |zero_policy| {
+        let mut options = Options::default();
+        options.zero_policy = zero_policy;
+        options
+    };
+    let collapse = options(ZeroPolicy::Collapse);
+    let omit = options(ZeroPolicy::Omit);
+    let verbose = options(ZeroPolicy::Verbose);
+
+    let num = MyriadScaleInt::from(10305);
+    assert_eq!("一万零三百零五", num.to_chinese(&collapse));
+    assert_eq!("一万三百五", num.to_chinese(&omit));
+    assert_eq!("一万零三百零五", num.to_chinese(&verbose));
+
+    let num = MyriadScaleInt::from(-1005);
+    assert_eq!("负一千零零五", num.to_chinese(&verbose));
+    assert_eq!("负一千五", num.to_chinese(&omit));
+
+    let num = MyriadScaleInt::from(1_0000_0050);
+    assert_eq!("一亿零五十", num.to_chinese(&collapse));
+    assert_eq!("一亿零零零零零零五十", num.to_chinese(&verbose));
+
+    let num = MidScaleInt::from(1_0000_0000_0001_u64);
+    assert_eq!("一万亿零一", num.to_chinese(&collapse));
+    assert_eq!(
+        format!("一万亿{}一", "零".repeat(11)),
+        num.to_chinese(&verbose)
+    );
+
+    let num = ShortScaleInt::from(10_0203);
+    assert_eq!("一亿零二百零三", num.to_chinese(&collapse));
+    assert_eq!("一亿零零二百零三", num.to_chinese(&verbose));
+
+    let zero = MyriadScaleInt::from(0);
+    assert_eq!("零", zero.to_chinese(&omit));
+    assert_eq!("零", zero.to_chinese(&verbose));
+}
+
+#[test]
+fn colloquial() {
+    use chinese_numerals::{
+        ChineseNumeral, MidScaleInt, MyriadScaleInt, Options, ShortScaleInt, Variant,
+    };
+
+    let mut options = Options::default();
+    options.colloquial = true;
+    let colloquial = |num: i64| MyriadScaleInt::from(num).to_chinese(&options);
+    assert_eq!("三万五", colloquial(35_000));
+    assert_eq!("一千二", colloquial(1_200));
+    assert_eq!("负三万五", colloquial(-35_000));
+    assert_eq!("一亿二", colloquial(1_2000_0000));
+    assert_eq!("十五万", colloquial(15_0000));
+    assert_eq!("一千五百万", colloquial(1500_0000));
+    assert_eq!("一百零五", colloquial(105));
+    assert_eq!("一万零五百", colloquial(1_0500));
+    assert_eq!("五千", colloquial(5000));
+    assert_eq!("零", colloquial(0));
+    assert_eq!(
+        "三万五千",
+        MyriadScaleInt::from(35_000).to_lowercase(Variant::Simplified)
+    );
+
+    let mut upper = options.clone();
+    upper.case = chinese_numerals::Case::Upper;
+    upper.strictness = chinese_numerals::Strictness::Lenient;
+    assert_eq!("叁万伍", MyriadScaleInt::from(35_000).to_chinese(&upper));
+    assert_eq!("一千二", ShortScaleInt::from(1200).to_chinese(&options));
+
+    let num = MidScaleInt::from(1_2000_0000_0000_u64);
+    assert_eq!("一万二千亿", num.to_lowercase(Variant::Simplified));
+    assert_eq!("一万二千亿", num.to_chinese(&options));
+    assert_eq!(Some(12), num.explain().steps.first().map(|step| step.exp));
+    let num = MidScaleInt::from(3_5000_0000_u64);
+    assert_eq!("三亿五", num.to_chinese(&options));
+}
+
+#[test]
+fn scale_units() {
+    use chinese_numerals::{units, ChineseNumeral, MyriadScaleInt, NumChar, Scale, ShortScaleInt};
+
+    for scale in [Scale::Short, Scale::Myriad, Scale::Mid, Scale::Long] {
+        let units = units(scale);
+        assert_eq!(11, units.len());
+        assert_eq!(NumChar::Wan, units[0].char);
+        assert_eq!(NumChar::Zai, units[10].char);
+        assert_eq!(4, units[0].exponent);
+        assert!(units.windows(2).all(|w| w[0].exponent < w[1].exponent));
+    }
+    let exps = |scale| units(scale).iter().map(|u| u.exponent).collect::<Vec<_>>();
+    assert_eq!(
+        vec![4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14],
+        exps(Scale::Short)
+    );
+    assert_eq!(
+        vec![4, 8, 12, 16, 20, 24, 28, 32, 36, 40, 44],
+        exps(Scale::Myriad)
+    );
+    assert_eq!(
+        vec![4, 8, 16, 24, 32, 40, 48, 56, 64, 72, 80],
+        exps(Scale::Mid)
+    );
+    assert_eq!(
+        vec![4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096],
+        exps(Scale::Long)
+    );
+
+    let zhao = units(Scale::Short)[2];
+    assert_eq!(
+        "一兆",
+        ShortScaleInt::try_from(10_i64.pow(zhao.exponent))
+            .unwrap()
+            .to_lowercase_simp()
+    );
+    let jing = units(Scale::Myriad)[3];
+    assert_eq!(
+        "一京",
+        MyriadScaleInt::from(10_i64.pow(jing.exponent)).to_lowercase_simp()
+    );
+}
+
+#[test]
+fn generic_scale() {
+    use chinese_numerals::{
+        ChineseInt, ChineseNumeral, Long, Mid, Myriad, MyriadScaleInt, ScaleInt, ScaleKind, Short,
+        ShortScaleInt, Sign,
+    };
+
+    fn lower<S: ScaleKind>(num: ChineseInt<S>) -> String {
+        num.to_lowercase_simp()
+    }
+
+    let num: ChineseInt<Myriad> = MyriadScaleInt::from(1_0000_0000);
+    assert_eq!("一亿", lower(num));
+    assert_eq!("一垓", lower(ChineseInt::<Short>::from(1_0000_0000)));
+    assert_eq!("一亿", lower(ChineseInt::<Mid>::from(1_0000_0000)));
+    assert_eq!(
+        "一万亿",
+        lower(ChineseInt::<Mid>::from(1_0000_0000_0000_u64))
+    );
+    assert_eq!("一亿", lower(ChineseInt::<Long>::from(1_0000_0000)));
+    assert!(ChineseInt::<Long>::from(-3).is_negative());
+
+    let num = ChineseInt::<Short>::new(Sign::Neg, 12);
+    assert_eq!(ShortScaleInt::from(-12), num);
+    assert!(ChineseInt::<Short>::try_new(Sign::Pos, u64::MAX).is_err());
+}
+
+#[test]
+fn big_int_from_sign_and_biguint() {
+    use chinese_numerals::{
+        ChineseNumeral, LongScaleBigInt, MidScaleBigInt, MyriadScaleBigInt, Sign,
+    };
+    use num_bigint::{BigInt, BigUint};
+
+    let abs = BigUint::from(1_0000_0000_u32);
+    let num = MidScaleBigInt::new(Sign::Neg, abs.clone());
+    assert_eq!("负一亿", num.to_lowercase_simp());
+    assert_eq!(
+        MidScaleBigInt::try_from(-BigInt::from(abs.clone())).unwrap(),
+        num
+    );
+    assert_eq!(num, MidScaleBigInt::from_biguint_neg(abs.clone()).unwrap());
+    assert_eq!(
+        MyriadScaleBigInt::from(0),
+        MyriadScaleBigInt::new(Sign::Neg, BigUint::default())
+    );
+    assert_eq!(
+        "负一亿",
+        LongScaleBigInt::from_biguint_neg(abs)
+            .unwrap()
+            .to_lowercase_simp()
+    );
+
+    let too_large = BigUint::from(10_u32).pow(48);
+    assert!(MyriadScaleBigInt::from_biguint_neg(too_large).is_err());
+}
+
+#[test]
+fn convert_batch() {
+    use chinese_numerals::{convert_batch, Case, ChineseNumeral, MyriadScaleInt, Options};
+
+    let values: Vec<i128> = (-1000..1000)
+        .map(|n| n * 7919)
+        .chain([i128::MIN, i128::MAX])
+        .collect();
+    let mut options = Options::default();
+    options.case = Case::Upper;
+    options.max_output_chars = Some(6);
+    let expected: Vec<String> = values
+        .iter()
+        .map(|&n| MyriadScaleInt::from(n).to_chinese(&options))
+        .collect();
+    assert_eq!(expected, convert_batch(&values, &options));
+    assert!(convert_batch(&[], &options).is_empty());
+}
+
+#[test]
+fn sign_display_and_conversions() {
+    use chinese_numerals::{NumChar, Sign};
+    use std::cmp::Ordering;
+
+    assert_eq!("正", Sign::Pos.to_string());
+    assert_eq!("负", Sign::Neg.to_string());
+    assert_eq!("負", format!("{:-}", Sign::Neg));
+    assert_eq!("", Sign::Nil.to_string());
+    assert_eq!("[ 负]", format!("[{:>2}]", Sign::Neg));
+    assert_eq!(Some(NumChar::Neg), Sign::Neg.to_num_char());
+    assert_eq!(None, Sign::Nil.to_num_char());
+
+    for sign in [Sign::Neg, Sign::Nil, Sign::Pos] {
+        assert_eq!(sign, Sign::from(Ordering::from(sign)));
+        assert_eq!(sign, Sign::from(num_bigint::Sign::from(sign)));
+    }
+    assert_eq!(Sign::Neg, Sign::from((-5_i32).cmp(&0)));
+    assert_eq!(Sign::Pos, Sign::from(num_bigint::BigInt::from(5).sign()));
+}
+
+#[test]
+fn leading_one_style() {
+    use chinese_numerals::{
+        ChineseNumeral, LeadingOneStyle, MyriadScaleInt, Options, ShortScaleInt,
+    };
+
+    let mut keep = Options::default();
+    keep.leading_one = LeadingOneStyle::Keep;
+    let omit = Options::default();
+    assert_eq!("十二", MyriadScaleInt::from(12).to_chinese(&omit));
+    assert_eq!("一十二", MyriadScaleInt::from(12).to_chinese(&keep));
+    assert_eq!("负一十", MyriadScaleInt::from(-10).to_chinese(&keep));
+    assert_eq!(
+        "一十万零一十二",
+        MyriadScaleInt::from(10_0012).to_chinese(&keep)
+    );
+    assert_eq!(
+        "十万零一十二",
+        MyriadScaleInt::from(10_0012).to_chinese(&omit)
+    );
+    assert_eq!("一十二", ShortScaleInt::from(12).to_chinese(&keep));
+    assert_eq!("一百一十", MyriadScaleInt::from(110).to_chinese(&keep));
+    assert_eq!(3, MyriadScaleInt::from(12).char_len(&keep));
+}
+
+#[test]
+fn upper_leading_one_style() {
+    use chinese_numerals::{
+        Case, ChineseNumeral, LeadingOneStyle, MyriadScaleInt, Options, Strictness, Variant,
+    };
+
+    let mut upper = Options::default();
+    upper.case = Case::Upper;
+    let mut trimmed = upper.clone();
+    trimmed.upper_leading_one = Some(LeadingOneStyle::Omit);
+    trimmed.strictness = Strictness::Lenient;
+    assert_eq!("壹拾贰", MyriadScaleInt::from(12).to_chinese(&upper));
+    assert_eq!("拾贰", MyriadScaleInt::from(12).to_chinese(&trimmed));
+    // a set style applies under the standard strictness too
+    let mut standard = trimmed.clone();
+    standard.strictness = Strictness::Standard;
+    assert_eq!("拾贰", MyriadScaleInt::from(12).to_chinese(&standard));
+    assert_eq!(
+        "拾貳萬零壹拾",
+        MyriadScaleInt::from(12_0010).to_chinese(&{
+            let mut options = trimmed.clone();
+            options.variant = Variant::Traditional;
+            options
+        })
+    );
+    // each case has its own style
+    let mut lower = Options::default();
+    lower.upper_leading_one = Some(LeadingOneStyle::Omit);
+    assert_eq!("十二", MyriadScaleInt::from(12).to_chinese(&lower));
+    let mut lower = Options::default();
+    lower.leading_one = LeadingOneStyle::Keep;
+    assert_eq!(
+        "壹拾贰",
+        MyriadScaleInt::from(12).to_chinese(&{
+            let mut options = lower.clone();
+            options.case = Case::Upper;
+            options
+        })
+    );
+    assert_eq!("一十二", MyriadScaleInt::from(12).to_chinese(&lower));
+}
+
+#[test]
+fn decimal_str() {
+    use chinese_numerals::{
+        ChineseNumeral, DecimalStr, Long, LongScaleBigInt, Mid, MidScaleBigInt, Myriad,
+        MyriadScaleBigInt, MyriadScaleInt, Options, Short, ShortScaleInt,
+    };
+    use proptest::prelude::*;
+
+    let _defaults = shared_defaults();
+
+    let num: DecimalStr = "-0012".parse().unwrap();
+    assert_eq!("负十二", num.to_lowercase_simp());
+    assert_eq!("12", num.digits());
+    assert_eq!("负十二", num.to_string());
+    let zero = DecimalStr::<Short>::from_decimal_str("-000").unwrap();
+    assert_eq!("零", zero.to_lowercase_simp());
+    assert_eq!("0", zero.digits());
+    assert_eq!(
+        ShortScaleInt::MAX.to_lowercase_simp(),
+        DecimalStr::<Short>::from_decimal_str(&"9".repeat(15))
+            .unwrap()
+            .to_lowercase_simp()
+    );
+    assert!(DecimalStr::<Short>::from_decimal_str(&"9".repeat(16)).is_err());
+    assert!(DecimalStr::<Mid>::from_decimal_str(&"9".repeat(88)).is_ok());
+    assert!(DecimalStr::<Mid>::from_decimal_str(&"9".repeat(89)).is_err());
+    assert!(DecimalStr::<Myriad>::from_decimal_str("12a").is_err());
+    assert_eq!(
+        MyriadScaleInt::from(i128::MIN).to_uppercase_trad(),
+        DecimalStr::<Myriad>::from_decimal_str(&i128::MIN.to_string())
+            .unwrap()
+            .to_uppercase_trad()
+    );
+
+    let options = Options::default();
+    proptest!(|(digits in "[1-9][0-9]{0,47}|[1-9]0{30,45}[1-9]?|[1-9]{1,3}0{20,40}[1-9]{0,4}")| {
+        let myriad = DecimalStr::<Myriad>::from_decimal_str(&digits).unwrap();
+        let big: MyriadScaleBigInt = digits.parse().unwrap();
+        prop_assert_eq!(big.to_chinese(&options), myriad.to_chinese(&options));
+        let mid = DecimalStr::<Mid>::from_decimal_str(&digits).unwrap();
+        let big: MidScaleBigInt = digits.parse().unwrap();
+        prop_assert_eq!(big.to_chinese(&options), mid.to_chinese(&options));
+        let long = DecimalStr::<Long>::from_decimal_str(&digits).unwrap();
+        let big: LongScaleBigInt = digits.parse().unwrap();
+        prop_assert_eq!(big.to_uppercase_simp(), long.to_uppercase_simp());
+    });
+    let digits = format!("1{}", "0".repeat(100));
+    for digits in [
+        digits.clone(),
+        format!("{digits}5"),
+        format!("35{}", "0".repeat(500)),
+    ] {
+        let long = DecimalStr::<Long>::from_decimal_str(&digits).unwrap();
+        let big: LongScaleBigInt = digits.parse().unwrap();
+        assert_eq!(big.to_lowercase_simp(), long.to_lowercase_simp());
+    }
+}
+
+#[test]
+fn from_sci() {
+    use chinese_numerals::{
+        ChineseNumeral, LongScaleBigInt, LongScaleInt, MidScaleBigInt, MidScaleInt,
+        MyriadScaleBigInt, MyriadScaleInt, ShortScaleInt,
+    };
+
+    assert_eq!(
+        MidScaleInt::from(3_000_000_000_000_000_i64),
+        MidScaleInt::from_sci(3, 15).unwrap()
+    );
+    assert_eq!(
+        MyriadScaleInt::from(-120),
+        MyriadScaleInt::from_sci(-12, 1).unwrap()
+    );
+    assert_eq!(
+        MyriadScaleInt::from(0),
+        MyriadScaleInt::from_sci(0, 300).unwrap()
+    );
+    assert_eq!(
+        ShortScaleInt::MAX,
+        ShortScaleInt::from_sci(999_9999_9999_9999, 0).unwrap()
+    );
+    assert!(ShortScaleInt::from_sci(10, 15).is_err());
+    assert!(LongScaleInt::from_sci(1, 39).is_err());
+    assert!(LongScaleInt::from_sci(i128::MAX, 1).is_err());
+    let err = MyriadScaleInt::from_sci(5, 100).unwrap_err();
+    assert_eq!(Some("5e100"), err.value());
+    let err = MyriadScaleInt::from_sci(-5, 100).unwrap_err();
+    assert_eq!(Some("5e100"), err.value());
+    assert_eq!(Some(100), err.magnitude());
+    assert_eq!(Some(53), err.orders_over());
+    assert_eq!(
+        Some(17),
+        ShortScaleInt::from_sci(12, 16).unwrap_err().magnitude()
+    );
+    let err = MidScaleBigInt::from_sci(1, u32::MAX).unwrap_err();
+    assert_eq!(Some(u32::MAX as usize), err.magnitude());
+
+    assert_eq!(
+        "一载",
+        MyriadScaleBigInt::from_sci(1, 44)
+            .unwrap()
+            .to_lowercase_simp()
+    );
+    assert_eq!(
+        "负九千九百九十九载",
+        MyriadScaleBigInt::from_sci(-9999, 44)
+            .unwrap()
+            .to_lowercase_simp()
+    );
+    assert!(MyriadScaleBigInt::from_sci(1, 48).is_err());
+    assert!(MidScaleBigInt::from_sci(1, u32::MAX).is_err());
+    assert_eq!(
+        "一载",
+        MidScaleBigInt::from_sci(1, 80).unwrap().to_lowercase_simp()
+    );
+    assert_eq!(
+        "一载",
+        LongScaleBigInt::from_sci(1, 4096)
+            .unwrap()
+            .to_lowercase_simp()
+    );
+    assert_eq!(
+        MyriadScaleBigInt::from(0),
+        MyriadScaleBigInt::from_sci(0, 1000).unwrap()
+    );
+}
+
+#[test]
+fn reference_oracle() {
+    use chinese_numerals::{reference, Case, Scale, Variant};
+    use num_bigint::{BigInt, BigUint, Sign};
+    use proptest::prelude::*;
+
+    let scales = [Scale::Short, Scale::Myriad, Scale::Mid, Scale::Long];
+    assert_eq!(
+        "壹拾萬零壹拾",
+        reference::to_chinese("100010", Scale::Myriad, Case::Upper, Variant::Traditional)
+    );
+    assert_eq!(
+        "十万零一十",
+        reference::to_chinese("00100010", Scale::Myriad, Case::Lower, Variant::Simplified)
+    );
+    assert_eq!(
+        "零",
+        reference::to_chinese("-0", Scale::Long, Case::Lower, Variant::Simplified)
+    );
+    for scale in scales {
+        for value in [
+            0,
+            1,
+            -10,
+            19,
+            100,
+            1_0001,
+            10_0000,
+            1000_0000_0001,
+            u64::MAX as i128,
+        ] {
+            reference::check_i128(value, scale).unwrap();
+        }
+        reference::check_u128(u128::MAX, scale).unwrap();
+    }
+
+    proptest!(|(value: u128, shift in 0..128_u32)| {
+        for scale in scales {
+            prop_assert_eq!(Ok(()), reference::check_u128(value >> shift, scale));
+            prop_assert_eq!(Ok(()), reference::check_i128((value >> shift) as i128, scale));
+        }
+    });
+    proptest!(|(bytes in prop::collection::vec(any::<u8>(), 0..40), neg: bool, zeros in 0..40_usize)| {
+        let abs = BigUint::from_bytes_le(&bytes) * BigUint::from(10_u32).pow(zeros as u32);
+        let value = BigInt::from_biguint(if neg { Sign::Minus } else { Sign::Plus }, abs);
+        for scale in scales {
+            prop_assert_eq!(Ok(()), reference::check_bigint(&value, scale));
+        }
+    });
+}
+
+#[test]
+fn arbitrary_round_trip() {
+    use arbitrary::{Arbitrary, Unstructured};
+    use chinese_numerals::{
+        ChineseNumeral, DecimalStr, LongScaleBigInt, LongScaleInt, MidScaleBigInt, MyriadScaleInt,
+        Short, ShortScaleInt,
+    };
+
+    fn round_trip<T>(u: &mut Unstructured) -> T
+    where
+        T: for<'a> Arbitrary<'a>
+            + ChineseNumeral
+            + PartialEq
+            + std::fmt::Debug
+            + for<'a> TryFrom<&'a str>,
+    {
+        let num = T::arbitrary(u).unwrap();
+        for numeral in [num.to_lowercase_simp(), num.to_uppercase_trad()] {
+            assert!(
+                T::try_from(numeral.as_str()).is_ok_and(|parsed| parsed == num),
+                "{numeral}"
+            );
+        }
+        num
+    }
+
+    let bytes: Vec<u8> = (0..4096_u32)
+        .map(|i| (i.wrapping_mul(2654435761) >> 13) as u8)
+        .collect();
+    let mut u = Unstructured::new(&bytes);
+    while !u.is_empty() {
+        let short: ShortScaleInt = round_trip(&mut u);
+        assert!(ShortScaleInt::MIN <= short && short <= ShortScaleInt::MAX);
+        round_trip::<MyriadScaleInt>(&mut u);
+        round_trip::<LongScaleInt>(&mut u);
+        round_trip::<MidScaleBigInt>(&mut u);
+        let long: LongScaleBigInt = round_trip(&mut u);
+        assert!(LongScaleBigInt::min_value() <= long && long <= LongScaleBigInt::max_value());
+        let digits = DecimalStr::<Short>::arbitrary(&mut u).unwrap();
+        assert!(digits.digits().len() <= 15);
+    }
+}
+
+#[test]
+fn negative_style() {
+    use chinese_numerals::{
+        Case, ChineseNumeral, MyriadScaleInt, NegativeStyle, Options, Style, Variant,
+    };
+
+    let options = |negative_style| {
+        let mut options = Options::default();
+        options.negative_style = negative_style;
+        options
+    };
+    let num = MyriadScaleInt::from(-350);
+    assert_eq!("负三百五十", num.to_chinese(&options(NegativeStyle::Fu)));
+    assert_eq!("欠三百五十", num.to_chinese(&options(NegativeStyle::Qian)));
+    assert_eq!("减三百五十", num.to_chinese(&options(NegativeStyle::Jian)));
+    assert_eq!(
+        "減參佰伍拾",
+        num.to_chinese(&{
+            let mut options = options(NegativeStyle::Jian);
+            options.case = Case::Upper;
+            options.variant = Variant::Traditional;
+            options.style = Style::IcuCompatible;
+            options
+        })
+    );
+    let custom = options(NegativeStyle::Custom("零下".to_owned()));
+    assert_eq!("零下三百五十", num.to_chinese(&custom));
+    assert_eq!(6, num.char_len(&custom));
+    assert_eq!(
+        "零下三百",
+        num.to_chinese(&{
+            let mut options = custom.clone();
+            options.max_output_chars = Some(4);
+            options
+        })
+    );
+    assert_eq!("三百五十", MyriadScaleInt::from(350).to_chinese(&custom));
+}
+
+#[test]
+fn magnitude() {
+    use chinese_numerals::{
+        ChineseNumeral, LongScaleInt, MidScaleInt, MyriadScaleInt, NumChar, ShortScaleInt,
+    };
+
+    let num = MyriadScaleInt::from(-1_2345_6789_i64);
+    assert_eq!(Some(NumChar::Yi), num.highest_unit());
+    assert_eq!(8, num.magnitude());
+    assert_eq!(Some(NumChar::Shi), MyriadScaleInt::from(10).highest_unit());
+    assert_eq!(1, MyriadScaleInt::from(10).magnitude());
+    assert_eq!(3, ShortScaleInt::from(9999).magnitude());
+    assert_eq!(None, MyriadScaleInt::default().highest_unit());
+    assert_eq!(0, MyriadScaleInt::default().magnitude());
+
+    let num = 10_u64.pow(16);
+    assert_eq!(
+        Some(NumChar::Jing),
+        MyriadScaleInt::from(num).highest_unit()
+    );
+    assert_eq!(16, MyriadScaleInt::from(num).magnitude());
+    assert_eq!(Some(NumChar::Zhao), MidScaleInt::from(num).highest_unit());
+    assert_eq!(16, MidScaleInt::from(num).magnitude());
+    assert_eq!(Some(NumChar::Zhao), LongScaleInt::from(num).highest_unit());
+    assert_eq!(16, LongScaleInt::from(num).magnitude());
+    assert_eq!(14, ShortScaleInt::MAX.magnitude());
+    assert_eq!(Some(NumChar::Zai), ShortScaleInt::MAX.highest_unit());
+}
+
+#[test]
+fn compact_ints() {
+    use chinese_numerals::{
+        Case, ChineseNumeral, LongScaleInt, LongScaleInt64, MidScaleInt32, MyriadScaleInt,
+        MyriadScaleInt32, MyriadScaleInt64, ScaleInt, Variant,
+    };
+
+    let _defaults = shared_defaults();
+
+    assert_eq!(8, std::mem::size_of::<MyriadScaleInt32>());
+    assert!(std::mem::size_of::<MyriadScaleInt64>() < std::mem::size_of::<MyriadScaleInt>());
+
+    let num = MyriadScaleInt32::from(-1_0203_0405);
+    assert_eq!("负一亿零二百零三万零四百零五", num.to_lowercase_simp());
+    assert_eq!(
+        MyriadScaleInt::from(-1_0203_0405).to_uppercase_trad(),
+        num.to_uppercase_trad()
+    );
+    assert_eq!("负一亿零二百零三万零四百零五", format!("{num}"));
+    assert_eq!(
+        num.to_lowercase_simp(),
+        num.to_array_string(Case::Lower, Variant::Simplified)
+            .as_str()
+    );
+    assert_eq!(
+        MyriadScaleInt::from(-1_0203_0405),
+        MyriadScaleInt::from(num)
+    );
+    assert_eq!(
+        Ok(num),
+        MyriadScaleInt32::try_from(MyriadScaleInt::from(-1_0203_0405))
+    );
+    assert!(MyriadScaleInt32::try_from(MyriadScaleInt::from(u64::MAX)).is_err());
+
+    assert_eq!(u32::MAX, MidScaleInt32::MAX.into_parts().1);
+    assert!(
+        MidScaleInt32::MIN < MidScaleInt32::from(0) && MidScaleInt32::from(0) < MidScaleInt32::MAX
+    );
+    assert_eq!(Ok(MidScaleInt32::from(350)), "350".parse());
+    assert!("4294967296".parse::<MidScaleInt32>().is_err());
+    assert_eq!(
+        Ok(MidScaleInt32::from(35000)),
+        MidScaleInt32::try_from("三万五千")
+    );
+
+    let num = LongScaleInt64::from(u64::MAX);
+    assert_eq!(
+        LongScaleInt::from(u64::MAX).to_lowercase_simp(),
+        num.to_lowercase_simp()
+    );
+    assert_eq!(
+        Some(num),
+        LongScaleInt64::from_sci(18446744073709551615, 0).ok()
+    );
+}
+
+#[test]
+fn char_classification() {
+    use chinese_numerals::{char_value, is_digit_char, is_unit_char};
+
+    for (ch, value) in "零一二三四五六七八九".chars().zip(0..) {
+        assert!(is_digit_char(ch) && !is_unit_char(ch));
+        assert_eq!(Some(value), char_value(ch));
+    }
+    for (ch, value) in "零壹贰叁肆伍陆柒捌玖".chars().zip(0..) {
+        assert_eq!(Some(value), char_value(ch));
+    }
+    for (ch, value) in "零壹貳叄肆伍陸柒捌玖".chars().zip(0..) {
+        assert_eq!(Some(value), char_value(ch));
+    }
+    assert_eq!(Some(2), char_value('两'));
+    assert_eq!(Some(0), char_value('〇'));
+
+    for (ch, value) in [
+        ('十', 10),
+        ('拾', 10),
+        ('佰', 100),
+        ('千', 1000),
+        ('仟', 1000),
+    ] {
+        assert!(is_unit_char(ch) && !is_digit_char(ch));
+        assert_eq!(Some(value), char_value(ch));
+    }
+    for (i, ch) in "万亿兆京垓秭穰沟涧".chars().enumerate() {
+        assert!(is_unit_char(ch));
+        assert_eq!(Some(10_u128.pow(4 * (i as u32 + 1))), char_value(ch));
+    }
+    assert_eq!(char_value('溝'), char_value('沟'));
+    assert!(is_unit_char('载') && char_value('载').is_none());
+
+    for ch in ['负', '點', 'a', '1', '年'] {
+        assert!(!is_digit_char(ch) && !is_unit_char(ch));
+        assert_eq!(None, char_value(ch));
+    }
+}
+
+#[test]
+fn numbers_in_text() {
+    use chinese_numerals::{convert_numbers_in_text, Case, Options, Variant};
+
+    let options = Options::default();
+    let convert = |s| convert_numbers_in_text(s, &options);
+    assert_eq!("共一百二十三人", convert("共123人"));
+    assert_eq!(
+        "增长百分之五，下降负百分之零点五",
+        convert("增长5%，下降-0.5%")
+    );
+    assert_eq!("圆周率约三点一四一五", convert("圆周率约3.1415"));
+    assert_eq!("一九九七年七月一日", convert("1997年7月1日"));
+    assert_eq!("一千二百三十四万五千六百七十八", convert("12,345,678"));
+    assert_eq!("十二,三十四", convert("12,34"));
+    assert_eq!("特工零零七", convert("特工007"));
+    assert_eq!("十-二十页", convert("10-20页"));
+    assert_eq!("A4 纸、H2O、v2、x_1", convert("A4 纸、H2O、v2、x_1"));
+    assert_eq!("版本 1.2.3", convert("版本 1.2.3"));
+    assert_eq!("一.", convert("1."));
+    assert_eq!("零", convert("-0"));
+    let huge = "1".repeat(60);
+    assert_eq!(huge, convert(&huge));
+    assert_eq!("", convert(""));
+
+    let mut options = Options::default();
+    options.case = Case::Upper;
+    options.variant = Variant::Traditional;
+    assert_eq!(
+        "金額：壹萬貳仟叄佰肆拾伍點陸柒元",
+        convert_numbers_in_text("金額：12345.67元", &options)
+    );
+}
+
+#[test]
+fn numerals_in_text() {
+    use chinese_numerals::{convert_numerals_in_text, Scale};
+
+    let convert = |s| convert_numerals_in_text(s, Scale::Myriad);
+    assert_eq!("共123人", convert("共一百二十三人"));
+    assert_eq!("共123人", convert("共123人"));
+    assert_eq!("1997年7月1日", convert("一九九七年七月一日"));
+    assert_eq!("2024年", convert("二〇二四年"));
+    assert_eq!("35000元", convert("3万5千元"));
+    assert_eq!("12000000元", convert("壹仟贰佰万元"));
+    assert_eq!("10203元", convert("壹萬零貳佰零叄元"));
+    assert_eq!("-5度", convert("負五度"));
+    assert_eq!("3.14", convert("三点一四"));
+    assert_eq!("3点钟", convert("三点钟"));
+    assert_eq!("22个", convert("二十两个"));
+    assert_eq!("负责", convert("负责"));
+    assert_eq!("正在", convert("正在"));
+    assert_eq!("", convert(""));
+    assert_eq!("100000000", convert_numerals_in_text("一亿", Scale::Mid));
+    assert_eq!("100000", convert_numerals_in_text("一亿", Scale::Short));
+}
+
+#[test]
+fn ethnum_256() {
+    use chinese_numerals::{ChineseNumeral, DecimalStr, Long, Mid, Myriad, Short};
+    use ethnum::{I256, U256};
+
+    let num = DecimalStr::<Mid>::try_from(U256::from(1_0203_0405_u64)).unwrap();
+    assert_eq!("一亿零二百零三万零四百零五", num.to_lowercase_simp());
+    let max = DecimalStr::<Mid>::try_from(U256::MAX).unwrap();
+    assert_eq!(U256::MAX.to_string(), max.digits());
+    assert!(DecimalStr::<Long>::try_from(U256::MAX).is_ok());
+    assert!(DecimalStr::<Myriad>::try_from(U256::MAX).is_err());
+    assert!(DecimalStr::<Short>::try_from(U256::from(u64::MAX)).is_err());
+
+    let num = DecimalStr::<Myriad>::try_from(I256::from(-12_i32)).unwrap();
+    assert_eq!("负十二", num.to_lowercase_simp());
+    let min = DecimalStr::<Long>::try_from(I256::MIN).unwrap();
+    assert!(min.to_lowercase_simp().starts_with("负"));
+    assert_eq!(I256::MIN.unsigned_abs().to_string(), min.digits());
+    assert_eq!(
+        "零",
+        DecimalStr::<Mid>::try_from(I256::ZERO)
+            .unwrap()
+            .to_lowercase_simp()
+    );
+}
+
+#[test]
+fn grouped_strings() {
+    use chinese_numerals::{
+        Case, ChineseNumeral, MidScaleInt, MyriadScaleInt, ShortScaleInt, Variant,
+    };
+
+    let num = MyriadScaleInt::from(-12_0000_0000_0003_i64);
+    let groups = num.to_grouped_strings(Case::Lower, Variant::Simplified);
+    assert_eq!(vec!["负十二兆", "零三"], groups);
+    assert_eq!(num.to_lowercase_simp(), groups.concat());
+    assert_eq!(
+        vec!["壹拾貳兆", "零叄"],
+        MyriadScaleInt::from(12_0000_0000_0003_i64)
+            .to_grouped_strings(Case::Upper, Variant::Traditional)
+    );
+    assert_eq!(
+        vec!["一万亿", "零五"],
+        MidScaleInt::from(1_0000_0000_0005_i64)
+            .to_grouped_strings(Case::Lower, Variant::Simplified)
+    );
+    assert_eq!(
+        vec!["一垓", "零二兆", "零三万", "零四百零五"],
+        ShortScaleInt::from(1_0203_0405).to_grouped_strings(Case::Lower, Variant::Simplified)
+    );
+    assert_eq!(
+        vec!["三万"],
+        MyriadScaleInt::from(3_0000).to_grouped_strings(Case::Lower, Variant::Simplified)
+    );
+    assert_eq!(
+        vec!["零"],
+        MyriadScaleInt::default().to_grouped_strings(Case::Lower, Variant::Simplified)
+    );
+}
+
+#[test]
+fn strictness() {
+    use chinese_numerals::{
+        validate, Case, ChineseNumeral, LeadingOneStyle, MyriadScaleInt, Options, Scale,
+        Strictness, ZeroPolicy,
+    };
+
+    let mut loose = Options::default();
+    loose.zero_policy = ZeroPolicy::Omit;
+    loose.colloquial = true;
+    loose.leading_one = LeadingOneStyle::Keep;
+    loose.upper_leading_one = Some(LeadingOneStyle::Omit);
+    let with = |case, strictness| {
+        let mut options = loose.clone();
+        options.case = case;
+        options.strictness = strictness;
+        options
+    };
+    let num = MyriadScaleInt::from(12_0300);
+
+    // the uppercase financial path conforms to the standard but for a set leading 壹
+    let upper = with(Case::Upper, Strictness::Standard);
+    assert_eq!("拾贰万零叁佰", num.to_chinese(&upper));
+    let mut default_upper = Options::default();
+    default_upper.case = Case::Upper;
+    assert_eq!("壹拾贰万零叁佰", num.to_chinese(&default_upper));
+    assert_eq!(
+        Ok(()),
+        validate(&num.to_chinese(&default_upper), Scale::Myriad)
+    );
+    let lower = with(Case::Lower, Strictness::Standard);
+    assert_eq!("一十二万三百", num.to_chinese(&lower));
+    let upper = with(Case::Upper, Strictness::Lenient);
+    assert_eq!("拾贰万叁佰", num.to_chinese(&upper));
+    let classical = with(Case::Upper, Strictness::Classical);
+    assert_eq!("壹拾贰万叁佰", num.to_chinese(&classical));
+    let mut classical = Options::default();
+    classical.strictness = Strictness::Classical;
+    assert_eq!("一十二万三百", num.to_chinese(&classical));
+    assert_eq!(6, num.char_len(&classical));
+
+    let num = MyriadScaleInt::from(3_5000);
+    assert_eq!(
+        "叁万伍仟",
+        num.to_chinese(&with(Case::Upper, Strictness::Standard))
+    );
+    assert_eq!(
+        "三万五",
+        num.to_chinese(&with(Case::Lower, Strictness::Standard))
+    );
+    assert_eq!(
+        "叁万伍",
+        num.to_chinese(&with(Case::Upper, Strictness::Lenient))
+    );
+    assert_eq!(
+        "三万五千",
+        num.to_chinese(&with(Case::Lower, Strictness::Classical))
+    );
+}
+
+#[test]
+fn append_zheng() {
+    use chinese_numerals::{Case, ChineseNumeral, MyriadScaleInt, Options, Style, Variant};
+
+    let mut options = Options::default();
+    options.case = Case::Upper;
+    options.append_zheng = true;
+    let num = MyriadScaleInt::from(3500);
+    assert_eq!("叁仟伍佰整", num.to_chinese(&options));
+    assert_eq!(5, num.char_len(&options));
+    assert_eq!(15, num.byte_len(&options));
+    assert_eq!(
+        "叁仟伍佰",
+        num.to_chinese(&{
+            let mut options = options.clone();
+            options.max_output_chars = Some(4);
+            options
+        })
+    );
+    assert_eq!(
+        4,
+        num.char_len(&{
+            let mut options = options.clone();
+            options.max_output_chars = Some(4);
+            options
+        })
+    );
+    assert_eq!(
+        "壹萬零貳拾整",
+        MyriadScaleInt::from(1_0020).to_chinese(&{
+            let mut options = options.clone();
+            options.variant = Variant::Traditional;
+            options
+        })
+    );
+    assert_eq!(
+        "三千五百整",
+        num.to_chinese(&{
+            let mut options = options.clone();
+            options.case = Case::Lower;
+            options
+        })
+    );
+    assert_eq!(
+        "叁仟伍佰",
+        num.to_chinese(&{
+            let mut options = options.clone();
+            options.style = Style::IcuCompatible;
+            options
+        })
+    );
+    assert_eq!(
+        "叁仟伍佰",
+        num.to_chinese(&{
+            let mut options = Options::default();
+            options.case = Case::Upper;
+            options
+        })
+    );
+}
+
+#[test]
+fn decimal_rounding() {
+    use chinese_numerals::{ChineseDecimal, ErrorKind, Rounding, ShortScaleInt};
+
+    let _defaults = shared_defaults();
+
+    let round = |s: &str, digits, rounding| {
+        s.parse::<ChineseDecimal>()
+            .unwrap()
+            .round(digits, rounding)
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!("三", round("2.5", 0, Rounding::HalfUp));
+    assert_eq!("二", round("2.5", 0, Rounding::HalfEven));
+    assert_eq!("四", round("3.5", 0, Rounding::HalfEven));
+    assert_eq!("三", round("2.501", 0, Rounding::HalfEven));
+    assert_eq!("二", round("2.9", 0, Rounding::Truncate));
+    assert_eq!("负三", round("-2.5", 0, Rounding::HalfUp));
+    assert_eq!("一千点零", round("999.96", 1, Rounding::HalfUp));
+    assert_eq!("一千", round("999.96", 0, Rounding::HalfEven));
+    assert_eq!("零点零零", round("-0.001", 2, Rounding::HalfUp));
+    assert_eq!("十二点五零", round("12.50", 3, Rounding::Truncate));
+    assert_eq!("一点二", round("1.25", 1, Rounding::HalfEven));
+
+    let num: ChineseDecimal<ShortScaleInt> = "999999999999999.5".parse().unwrap();
+    let err = num.round(0, Rounding::HalfUp).unwrap_err();
+    assert_eq!(&ErrorKind::OutOfRange, err.kind());
+}
+
+#[test]
+fn cmp_primitives() {
+    use chinese_numerals::{MyriadScaleBigInt, MyriadScaleInt, MyriadScaleInt32, ShortScaleInt};
+    use num_bigint::{BigInt, BigUint};
+
+    let num = MyriadScaleInt::from(1_0203_0405);
+    assert_eq!(num, 1_0203_0405);
+    assert_eq!(1_0203_0405u64, num);
+    assert!(num > 1_0203_0404i64 && num < 1_0203_0406u128);
+    assert_ne!(num, -1_0203_0405);
+    assert!(MyriadScaleInt::from(-5) < -3 && -3 > MyriadScaleInt::from(-5));
+    assert!(MyriadScaleInt::from(-5) < 0u32);
+    assert_eq!(MyriadScaleInt::default(), 0);
+    assert!(ShortScaleInt::MAX < u128::MAX);
+    assert!(MyriadScaleInt32::MAX > i32::MAX);
+    assert!(MyriadScaleInt32::MIN < i64::from(i32::MIN));
+
+    let big = MyriadScaleBigInt::from(-12345);
+    assert_eq!(big, -12345);
+    assert_eq!(big, BigInt::from(-12345));
+    let (upper, lower) = (BigInt::from(-12344), BigInt::from(-12346));
+    assert!(big < upper && lower < big);
+    assert_ne!(big, BigUint::from(12345u32));
+    assert_eq!(MyriadScaleBigInt::from(12345), BigUint::from(12345u32));
+}
+
+#[test]
+fn display_views() {
+    use chinese_numerals::{ChineseNumeral, MyriadScaleInt, ShortScaleInt, Variant};
+    use std::fmt::Write;
+
+    let num = MyriadScaleInt::from(1_0203_0405);
+    assert_eq!(
+        num.to_lowercase(Variant::Simplified),
+        num.display_lower(Variant::Simplified).to_string()
+    );
+    assert_eq!(
+        num.to_uppercase(Variant::Traditional),
+        num.display_upper(Variant::Traditional).to_string()
+    );
+    let mut buf = String::new();
+    write!(
+        buf,
+        "{}|",
+        ShortScaleInt::from(0).display_lower(Variant::Simplified)
+    )
+    .unwrap();
+    write!(
+        buf,
+        "{:>4}|",
+        MyriadScaleInt::from(12).display_lower(Variant::Simplified)
+    )
+    .unwrap();
+    write!(
+        buf,
+        "{:<3}",
+        MyriadScaleInt::from(-2).display_upper(Variant::Simplified)
+    )
+    .unwrap();
+    assert_eq!("零|  十二|负贰 ", buf);
+}
+
+#[test]
+fn small_fast_path() {
+    use chinese_numerals::{
+        ChineseNumeral, LongScaleBigInt, LongScaleInt, MidScaleInt, MyriadScaleBigInt,
+        MyriadScaleInt, MyriadScaleInt32, Variant,
+    };
+
+    let values = (0..2_0000_u128)
+        .chain((0..1000).map(|i| i * 99_991 + 7))
+        .chain([
+            1_0000,
+            1_0001,
+            1_0100,
+            10_1000,
+            1000_0001,
+            9999_9999,
+            1_0000_0000,
+            1_0000_0001,
+        ]);
+    for value in values {
+        let expected = MyriadScaleBigInt::from(value);
+        let lower = expected.to_lowercase(Variant::Simplified);
+        let upper = expected.to_uppercase(Variant::Simplified);
+        assert_eq!(
+            lower,
+            MyriadScaleInt::from(value).to_lowercase_simp(),
+            "{value}"
+        );
+        assert_eq!(
+            upper,
+            MyriadScaleInt::from(value).to_uppercase_simp(),
+            "{value}"
+        );
+        assert_eq!(
+            lower,
+            MidScaleInt::from(value).to_lowercase_simp(),
+            "{value}"
+        );
+        assert_eq!(
+            upper,
+            LongScaleInt::from(value).to_uppercase_simp(),
+            "{value}"
+        );
+        assert_eq!(
+            LongScaleBigInt::from(value).to_lowercase_simp(),
+            LongScaleInt::from(value).to_lowercase_simp()
+        );
+        if let Ok(value) = u32::try_from(value) {
+            assert_eq!(lower, MyriadScaleInt32::from(value).to_lowercase_simp());
+        }
+    }
+}
+
+#[test]
+fn group_separator() {
+    use chinese_numerals::{ChineseNumeral, LongScaleInt, MidScaleInt, MyriadScaleInt, Options};
+
+    let mut options = Options::default();
+    options.group_separator = Some(" ".to_owned());
+    let num = MyriadScaleInt::from(-1_0203_0405);
+    assert_eq!("负一亿 零二百零三万 零四百零五", num.to_chinese(&options));
+    assert_eq!(16, num.char_len(&options));
+    assert_eq!("一万", MyriadScaleInt::from(1_0000).to_chinese(&options));
+    assert_eq!("三百", MyriadScaleInt::from(300).to_chinese(&options));
+    assert_eq!(
+        "一万亿 零五",
+        MidScaleInt::from(1_0000_0000_0005_u64).to_chinese(&options)
+    );
+
+    let mut options = Options::default();
+    options.group_separator = Some("\u{200B}".to_owned());
+    assert_eq!(
+        "三亿\u{200B}五千万\u{200B}零一",
+        LongScaleInt::from(3_5000_0001).to_chinese(&options)
+    );
+}
+
+#[test]
+fn num_char_from_char() {
+    use chinese_numerals::{NumChar, Variant};
+
+    assert_eq!(27, NumChar::ALL.len());
+    for (i, &ch) in NumChar::ALL.iter().enumerate() {
+        assert_eq!(i, ch as usize);
+    }
+    for &ch in &NumChar::ALL[..NumChar::Pos as usize] {
+        for variant in [Variant::Simplified, Variant::Traditional] {
+            for glyph in [ch.to_lowercase(variant), ch.to_uppercase(variant)] {
+                let glyph = glyph.chars().next().unwrap();
+                assert_eq!(Some(ch), NumChar::from_char(glyph), "{glyph}");
+            }
+        }
+    }
+    assert_eq!(Some(NumChar::Zheng), NumChar::from_char('正'));
+    assert_eq!(Some(NumChar::Point), NumChar::from_char('点'));
+    assert_eq!(None, NumChar::from_char('a'));
+}
+
+#[test]
+fn defmt_format() {
+    use chinese_numerals::{MyriadScaleBigInt, MyriadScaleInt, MyriadScaleInt32, ShortScaleInt};
+
+    fn assert_format<T: defmt::Format>() {}
+    assert_format::<ShortScaleInt>();
+    assert_format::<MyriadScaleInt>();
+    assert_format::<MyriadScaleInt32>();
+    assert_format::<MyriadScaleBigInt>();
+}
+
+#[test]
+fn rand_distribution() {
+    use chinese_numerals::{MidScaleInt, MyriadScaleBigInt, MyriadScaleInt32, ShortScaleInt};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(1843);
+    for _ in 0..1000 {
+        let num = rng.gen::<ShortScaleInt>();
+        assert!(ShortScaleInt::MIN <= num && num <= ShortScaleInt::MAX);
+        let num = rng.gen::<MyriadScaleInt32>();
+        assert!(MyriadScaleInt32::MIN <= num && num <= MyriadScaleInt32::MAX);
+        let num = rng.gen::<MyriadScaleBigInt>();
+        assert!(MyriadScaleBigInt::min_value() <= num && num <= MyriadScaleBigInt::max_value());
+    }
+    let nums: Vec<MidScaleInt> = (&mut rng)
+        .sample_iter(rand::distributions::Standard)
+        .take(100)
+        .collect();
+    assert!(nums.iter().any(|num| *num < 0) && nums.iter().any(|num| *num > 0));
+}
+
+#[test]
+fn checked_output_length() {
+    use chinese_numerals::{ChineseNumeral, ErrorKind, LongScaleBigInt, ShortScaleInt, Variant};
+
+    let num = ShortScaleInt::from(-12);
+    assert_eq!(
+        "负十二",
+        num.to_lowercase_checked(Variant::Simplified, 3).unwrap()
+    );
+    assert_eq!(
+        "負壹拾貳",
+        num.to_uppercase_checked(Variant::Traditional, 4).unwrap()
+    );
+    assert_eq!(
+        &ErrorKind::OutputTooLong(3),
+        num.to_uppercase_checked(Variant::Simplified, 3)
+            .unwrap_err()
+            .kind()
+    );
+    assert_eq!(
+        "零",
+        ShortScaleInt::from(0)
+            .to_lowercase_checked(Variant::Simplified, 1)
+            .unwrap()
+    );
+    assert!(ShortScaleInt::from(0)
+        .to_lowercase_checked(Variant::Simplified, 0)
+        .is_err());
+
+    let huge = LongScaleBigInt::max_value();
+    let err = huge
+        .to_lowercase_checked(Variant::Simplified, 100)
+        .unwrap_err();
+    assert_eq!(&ErrorKind::OutputTooLong(100), err.kind());
+}
+
+#[test]
+fn digit_stream() {
+    use chinese_numerals::{
+        Case, ChineseNumeral, DigitStream, ErrorKind, LongScaleBigInt, MidScaleBigInt,
+        MyriadScaleBigInt, ParseError, Scale, ShortScaleInt, Variant,
+    };
+    use num_bigint::BigInt;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn to_case<T: ChineseNumeral>(num: &T, case: Case, variant: Variant) -> String {
+        match case {
+            Case::Lower => num.to_lowercase(variant),
+            Case::Upper => num.to_uppercase(variant),
+        }
+    }
+
+    fn stream(scale: Scale, digits: &str, case: Case, variant: Variant) -> String {
+        let len = digits.trim_start_matches('-').len();
+        let mut stream = DigitStream::new(scale, len, case, variant);
+        let mut output = String::new();
+        for ch in digits.chars() {
+            stream.push(ch, &mut output).unwrap();
+        }
+        stream.finish(&mut output).unwrap();
+        output
+    }
+
+    let mut rng = StdRng::seed_from_u64(1854);
+    for _ in 0..2000 {
+        let len = rng.gen_range(1..=40);
+        // sparse digits exercise the zeros between groups
+        let mut digits: String = (0..len)
+            .map(|_| match rng.gen_range(0..3) {
+                0 => char::from(b'0' + rng.gen_range(0..10)),
+                _ => '0',
+            })
+            .collect();
+        if rng.gen::<bool>() {
+            digits.insert(0, '-');
+        }
+        let value: BigInt = digits.parse().unwrap();
+        for case in [Case::Lower, Case::Upper] {
+            for variant in [Variant::Simplified, Variant::Japanese] {
+                let myriad = MyriadScaleBigInt::try_from(&value).unwrap();
+                let mid = MidScaleBigInt::try_from(&value).unwrap();
+                let long = LongScaleBigInt::try_from(&value).unwrap();
+                for (scale, expected) in [
+                    (Scale::Myriad, to_case(&myriad, case, variant)),
+                    (Scale::Mid, to_case(&mid, case, variant)),
+                    (Scale::Long, to_case(&long, case, variant)),
+                ] {
+                    assert_eq!(
+                        expected,
+                        stream(scale, &digits, case, variant),
+                        "{digits} {scale:?}"
+                    );
+                }
+            }
+        }
+    }
+    for value in [0_i64, 10, -15, 1001, 10_0010, 999_9999_9999_9999] {
+        let expected = ShortScaleInt::try_from(value).unwrap().to_lowercase_simp();
+        assert_eq!(
+            expected,
+            stream(
+                Scale::Short,
+                &value.to_string(),
+                Case::Lower,
+                Variant::Simplified
+            )
+        );
+    }
+
+    let mut stream = DigitStream::new(Scale::Myriad, 2, Case::Lower, Variant::Simplified);
+    let mut output = String::new();
+    let err = stream.push('x', &mut output).unwrap_err();
+    assert_eq!(&ErrorKind::Parse(ParseError::InvalidChar('x')), err.kind());
+    stream.push('1', &mut output).unwrap();
+    assert_eq!("", output);
+    let err = stream.finish(&mut output).unwrap_err();
+    assert_eq!(&ErrorKind::Parse(ParseError::Empty), err.kind());
+}
+
+#[test]
+fn big_display_borrowed() {
+    use chinese_numerals::{ChineseNumeral, LongScaleBigInt, MidScaleBigInt, Variant};
+    use num_bigint::BigUint;
+
+    let _defaults = shared_defaults();
+
+    let value = BigUint::from(10_u32).pow(40) + BigUint::from(1_0000_0005_u64);
+    let num = LongScaleBigInt::try_from(&value).unwrap();
+    let numeral = "一亿京零一亿零五";
+    assert_eq!(numeral, num.to_lowercase_simp());
+    assert_eq!(numeral, format!("{}", &num));
+    assert_eq!(
+        numeral,
+        format!("{}", num.display_lower(Variant::Simplified))
+    );
+    assert_eq!("约一亿京", format!("{:.1}", &num));
+
+    let num = MidScaleBigInt::try_from(&value).unwrap();
+    assert_eq!("一秭零一亿零五", format!("{}", &num));
+}
+
+#[test]
+fn classifiers() {
+    use chinese_numerals::{
+        with_classifier, Case, MyriadScaleInt, Options, ShortScaleInt, Variant,
+    };
+
+    let options = Options::default();
+    let convert = |n: i32, classifier, options: &Options| {
+        with_classifier(&MyriadScaleInt::from(n), classifier, options)
+    };
+    assert_eq!("两个", convert(2, "个", &options));
+    assert_eq!("两百只", convert(200, "只", &options));
+    assert_eq!("两万零二张", convert(2_0002, "张", &options));
+    assert_eq!("十二个", convert(12, "个", &options));
+    assert_eq!("二十个", convert(20, "个", &options));
+    assert_eq!("负两个", convert(-2, "个", &options));
+    assert_eq!("零个", convert(0, "个", &options));
+    assert_eq!(
+        "两百个",
+        with_classifier(&ShortScaleInt::from(200), "个", &options)
+    );
+
+    let mut traditional = Options::default();
+    traditional.variant = Variant::Traditional;
+    assert_eq!("兩萬隻", convert(2_0000, "隻", &traditional));
+    let mut upper = Options::default();
+    upper.case = Case::Upper;
+    assert_eq!("贰个", convert(2, "个", &upper));
+
+    let mut colloquial = Options::default();
+    colloquial.colloquial = true;
+    assert_eq!("仨", convert(3, "个", &colloquial));
+    assert_eq!("两只", convert(2, "只", &colloquial));
+    assert_eq!("负两个", convert(-2, "个", &colloquial));
+    assert_eq!(
+        "倆",
+        convert(2, "个", &{
+            let mut options = colloquial.clone();
+            options.variant = Variant::Traditional;
+            options
+        })
+    );
+}
+
+#[test]
+fn era_years() {
+    use chinese_numerals::{format_era_year, format_year_range};
+
+    // astronomical years, where 0 is 1 BCE
+    assert_eq!("公元前二〇二年", format_era_year(-201));
+    assert_eq!("公元前一年", format_era_year(0));
+    assert_eq!("公元前二年", format_era_year(-1));
+    assert_eq!("一年", format_era_year(1));
+    assert_eq!("二〇二四年", format_era_year(2024));
+    assert_eq!("一九四九至一九七八年", format_year_range(1949, 1978));
+    assert_eq!("公元前二二一至前二〇六年", format_year_range(-220, -205));
+    assert_eq!("公元前二七年至公元一四年", format_year_range(-26, 14));
+    assert_eq!("公元一四年至公元前二七年", format_year_range(14, -26));
+}
+
+#[test]
+fn weekday_and_month_names() {
+    use chinese_numerals::{format_weekday, month_name, weekday_name, Variant, WeekdayStyle};
+
+    for weekday in 1..=7 {
+        assert_eq!(
+            format_weekday(weekday - 1),
+            weekday_name(weekday, WeekdayStyle::default(), Variant::Simplified)
+        );
+    }
+    assert_eq!(None, format_weekday(7));
+    assert_eq!(None, format_weekday(99));
+    assert_eq!(
+        Some("周三".to_owned()),
+        weekday_name(3, WeekdayStyle::Zhou, Variant::Simplified)
+    );
+    assert_eq!(
+        Some("週日".to_owned()),
+        weekday_name(7, WeekdayStyle::Zhou, Variant::Traditional)
+    );
+    assert_eq!(
+        None,
+        weekday_name(0, WeekdayStyle::Xingqi, Variant::Simplified)
+    );
+
+    let months: Vec<_> = (1..=12)
+        .filter_map(|month| month_name(month, Variant::Simplified))
+        .collect();
+    assert_eq!("一月", months[0]);
+    assert_eq!("十月", months[9]);
+    assert_eq!("十一月", months[10]);
+    assert_eq!(None, month_name(0, Variant::Simplified));
+}
+
+#[test]
+fn parse_error_spans() {
+    use chinese_numerals::{check_numeral, ParseError, Scale};
+
+    assert_eq!(Ok(()), check_numeral("3万5千", Scale::Myriad));
+    assert_eq!(Ok(()), check_numeral("-十二", Scale::Short));
+
+    let err = check_numeral("一亿零二百万点五", Scale::Myriad).unwrap_err();
+    assert_eq!(ParseError::InvalidChar('点'), err.error);
+    assert_eq!(18..21, err.span);
+    assert_eq!(Some('点'), err.found);
+    assert_eq!("a digit or a unit", err.expected);
+
+    let err = check_numeral("a十", Scale::Myriad).unwrap_err();
+    assert_eq!(0..1, err.span);
+    assert_eq!("a sign, a digit or a unit", err.expected);
+
+    let err = check_numeral("一二", Scale::Myriad).unwrap_err();
+    assert_eq!(3..6, err.span);
+    assert_eq!("a unit after a digit", err.expected);
+    let err = check_numeral("一万一万", Scale::Myriad).unwrap_err();
+    assert_eq!(9..12, err.span);
+    assert_eq!("units in descending order", err.expected);
+
+    let err = check_numeral("负", Scale::Myriad).unwrap_err();
+    assert_eq!(ParseError::Empty, err.error);
+    assert_eq!(3..3, err.span);
+    assert_eq!(None, err.found);
+    assert_eq!(
+        "Invalid character 'x' in a number at 3..4, expected a digit or a unit",
+        check_numeral("五x", Scale::Myriad).unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn dyn_numerals() {
+    use chinese_numerals::{
+        ChineseNumeral, DynChineseNumeral, LongScaleInt32, MyriadScaleInt, Options, Scale,
+        ShortScaleInt, Sign, Variant,
+    };
+
+    let nums: Vec<Box<dyn DynChineseNumeral>> = vec![
+        Box::new(ShortScaleInt::from(-1_0000)),
+        Box::new(MyriadScaleInt::from(0)),
+        Box::new(LongScaleInt32::from(10305_u32)),
+    ];
+    let scales: Vec<_> = nums.iter().map(|num| num.scale()).collect();
+    assert_eq!(vec![Scale::Short, Scale::Myriad, Scale::Long], scales);
+    let signs: Vec<_> = nums.iter().map(|num| num.sign()).collect();
+    assert_eq!(vec![Sign::Neg, Sign::Nil, Sign::Pos], signs);
+    assert_eq!("负一万", nums[0].lowercase(Variant::Simplified));
+    assert_eq!("零", nums[1].uppercase(Variant::Simplified));
+    assert_eq!("壹萬零叄佰零伍", format!("{:-#}", nums[2]));
+    assert_eq!(
+        MyriadScaleInt::from(10305).to_chinese(&Options::default()),
+        nums[2].render(&Options::default())
+    );
+    assert!(nums[2]
+        .try_render(&{
+            let mut options = Options::default();
+            options.max_output_chars = Some(3);
+            options
+        })
+        .is_err());
+    // both traits can be in scope
+    assert_eq!("十二", MyriadScaleInt::from(12).to_lowercase_simp());
+}
+
+#[test]
+fn join_lists() {
+    use chinese_numerals::{
+        format_list, join_chinese, Case, MyriadScaleInt, Options, ShortScaleInt, Variant,
+    };
+
+    let _defaults = shared_defaults();
+
+    let nums = [1, 20, 10305].map(MyriadScaleInt::from);
+    assert_eq!("一 二十 一万零三百零五", join_chinese(nums, " "));
+    assert_eq!("十", join_chinese([ShortScaleInt::from(10)], "、"));
+
+    let mut options = Options::default();
+    options.case = Case::Upper;
+    options.variant = Variant::Traditional;
+    assert_eq!(
+        "壹、貳、叄",
+        format_list((1..=3).map(ShortScaleInt::from), &options)
+    );
+    assert_eq!("", format_list(Vec::<ShortScaleInt>::new(), &options));
+}
+
+#[test]
+fn try_to_chinese() {
+    use chinese_numerals::{Case, ErrorKind, Options, Scale, TryToChinese};
+
+    let _defaults = shared_defaults();
+
+    assert_eq!("负一万零五", (-10005_i64).try_to_short_scale().unwrap());
+    assert_eq!("零", 0_usize.try_to_short_scale().unwrap());
+    assert_eq!(
+        "玖载玖正玖涧玖沟玖穰玖秭玖垓玖京玖兆玖亿玖万玖仟玖佰玖拾玖",
+        999_9999_9999_9999_i128
+            .try_to_short_scale_with(&{
+                let mut options = Options::default();
+                options.case = Case::Upper;
+                options
+            })
+            .unwrap()
+    );
+
+    let err = (-1000_0000_0000_0000_i64).try_to_short_scale().unwrap_err();
+    assert_eq!(&ErrorKind::OutOfRange, err.kind());
+    assert_eq!(Some(Scale::Short), err.scale());
+    let err = u128::MAX.try_to_short_scale().unwrap_err();
+    assert_eq!(&ErrorKind::OutOfRange, err.kind());
+
+    let mut options = Options::default();
+    options.max_output_chars = Some(2);
+    let err = 105_u64.try_to_short_scale_with(&options).unwrap_err();
+    assert_eq!(&ErrorKind::OutputTooLong(2), err.kind());
+}
+
+#[test]
+fn parse_auto_scales() {
+    use chinese_numerals::{parse_auto, ParseError, Scale};
+
+    let scales = |s| -> Vec<Scale> {
+        parse_auto(s)
+            .unwrap()
+            .into_iter()
+            .map(|(scale, _)| scale)
+            .collect()
+    };
+    assert_eq!(
+        vec![Scale::Short, Scale::Myriad, Scale::Mid, Scale::Long],
+        scales("三万五千")
+    );
+    // 十 before 万 is written 亿 in short scale
+    assert_eq!(
+        vec![Scale::Myriad, Scale::Mid, Scale::Long],
+        scales("十二万")
+    );
+    assert_eq!(vec![Scale::Mid, Scale::Long], scales("一万亿"));
+    // 亿兆 is written 京 in mid-scale
+    assert_eq!(vec![Scale::Long], scales("一亿兆"));
+    // 亿亿 is written 兆 in mid-scale and long scale
+    assert_eq!(Err(ParseError::InvalidChar('亿')), parse_auto("一亿亿"));
+    assert_eq!(Err(ParseError::InvalidChar('万')), parse_auto("一万万"));
+
+    assert_eq!(
+        vec![
+            (Scale::Short, "-300000".to_owned()),
+            (Scale::Myriad, "-300000000".to_owned()),
+            (Scale::Mid, "-300000000".to_owned()),
+            (Scale::Long, "-300000000".to_owned()),
+        ],
+        parse_auto("负三亿").unwrap()
+    );
+    assert_eq!(
+        vec![(Scale::Myriad, "10000000000000000".to_owned())],
+        parse_auto("一京").unwrap()[1..2]
+    );
+    assert_eq!(Err(ParseError::InvalidChar('x')), parse_auto("三x"));
+    assert_eq!(Err(ParseError::Empty), parse_auto(""));
+}
+
+#[test]
+fn parse_preferring_scales() {
+    use chinese_numerals::{parse_preferring, ParseError, Scale};
+
+    let reading = parse_preferring("二兆", Scale::Short).unwrap();
+    assert_eq!(Scale::Short, reading.scale);
+    assert_eq!("2000000", reading.value);
+    // mid-scale and long scale agree on 兆
+    assert_eq!(
+        vec![
+            (Scale::Myriad, "2000000000000".to_owned()),
+            (Scale::Mid, "20000000000000000".to_owned()),
+        ],
+        reading.alternatives
+    );
+
+    let reading = parse_preferring("一万亿", Scale::Long).unwrap();
+    assert_eq!("1000000000000", reading.value);
+    assert!(!reading.is_ambiguous());
+
+    // read leniently in the preferred scale
+    let reading = parse_preferring("一万亿", Scale::Myriad).unwrap();
+    assert_eq!(Scale::Myriad, reading.scale);
+    assert_eq!("1000000000000", reading.value);
+    assert!(!reading.is_ambiguous());
+
+    // out of range of short scale
+    let reading = parse_preferring("一万载", Scale::Short).unwrap();
+    assert_eq!(Scale::Mid, reading.scale);
+    assert_eq!(format!("1{}", "0".repeat(84)), reading.value);
+    assert!(reading.is_ambiguous());
+
+    assert_eq!(Err(ParseError::Empty), parse_preferring("负", Scale::Mid));
+}
+
+#[test]
+fn serde_dual_representation() {
+    use chinese_numerals::{DecimalStr, Mid, MidScaleBigInt, MyriadScaleInt, ShortScaleInt};
+
+    let _defaults = shared_defaults();
+
+    let num = MyriadScaleInt::from(-10305);
+    assert_eq!(
+        r#"{"value":"-10305","chinese":"负一万零三百零五"}"#,
+        serde_json::to_string(&num).unwrap()
+    );
+    assert_eq!(
+        r#"{"value":"0","chinese":"零"}"#,
+        serde_json::to_string(&ShortScaleInt::default()).unwrap()
+    );
+    let big = MidScaleBigInt::from(u128::MAX);
+    let json: serde_json::Value = serde_json::to_value(&big).unwrap();
+    assert_eq!(u128::MAX.to_string(), json["value"]);
+    let digits = DecimalStr::<Mid>::from_decimal_str("1_0000_0000").unwrap();
+    assert_eq!(
+        r#"{"value":"100000000","chinese":"一亿"}"#,
+        serde_json::to_string(&digits).unwrap()
+    );
+
+    // compact formats hold the decimal string only
+    let bytes = bincode::serialize(&num).unwrap();
+    assert_eq!("-10305", bincode::deserialize::<String>(&bytes).unwrap());
+    let bytes = bincode::serialize(&big).unwrap();
+    assert_eq!(
+        u128::MAX.to_string(),
+        bincode::deserialize::<String>(&bytes).unwrap()
+    );
+}
+
+#[test]
+fn display_padding() {
+    use chinese_numerals::{
+        format_const, Case, ChineseDecimal, ChineseNumeral, MyriadScaleBigInt, MyriadScaleInt,
+        Scale, Variant,
+    };
+
+    let _defaults = shared_defaults();
+
+    let num = MyriadScaleInt::from(-12);
+    assert_eq!("  负十二", format!("{:>5}", num));
+    assert_eq!("  负十二", format!("{:5}", num));
+    assert_eq!("负十二**", format!("{:*<5}", num));
+    assert_eq!("负十二", format!("{:2}", num));
+    assert_eq!("·負壹拾貳·", format!("{:·^-#6}", num));
+    assert_eq!("    一万", format!("{:6}", MyriadScaleBigInt::from(10000)));
+
+    let decimal: ChineseDecimal = "3.14".parse().unwrap();
+    assert_eq!("  三点一四", format!("{:6}", decimal));
+    assert_eq!("三点一四__", format!("{:_<6}", decimal));
+
+    let label = format_const(305, Scale::Myriad, Case::Lower, Variant::Simplified);
+    assert_eq!(" 三百零五", format!("{:5}", label));
+
+    assert_eq!(
+        "   十二",
+        format!(
+            "{:5}",
+            MyriadScaleInt::from(12).display_lower(Variant::Simplified)
+        )
+    );
+}
+
+#[test]
+fn fold_numerals() {
+    use chinese_numerals::{fold_numeral, ChineseNumeral, MyriadScaleInt, Variant};
+
+    let num = MyriadScaleInt::from(-3_0000_0105_i64);
+    let forms = [
+        num.to_lowercase(Variant::Simplified),
+        num.to_lowercase(Variant::Traditional),
+        num.to_uppercase(Variant::Simplified),
+        num.to_uppercase(Variant::Traditional),
+    ];
+    for form in &forms {
+        assert_eq!("负三亿零一百零五", fold_numeral(form));
+    }
+    assert_eq!("二万零五十", fold_numeral("两万〇五十"));
+    assert_eq!("一点五", fold_numeral("壹點伍"));
+    // Kangxi radicals and compatibility ideographs
+    assert_eq!("十二", fold_numeral("\u{2F17}\u{2F06}"));
+    assert_eq!("六十", fold_numeral("\u{F9D3}\u{F973}"));
+    assert_eq!("第2024号，共abc", fold_numeral("第２０２４号，共abc"));
+    assert_eq!("", fold_numeral(""));
+}
+
+#[test]
+fn scale_ceilings() {
+    use chinese_numerals::{
+        fits_in_scale, LongScaleBigInt, MyriadScaleInt, Scale, ShortScaleInt, LONG_SCALE_MAX_EXP,
+        MID_SCALE_MAX_EXP, MYRIAD_SCALE_MAX_EXP, SHORT_SCALE_MAX_EXP,
+    };
+    use num_bigint::BigInt;
+
+    assert_eq!(15, SHORT_SCALE_MAX_EXP);
+    assert_eq!(48, MYRIAD_SCALE_MAX_EXP);
+    assert_eq!(88, MID_SCALE_MAX_EXP);
+    assert_eq!(8192, LONG_SCALE_MAX_EXP);
+    assert_eq!(MYRIAD_SCALE_MAX_EXP, Scale::Myriad.max_exp());
+
+    // agrees with the conversions
+    for value in [999_9999_9999_9999_i64, 1000_0000_0000_0000, i64::MIN, 0] {
+        assert_eq!(
+            ShortScaleInt::try_from(value).is_ok(),
+            fits_in_scale(value, Scale::Short)
+        );
+    }
+    assert!(fits_in_scale(i128::MIN, Scale::Mid));
+    assert!(fits_in_scale(u128::MAX, Scale::Myriad));
+    assert!(MyriadScaleInt::try_from("9".repeat(48).as_str()).is_err());
+
+    let big: BigInt = BigInt::from(10).pow(LONG_SCALE_MAX_EXP) - 1;
+    assert!(fits_in_scale(&big, Scale::Long));
+    assert!(LongScaleBigInt::try_from(big.clone()).is_ok());
+    let big: BigInt = -big - 1;
+    assert!(!fits_in_scale(&big, Scale::Long));
+    assert!(LongScaleBigInt::try_from(big).is_err());
+
+    assert!(fits_in_scale("-0001_0000", Scale::Short));
+    assert!(!fits_in_scale("", Scale::Short));
+    assert!(!fits_in_scale("一万", Scale::Myriad));
+}
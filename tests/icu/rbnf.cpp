@@ -0,0 +1,42 @@
+// Generates the expected outputs of ICU's spellout rules, used by test `icu_compatible`.
+//
+//     g++ rbnf.cpp -o rbnf -licuuc -licui18n
+//     ./rbnf zh < numbers.txt > zh.tsv
+//     ./rbnf zh_Hant %spellout-cardinal-financial < numbers.txt > zh_Hant_financial.tsv
+//
+// Numbers are read line by line, and written with their spellout separated by a tab.
+
+#include <iostream>
+#include <string>
+#include <unicode/locid.h>
+#include <unicode/rbnf.h>
+#include <unicode/unistr.h>
+
+using namespace icu;
+
+int main(int argc, char **argv) {
+    if (argc < 2) {
+        std::cerr << "Usage: rbnf LOCALE [RULE_SET]\n";
+        return 1;
+    }
+    UErrorCode status = U_ZERO_ERROR;
+    RuleBasedNumberFormat format(URBNF_SPELLOUT, Locale(argv[1]), status);
+    if (argc > 2) {
+        format.setDefaultRuleSet(UnicodeString::fromUTF8(argv[2]), status);
+    }
+    if (U_FAILURE(status)) {
+        std::cerr << u_errorName(status) << "\n";
+        return 1;
+    }
+
+    std::string line;
+    while (std::getline(std::cin, line)) {
+        int64_t number = std::stoll(line);
+        UnicodeString spellout;
+        format.format(number, spellout);
+        std::string output;
+        spellout.toUTF8String(output);
+        std::cout << number << "\t" << output << "\n";
+    }
+    return 0;
+}